@@ -1,3 +1,17 @@
+use anchor_lang::prelude::*;
+
 pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
 pub const DEVICE_KEY: &[u8] = b"device";
 pub const DEVICE_REGISTRY_KEY: &[u8] = b"device_registry";
+pub const DEVICE_METADATA_KEY: &[u8] = b"device_metadata";
+pub const BOOKING_AUTHORITY_KEY: &[u8] = b"booking_authority";
+
+pub const DEFAULT_STALE_AFTER_SECS: u32 = 300;
+
+pub const MAX_MIME_TYPES: usize = 8;
+pub const MAX_MIME_TYPE_LEN: usize = 32;
+pub const MAX_FIRMWARE_VERSION_LEN: usize = 32;
+pub const MAX_LATITUDE_E7: i32 = 900_000_000;
+pub const MAX_LONGITUDE_E7: i32 = 1_800_000_000;
+
+pub const SOULBOARD_PROGRAM_ID: Pubkey = pubkey!("915wZsHsUJ7Pdei1XUY8jtdfia7D8t4r9XkhGD3TvrDV");
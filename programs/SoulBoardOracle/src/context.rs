@@ -101,3 +101,139 @@ pub struct ReportDeviceMetrics<'info> {
 
     pub oracle_authority: Signer<'info>,
 }
+
+#[derive(Accounts)]
+#[instruction(device_idx: u64)]
+pub struct SetDeviceMetadata<'info> {
+    #[account(
+        seeds = [DEVICE_KEY, authority.key().as_ref(), &device_idx.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub device: Account<'info, Device>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + DeviceMetadata::INIT_SPACE,
+        seeds = [DEVICE_METADATA_KEY, device.key().as_ref()],
+        bump,
+    )]
+    pub metadata: Account<'info, DeviceMetadata>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(device_idx: u64)]
+pub struct CloseDevice<'info> {
+    #[account(
+        mut,
+        seeds = [DEVICE_REGISTRY_KEY, authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, DeviceRegistry>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [DEVICE_KEY, authority.key().as_ref(), &device_idx.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub device: Account<'info, Device>,
+
+    /// CHECK: device metadata PDA; closed alongside the device if it was ever created
+    #[account(mut, seeds = [DEVICE_METADATA_KEY, device.key().as_ref()], bump)]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(device_idx: u64)]
+pub struct RecordDeviceHeartbeat<'info> {
+    #[account(
+        mut,
+        seeds = [DEVICE_KEY, device_authority.key().as_ref(), &device_idx.to_le_bytes()],
+        bump,
+    )]
+    pub device: Account<'info, Device>,
+
+    /// CHECK: used for PDA seeds and ownership verification
+    pub device_authority: AccountInfo<'info>,
+
+    pub oracle_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(device_idx: u64)]
+pub struct SetDeviceStaleAfter<'info> {
+    #[account(
+        mut,
+        seeds = [DEVICE_KEY, authority.key().as_ref(), &device_idx.to_le_bytes()],
+        bump,
+        has_one = authority,
+    )]
+    pub device: Account<'info, Device>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRegistry<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [DEVICE_REGISTRY_KEY, authority.key().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub registry: Account<'info, DeviceRegistry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(device_idx: u64)]
+pub struct UpdateDeviceBookings<'info> {
+    #[account(
+        mut,
+        seeds = [DEVICE_KEY, device_authority.key().as_ref(), &device_idx.to_le_bytes()],
+        bump,
+    )]
+    pub device: Account<'info, Device>,
+
+    /// CHECK: used for PDA seeds and ownership verification
+    pub device_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [BOOKING_AUTHORITY_KEY],
+        bump,
+        seeds::program = SOULBOARD_PROGRAM_ID,
+    )]
+    pub booking_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(device_idx: u64)]
+pub struct MigrateDevice<'info> {
+    /// CHECK: pre-upgrade device; layout is validated in the instruction
+    #[account(
+        mut,
+        seeds = [DEVICE_KEY, authority.key().as_ref(), &device_idx.to_le_bytes()],
+        bump,
+    )]
+    pub device: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
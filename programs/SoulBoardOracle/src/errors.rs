@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+/// Codes are positional and clients match on them: add new variants at the end.
 #[error_code]
 pub enum OracleError {
     #[msg("Invalid authority")]
@@ -22,4 +23,19 @@ pub enum OracleError {
 
     #[msg("Arithmetic underflow")]
     ArithmeticUnderflow,
+
+    #[msg("Device has active bookings")]
+    DeviceHasActiveBookings,
+
+    #[msg("Registry still has devices")]
+    RegistryHasDevices,
+
+    #[msg("Invalid string length")]
+    InvalidStringLength,
+
+    #[msg("Coordinates out of range")]
+    InvalidCoordinates,
+
+    #[msg("Device is not in a pre-upgrade layout")]
+    AccountAlreadyMigrated,
 }
@@ -1,12 +1,21 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 
+use crate::constants::{
+    ANCHOR_DISCRIMINATOR_SIZE, DEFAULT_STALE_AFTER_SECS, MAX_FIRMWARE_VERSION_LEN, MAX_LATITUDE_E7, MAX_LONGITUDE_E7,
+    MAX_MIME_TYPES, MAX_MIME_TYPE_LEN,
+};
 use crate::context::{
-    RegisterDevice, ReportDeviceMetrics, SetDeviceStatus, UpdateDeviceLocation, UpdateDeviceOracle,
+    CloseDevice, MigrateDevice, RecordDeviceHeartbeat, RegisterDevice, ReportDeviceMetrics, SetDeviceMetadata,
+    SetDeviceStaleAfter, SetDeviceStatus, UpdateDeviceBookings, UpdateDeviceLocation,
+    UpdateDeviceOracle,
 };
 use crate::errors::OracleError;
 use crate::states::{
-    DeviceLocationUpdated, DeviceMetrics, DeviceMetricsReported, DeviceOracleUpdated,
-    DeviceRegistered, DeviceStatus, DeviceStatusUpdated,
+    Device, DeviceClosed, DeviceHeartbeat, DeviceHeartbeatRecorded, DeviceLocationUpdated,
+    DeviceMetadataUpdated, DeviceMetrics, DeviceMetricsReported, DeviceOracleUpdated,
+    DeviceMigrated, DeviceRegistered, DeviceSpecs, DeviceStaleAfterUpdated, DeviceStatus,
+    DeviceStatusUpdated, LegacyDevice,
 };
 
 pub fn register_device(
@@ -27,6 +36,13 @@ pub fn register_device(
         total_impressions: 0,
         last_reported_at: 0,
     };
+    device.active_bookings = 0;
+    device.heartbeat = DeviceHeartbeat {
+        last_heartbeat_at: 0,
+        online_since: 0,
+        total_uptime_secs: 0,
+        stale_after_secs: DEFAULT_STALE_AFTER_SECS,
+    };
 
     registry.last_device_id = registry
         .last_device_id
@@ -130,7 +146,9 @@ pub fn report_device_metrics(
         .total_impressions
         .checked_add(impressions)
         .ok_or(OracleError::ArithmeticOverflow)?;
-    device.metrics.last_reported_at = Clock::get()?.unix_timestamp;
+    let now = Clock::get()?.unix_timestamp;
+    device.metrics.last_reported_at = now;
+    device.heartbeat.record(now)?;
 
     emit!(DeviceMetricsReported {
         device: device.key(),
@@ -143,3 +161,238 @@ pub fn report_device_metrics(
 
     Ok(())
 }
+
+pub fn record_device_heartbeat(
+    ctx: Context<RecordDeviceHeartbeat>,
+    _device_idx: u64,
+) -> Result<()> {
+    let device = &mut ctx.accounts.device;
+    require!(device.status == DeviceStatus::Active, OracleError::DeviceInactive);
+    require_keys_eq!(
+        device.authority,
+        ctx.accounts.device_authority.key(),
+        OracleError::InvalidAuthority
+    );
+    require_keys_eq!(
+        device.oracle_authority,
+        ctx.accounts.oracle_authority.key(),
+        OracleError::InvalidOracleAuthority
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    device.heartbeat.record(now)?;
+
+    emit!(DeviceHeartbeatRecorded {
+        device: device.key(),
+        online_since: device.heartbeat.online_since,
+        total_uptime_secs: device.heartbeat.total_uptime_secs,
+        reported_at: now,
+    });
+
+    Ok(())
+}
+
+pub fn set_device_stale_after(
+    ctx: Context<SetDeviceStaleAfter>,
+    _device_idx: u64,
+    stale_after_secs: u32,
+) -> Result<()> {
+    require!(stale_after_secs > 0, OracleError::InvalidParameters);
+
+    let device = &mut ctx.accounts.device;
+    device.heartbeat.stale_after_secs = stale_after_secs;
+
+    emit!(DeviceStaleAfterUpdated {
+        device: device.key(),
+        stale_after_secs,
+    });
+
+    Ok(())
+}
+
+pub fn set_device_metadata(
+    ctx: Context<SetDeviceMetadata>,
+    _device_idx: u64,
+    specs: DeviceSpecs,
+) -> Result<()> {
+    require!(
+        specs.resolution_width > 0 && specs.resolution_height > 0,
+        OracleError::InvalidParameters
+    );
+    require!(
+        specs.supported_mime_types.len() <= MAX_MIME_TYPES,
+        OracleError::InvalidParameters
+    );
+    for mime_type in specs.supported_mime_types.iter() {
+        require!(
+            !mime_type.is_empty() && mime_type.len() <= MAX_MIME_TYPE_LEN,
+            OracleError::InvalidStringLength
+        );
+    }
+    require!(
+        specs.firmware_version.len() <= MAX_FIRMWARE_VERSION_LEN,
+        OracleError::InvalidStringLength
+    );
+    require!(
+        (-MAX_LATITUDE_E7..=MAX_LATITUDE_E7).contains(&specs.latitude_e7)
+            && (-MAX_LONGITUDE_E7..=MAX_LONGITUDE_E7).contains(&specs.longitude_e7),
+        OracleError::InvalidCoordinates
+    );
+
+    let metadata = &mut ctx.accounts.metadata;
+    metadata.device = ctx.accounts.device.key();
+    metadata.specs = specs;
+    metadata.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(DeviceMetadataUpdated {
+        device: metadata.device,
+        metadata: metadata.key(),
+        specs: metadata.specs.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn close_device(ctx: Context<CloseDevice>, _device_idx: u64) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let device = &ctx.accounts.device;
+
+    require!(
+        device.active_bookings == 0,
+        OracleError::DeviceHasActiveBookings
+    );
+
+    registry.device_count = registry
+        .device_count
+        .checked_sub(1)
+        .ok_or(OracleError::ArithmeticUnderflow)?;
+
+    // The metadata PDA only exists once `set_device_metadata` ran; close it
+    // here so its rent is not stranded behind a device that no longer exists.
+    let metadata = ctx.accounts.metadata.to_account_info();
+    if metadata.owner == &crate::ID {
+        let authority = ctx.accounts.authority.to_account_info();
+        let rent = metadata.lamports();
+        **authority.try_borrow_mut_lamports()? = authority
+            .lamports()
+            .checked_add(rent)
+            .ok_or(OracleError::ArithmeticOverflow)?;
+        **metadata.try_borrow_mut_lamports()? = 0;
+        metadata.assign(&anchor_lang::system_program::ID);
+        metadata.realloc(0, false)?;
+    }
+
+    emit!(DeviceClosed {
+        device: device.key(),
+        authority: device.authority,
+        device_idx: device.device_idx,
+    });
+
+    Ok(())
+}
+
+pub fn lock_device_booking(ctx: Context<UpdateDeviceBookings>, _device_idx: u64) -> Result<()> {
+    let device = &mut ctx.accounts.device;
+    require_keys_eq!(
+        device.authority,
+        ctx.accounts.device_authority.key(),
+        OracleError::InvalidAuthority
+    );
+
+    device.active_bookings = device
+        .active_bookings
+        .checked_add(1)
+        .ok_or(OracleError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+pub fn release_device_booking(
+    ctx: Context<UpdateDeviceBookings>,
+    _device_idx: u64,
+) -> Result<()> {
+    let device = &mut ctx.accounts.device;
+    require_keys_eq!(
+        device.authority,
+        ctx.accounts.device_authority.key(),
+        OracleError::InvalidAuthority
+    );
+
+    device.active_bookings = device
+        .active_bookings
+        .checked_sub(1)
+        .ok_or(OracleError::ArithmeticUnderflow)?;
+
+    Ok(())
+}
+
+/// Grows a device registered before booking locks and heartbeats existed to the current
+/// layout. It starts with no active bookings and stays offline until its first heartbeat.
+pub fn migrate_device(ctx: Context<MigrateDevice>, _device_idx: u64) -> Result<()> {
+    let info = ctx.accounts.device.to_account_info();
+    require_keys_eq!(*info.owner, crate::ID, OracleError::InvalidParameters);
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() == ANCHOR_DISCRIMINATOR_SIZE + LegacyDevice::INIT_SPACE,
+            OracleError::AccountAlreadyMigrated
+        );
+        require!(
+            data[..ANCHOR_DISCRIMINATOR_SIZE] == *Device::DISCRIMINATOR,
+            OracleError::InvalidParameters
+        );
+        LegacyDevice::deserialize(&mut &data[ANCHOR_DISCRIMINATOR_SIZE..])
+            .map_err(|_| error!(OracleError::InvalidParameters))?
+    };
+    require_keys_eq!(
+        legacy.authority,
+        ctx.accounts.authority.key(),
+        OracleError::InvalidAuthority
+    );
+
+    let device = Device {
+        authority: legacy.authority,
+        device_idx: legacy.device_idx,
+        location: legacy.location,
+        oracle_authority: legacy.oracle_authority,
+        status: legacy.status,
+        metrics: legacy.metrics,
+        active_bookings: 0,
+        heartbeat: DeviceHeartbeat {
+            last_heartbeat_at: 0,
+            online_since: 0,
+            total_uptime_secs: 0,
+            stale_after_secs: DEFAULT_STALE_AFTER_SECS,
+        },
+    };
+
+    let space = ANCHOR_DISCRIMINATOR_SIZE + Device::INIT_SPACE;
+    let top_up = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(info.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    info.realloc(space, false)?;
+    {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        device.try_serialize(&mut writer)?;
+    }
+
+    emit!(DeviceMigrated {
+        device: info.key(),
+        authority: device.authority,
+    });
+
+    Ok(())
+}
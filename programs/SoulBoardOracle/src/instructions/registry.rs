@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::context::CreateDeviceRegistry;
-use crate::states::DeviceRegistryCreated;
+use crate::context::{CloseRegistry, CreateDeviceRegistry};
+use crate::errors::OracleError;
+use crate::states::{DeviceRegistryClosed, DeviceRegistryCreated};
 
 pub fn create_device_registry(ctx: Context<CreateDeviceRegistry>) -> Result<()> {
     let registry = &mut ctx.accounts.registry;
@@ -16,3 +17,15 @@ pub fn create_device_registry(ctx: Context<CreateDeviceRegistry>) -> Result<()>
 
     Ok(())
 }
+
+pub fn close_registry(ctx: Context<CloseRegistry>) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    require!(registry.device_count == 0, OracleError::RegistryHasDevices);
+
+    emit!(DeviceRegistryClosed {
+        registry: registry.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
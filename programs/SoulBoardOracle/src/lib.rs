@@ -6,7 +6,7 @@ pub mod instructions;
 pub mod states;
 
 use context::*;
-use states::DeviceStatus;
+use states::{DeviceSpecs, DeviceStatus};
 declare_id!("HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX");
 
 #[program]
@@ -17,6 +17,10 @@ pub mod soul_board_oracle {
         crate::instructions::registry::create_device_registry(ctx)
     }
 
+    pub fn close_registry(ctx: Context<CloseRegistry>) -> Result<()> {
+        crate::instructions::registry::close_registry(ctx)
+    }
+
     pub fn register_device(
         ctx: Context<RegisterDevice>,
         location: Pubkey,
@@ -62,4 +66,46 @@ pub mod soul_board_oracle {
             impressions,
         )
     }
+
+    pub fn record_device_heartbeat(
+        ctx: Context<RecordDeviceHeartbeat>,
+        device_idx: u64,
+    ) -> Result<()> {
+        crate::instructions::device::record_device_heartbeat(ctx, device_idx)
+    }
+
+    pub fn set_device_stale_after(
+        ctx: Context<SetDeviceStaleAfter>,
+        device_idx: u64,
+        stale_after_secs: u32,
+    ) -> Result<()> {
+        crate::instructions::device::set_device_stale_after(ctx, device_idx, stale_after_secs)
+    }
+
+    pub fn set_device_metadata(
+        ctx: Context<SetDeviceMetadata>,
+        device_idx: u64,
+        specs: DeviceSpecs,
+    ) -> Result<()> {
+        crate::instructions::device::set_device_metadata(ctx, device_idx, specs)
+    }
+
+    pub fn close_device(ctx: Context<CloseDevice>, device_idx: u64) -> Result<()> {
+        crate::instructions::device::close_device(ctx, device_idx)
+    }
+
+    pub fn lock_device_booking(ctx: Context<UpdateDeviceBookings>, device_idx: u64) -> Result<()> {
+        crate::instructions::device::lock_device_booking(ctx, device_idx)
+    }
+
+    pub fn release_device_booking(
+        ctx: Context<UpdateDeviceBookings>,
+        device_idx: u64,
+    ) -> Result<()> {
+        crate::instructions::device::release_device_booking(ctx, device_idx)
+    }
+
+    pub fn migrate_device(ctx: Context<MigrateDevice>, device_idx: u64) -> Result<()> {
+        crate::instructions::device::migrate_device(ctx, device_idx)
+    }
 }
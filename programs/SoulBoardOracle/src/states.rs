@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::OracleError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct DeviceRegistry {
@@ -17,6 +19,58 @@ pub struct Device {
     pub oracle_authority: Pubkey,
     pub status: DeviceStatus,
     pub metrics: DeviceMetrics,
+    pub active_bookings: u32,
+    pub heartbeat: DeviceHeartbeat,
+}
+
+impl Device {
+    /// A device is online while its last heartbeat is younger than `stale_after_secs`.
+    pub fn is_online(&self, now: i64) -> bool {
+        self.heartbeat.last_heartbeat_at > 0
+            && now.saturating_sub(self.heartbeat.last_heartbeat_at)
+                <= self.heartbeat.stale_after_secs as i64
+    }
+}
+
+/// `Device` as written before booking locks and heartbeats existed; read by `migrate_device`.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyDevice {
+    pub authority: Pubkey,
+    pub device_idx: u64,
+    pub location: Pubkey,
+    pub oracle_authority: Pubkey,
+    pub status: DeviceStatus,
+    pub metrics: DeviceMetrics,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DeviceMetadata {
+    pub device: Pubkey,
+    pub specs: DeviceSpecs,
+    pub updated_at: i64,
+}
+
+/// Hardware and firmware description of a screen. Coordinates are degrees scaled by 1e7.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
+pub struct DeviceSpecs {
+    pub resolution_width: u16,
+    pub resolution_height: u16,
+    pub orientation: ScreenOrientation,
+    pub width_mm: u32,
+    pub height_mm: u32,
+    #[max_len(8, 32)]
+    pub supported_mime_types: Vec<String>,
+    #[max_len(32)]
+    pub firmware_version: String,
+    pub latitude_e7: i32,
+    pub longitude_e7: i32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
+pub enum ScreenOrientation {
+    Landscape,
+    Portrait,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
@@ -32,6 +86,33 @@ pub struct DeviceMetrics {
     pub last_reported_at: i64,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Debug)]
+pub struct DeviceHeartbeat {
+    pub last_heartbeat_at: i64,
+    pub online_since: i64,
+    pub total_uptime_secs: u64,
+    pub stale_after_secs: u32,
+}
+
+impl DeviceHeartbeat {
+    /// Extends the current uptime interval, or starts a new one if the device went stale.
+    pub fn record(&mut self, now: i64) -> Result<()> {
+        let elapsed = now
+            .checked_sub(self.last_heartbeat_at)
+            .ok_or(OracleError::ArithmeticUnderflow)?;
+        if self.last_heartbeat_at > 0 && elapsed <= self.stale_after_secs as i64 {
+            self.total_uptime_secs = self
+                .total_uptime_secs
+                .checked_add(elapsed as u64)
+                .ok_or(OracleError::ArithmeticOverflow)?;
+        } else {
+            self.online_since = now;
+        }
+        self.last_heartbeat_at = now;
+        Ok(())
+    }
+}
+
 #[event]
 pub struct DeviceRegistryCreated {
     pub registry: Pubkey,
@@ -74,3 +155,43 @@ pub struct DeviceMetricsReported {
     pub total_impressions: u64,
     pub reported_at: i64,
 }
+
+#[event]
+pub struct DeviceHeartbeatRecorded {
+    pub device: Pubkey,
+    pub online_since: i64,
+    pub total_uptime_secs: u64,
+    pub reported_at: i64,
+}
+
+#[event]
+pub struct DeviceStaleAfterUpdated {
+    pub device: Pubkey,
+    pub stale_after_secs: u32,
+}
+
+#[event]
+pub struct DeviceMetadataUpdated {
+    pub device: Pubkey,
+    pub metadata: Pubkey,
+    pub specs: DeviceSpecs,
+}
+
+#[event]
+pub struct DeviceClosed {
+    pub device: Pubkey,
+    pub authority: Pubkey,
+    pub device_idx: u64,
+}
+
+#[event]
+pub struct DeviceRegistryClosed {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct DeviceMigrated {
+    pub device: Pubkey,
+    pub authority: Pubkey,
+}
@@ -8,13 +8,63 @@ pub const CAMPAIGN_LOCATION_KEY: &[u8] = b"campaign_location";
 pub const LOCATION_SCHEDULE_KEY: &[u8] = b"location_schedule";
 pub const CAMPAIGN_BOOKING_KEY: &[u8] = b"campaign_booking";
 pub const SOULBOARD_CONFIG_KEY: &[u8] = b"soulboard_config";
+pub const DELEGATE_KEY: &[u8] = b"delegate";
+pub const AGENCY_KEY: &[u8] = b"agency";
+pub const FEE_OVERRIDE_KEY: &[u8] = b"fee_override";
+pub const TREASURY_KEY: &[u8] = b"treasury";
+pub const PROVIDER_EARNINGS_KEY: &[u8] = b"provider_earnings";
+pub const CAMPAIGN_LOCATION_SPEND_KEY: &[u8] = b"campaign_location_spend";
+pub const LOCATION_POLICY_KEY: &[u8] = b"location_policy";
+pub const BOOKING_ORDER_KEY: &[u8] = b"booking_order";
 
 pub const MAX_CAMPAIGN_NAME_LEN: usize = 64;
 pub const MAX_CAMPAIGN_DESC_LEN: usize = 256;
 pub const MAX_CAMPAIGN_IMAGE_URL_LEN: usize = 256;
 pub const MAX_LOCATION_NAME_LEN: usize = 64;
 pub const MAX_LOCATION_DESC_LEN: usize = 256;
+pub const MAX_LATITUDE_E7: i32 = 900_000_000;
+pub const MAX_LONGITUDE_E7: i32 = 1_800_000_000;
+pub const MAX_UTC_OFFSET_MINUTES: i16 = 14 * 60;
+pub const MINUTES_PER_DAY: u16 = 24 * 60;
+pub const SECONDS_PER_DAY: i64 = 86_400;
+pub const TREASURY_PERIOD_SECS: i64 = 30 * SECONDS_PER_DAY;
+pub const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+pub const MAX_TREASURY_BENEFICIARIES: usize = 8;
+pub const MAX_TARGETING_PROVIDERS: usize = 8;
+pub const MAX_POLICY_ADVERTISERS: usize = 16;
+pub const MAX_APPROVAL_WINDOW_SECS: u32 = 7 * 86_400;
+pub const MAX_SLOT_HOLD_SECS: u32 = 15 * 60;
+pub const MAX_CANCEL_CUTOFF_SECS: u32 = 30 * 86_400;
+pub const DEFAULT_SETTLEMENT_GRACE_SECS: u32 = 3 * 86_400;
+pub const MAX_SETTLEMENT_GRACE_SECS: u32 = 90 * 86_400;
+pub const DEFAULT_PROVIDER_CANCEL_COMPENSATION_BPS: u16 = 1_000;
 
 pub const MAX_SLOTS_PER_SCHEDULE: u32 = 1000;
 pub const PLATFORM_FEE_BPS: u64 = 250;
 pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_AGENCY_COMMISSION_BPS: u16 = 3_000;
+
+// Advertiser-side permissions, granted by a delegate scoped to an `Advertiser`.
+pub const PERMISSION_MANAGE_BUDGET: u16 = 1 << 0;
+pub const PERMISSION_BOOK: u16 = 1 << 1;
+pub const PERMISSION_CANCEL: u16 = 1 << 2;
+pub const PERMISSION_EDIT_METADATA: u16 = 1 << 4;
+pub const ADVERTISER_PERMISSIONS: u16 =
+    PERMISSION_MANAGE_BUDGET | PERMISSION_BOOK | PERMISSION_CANCEL | PERMISSION_EDIT_METADATA;
+
+// Provider-side permissions, granted by a delegate scoped to a `Provider`.
+pub const PERMISSION_EDIT_SLOTS: u16 = 1 << 3;
+/// Booking rules of a location: approval, cancellation, modification and advertiser policy.
+pub const PERMISSION_EDIT_POLICY: u16 = 1 << 5;
+/// Location details, price, status, operating hours and referral.
+pub const PERMISSION_EDIT_LOCATION: u16 = 1 << 6;
+pub const PERMISSION_PROVIDER_CANCEL: u16 = 1 << 7;
+/// Accepting or rejecting bookings pending provider approval.
+pub const PERMISSION_APPROVE_BOOKINGS: u16 = 1 << 8;
+pub const PROVIDER_PERMISSIONS: u16 = PERMISSION_EDIT_SLOTS
+    | PERMISSION_EDIT_POLICY
+    | PERMISSION_EDIT_LOCATION
+    | PERMISSION_PROVIDER_CANCEL
+    | PERMISSION_APPROVE_BOOKINGS;
+/// Permissions granted to an agency operator when an advertiser links to it.
+pub const AGENCY_PERMISSIONS: u16 = ADVERTISER_PERMISSIONS;
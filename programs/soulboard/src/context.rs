@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use soul_board_oracle::constants::BOOKING_AUTHORITY_KEY;
+use soul_board_oracle::program::SoulBoardOracle;
 use crate::states::*;
 use crate::constant::*;
+use crate::errors::SoulboardError;
 
 #[derive(Accounts)]
 pub struct CreateAdvertiser<'info> {
@@ -32,6 +35,15 @@ pub struct InitializeConfig<'info> {
     )]
     pub config: Account<'info, SoulboardConfig>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Treasury::INIT_SPACE,
+        seeds = [TREASURY_KEY],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -39,18 +51,20 @@ pub struct InitializeConfig<'info> {
 pub struct CreateCampaign<'info> { 
     #[account(
         mut,
-        seeds = [ADVERTISER_KEY ,authority.key().as_ref() ],
+        seeds = [ADVERTISER_KEY, advertiser.creator.as_ref()],
         bump,
-        has_one = authority,
     )]
     pub advertiser: Account<'info, Advertiser>,
 
+    #[account(seeds = [AGENCY_KEY, agency.creator.as_ref()], bump)]
+    pub agency: Option<Account<'info, Agency>>,
+
 
     #[account(
         init,
         payer = authority, 
         space = ANCHOR_DISCRIMINATOR_SIZE + Campaign::INIT_SPACE,
-        seeds = [CAMPAIGN_KEY , authority.key().as_ref() , &advertiser.last_campaign_id.to_le_bytes() ],
+        seeds = [CAMPAIGN_KEY, advertiser.creator.as_ref(), &advertiser.last_campaign_id.to_le_bytes()],
         bump,
     )]
     pub campaign: Account<'info, Campaign>,
@@ -64,9 +78,12 @@ pub struct CreateCampaign<'info> {
 #[derive(Accounts)]
 #[instruction(campaign_idx: u64)]
 pub struct AddBudget<'info> {
-    #[account(mut,has_one = authority,seeds = [CAMPAIGN_KEY, authority.key().as_ref() , &campaign_idx.to_le_bytes()],bump)]
+    #[account(mut,seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()],bump)]
     pub campaign: Account<'info, Campaign>,
 
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -79,27 +96,90 @@ pub struct AddBudget<'info> {
 #[instruction(campaign_idx: u64)]
 pub struct WithdrawBudget<'info> {
 
-    #[account(mut,has_one = authority,seeds = [CAMPAIGN_KEY, authority.key().as_ref() , &campaign_idx.to_le_bytes()],bump)]
+    #[account(mut,seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()],bump)]
     pub campaign: Account<'info, Campaign>,
 
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// CHECK: campaign owner receiving the withdrawn budget
+    #[account(mut, address = campaign.authority)]
+    pub owner: AccountInfo<'info>,
+
 }
 
 #[derive(Accounts)]
 #[instruction(campaign_idx: u64)]
-pub struct CloseCampaign<'info> {
-    #[account(mut,seeds = [ADVERTISER_KEY , authority.key().as_ref() ],bump,has_one = authority)]
+pub struct SetCampaignStatus<'info> {
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64)]
+pub struct EndCampaign<'info> {
+    #[account(mut,seeds = [ADVERTISER_KEY, advertiser.creator.as_ref()],bump,has_one = authority)]
+    pub advertiser: Account<'info, Advertiser>,
+
+    #[account(mut,has_one = authority,constraint = campaign.creator == advertiser.creator,seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()],bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64)]
+pub struct SetCampaignTargeting<'info> {
+    #[account(mut, has_one = authority, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    pub authority: Signer<'info>,
+}
 
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64)]
+pub struct ExpireCampaign<'info> {
+    #[account(mut, seeds = [ADVERTISER_KEY, advertiser.creator.as_ref()], bump)]
     pub advertiser: Account<'info, Advertiser>,
-   
-    #[account(mut,close = authority,has_one = authority,seeds = [CAMPAIGN_KEY, authority.key().as_ref() , &campaign_idx.to_le_bytes()],bump)]
+
+    #[account(mut, constraint = campaign.creator == advertiser.creator, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: campaign owner receiving the released budget
+    #[account(mut, address = campaign.authority)]
+    pub owner: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64)]
+pub struct ArchiveCampaign<'info> {
+    #[account(mut,close = authority,has_one = authority,seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()],bump)]
     pub campaign: Account<'info, Campaign>,
-   
+
     #[account(mut)]
     pub authority: Signer<'info>,
+}
+
+/// Accounts of the pre-split `close_campaign`, kept so existing clients still work.
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64)]
+pub struct CloseCampaign<'info> {
+    #[account(mut,seeds = [ADVERTISER_KEY, advertiser.creator.as_ref()],bump,has_one = authority)]
+    pub advertiser: Account<'info, Advertiser>,
 
+    #[account(mut,close = authority,has_one = authority,constraint = campaign.creator == advertiser.creator,seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()],bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -117,13 +197,21 @@ pub struct CreateProvider<'info> {
         bump,
     )]
     pub provider: Account<'info, Provider>,
-    
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + ProviderEarnings::INIT_SPACE,
+        seeds = [PROVIDER_EARNINGS_KEY, provider.key().as_ref()],
+        bump,
+    )]
+    pub earnings: Account<'info, ProviderEarnings>,
 }
 
 
 #[derive(Accounts)]
 pub struct RegisterLocation<'info> {
-    #[account(mut,seeds = [PROVIDER_KEY, authority.key().as_ref() ,],bump,has_one = authority)]
+    #[account(mut,seeds = [PROVIDER_KEY, provider.creator.as_ref()],bump,has_one = authority)]
     pub provider: Account<'info, Provider>,
 
     #[account(mut)]
@@ -133,7 +221,7 @@ pub struct RegisterLocation<'info> {
         init,
         payer = authority,
         space = ANCHOR_DISCRIMINATOR_SIZE + Location::INIT_SPACE,
-        seeds = [LOCATION_KEY, authority.key().as_ref() , &provider.last_location_id.to_le_bytes()],
+        seeds = [LOCATION_KEY, provider.creator.as_ref(), &provider.last_location_id.to_le_bytes()],
         bump,
     )]
     pub location: Account<'info, Location>,
@@ -146,58 +234,133 @@ pub struct RegisterLocation<'info> {
 #[derive(Accounts)]
 #[instruction(campaign_idx: u64)]
 pub struct UpdateCampaign<'info> {
-    #[account(mut, has_one = authority, seeds = [CAMPAIGN_KEY, authority.key().as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
     pub campaign: Account<'info, Campaign>,
 
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
     pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
 #[instruction(location_idx: u64)]
 pub struct UpdateLocationDetails<'info> {
-    #[account(seeds = [PROVIDER_KEY, authority.key().as_ref() ], bump, has_one = authority)]
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY, authority.key().as_ref(), &location_idx.to_le_bytes()], bump)]
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
     pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
 #[instruction(location_idx: u64)]
 pub struct UpdateLocationPrice<'info> {
-    #[account(seeds = [PROVIDER_KEY, authority.key().as_ref() ], bump, has_one = authority)]
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct SetLocationOperatingHours<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY, authority.key().as_ref(), &location_idx.to_le_bytes()], bump)]
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
     pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
 #[instruction(location_idx: u64)]
 pub struct SetLocationStatus<'info> {
-    #[account(seeds = [PROVIDER_KEY, authority.key().as_ref() ], bump, has_one = authority)]
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct SetLocationApproval<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct SetLocationCancellation<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct SetLocationModifications<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY, authority.key().as_ref(), &location_idx.to_le_bytes()], bump)]
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
     pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
 #[instruction(campaign_idx: u64, location_idx: u64)]
 pub struct AddCampaignLocation<'info> {
-    #[account(mut, has_one = authority, seeds = [CAMPAIGN_KEY , authority.key().as_ref() , &campaign_idx.to_le_bytes()], bump)]
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
     pub campaign: Account<'info, Campaign>,
 
-    #[account(seeds = [PROVIDER_KEY, provider.authority.as_ref()], bump)]
+    #[account(mut, seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY , provider.authority.as_ref() , &location_idx.to_le_bytes()], bump)]
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
     #[account(
@@ -209,41 +372,95 @@ pub struct AddCampaignLocation<'info> {
     )]
     pub campaign_location: Account<'info, CampaignLocation>,
 
+    /// Required when the campaign caps spend per location.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + CampaignLocationSpend::INIT_SPACE,
+        seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()],
+        bump,
+    )]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    #[account(seeds = [LOCATION_POLICY_KEY, location.key().as_ref()], bump)]
+    pub location_policy: Option<Account<'info, LocationPolicy>>,
+
+    /// CHECK: validated via PDA derivation and owner check
+    #[account(mut)]
+    pub oracle_device: AccountInfo<'info>,
+
+    /// CHECK: used for PDA derivation and device authority validation
+    pub device_authority: AccountInfo<'info>,
+
+    /// CHECK: PDA signer for oracle device booking locks
+    #[account(seeds = [BOOKING_AUTHORITY_KEY], bump)]
+    pub booking_authority: AccountInfo<'info>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub oracle_program: Program<'info, SoulBoardOracle>,
 }
 
 #[derive(Accounts)]
 #[instruction(campaign_idx: u64, location_idx: u64)]
 pub struct RemoveCampaignLocation<'info> {
-    #[account(mut, has_one = authority, seeds = [CAMPAIGN_KEY , authority.key().as_ref() , &campaign_idx.to_le_bytes()], bump)]
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
     pub campaign: Account<'info, Campaign>,
 
-    #[account(seeds = [PROVIDER_KEY, provider.authority.as_ref()], bump)]
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY , provider.authority.as_ref() , &location_idx.to_le_bytes()], bump)]
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
     #[account(mut, seeds = [CAMPAIGN_LOCATION_KEY, campaign.key().as_ref(), location.key().as_ref()], bump)]
     pub campaign_location: Account<'info, CampaignLocation>,
 
+    /// Required when the booking charged the per-location spend counter.
+    #[account(mut, seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()], bump)]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    /// CHECK: delegate PDA the booking charged; credited back if it has not been revoked
+    #[account(
+        mut,
+        seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), campaign_location.charges.delegate.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub spend_delegate: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: validated via PDA derivation and owner check
+    #[account(mut)]
+    pub oracle_device: AccountInfo<'info>,
+
+    /// CHECK: used for PDA derivation and device authority validation
+    pub device_authority: AccountInfo<'info>,
+
+    /// CHECK: PDA signer for oracle device booking locks
+    #[account(seeds = [BOOKING_AUTHORITY_KEY], bump)]
+    pub booking_authority: AccountInfo<'info>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
+
+    pub oracle_program: Program<'info, SoulBoardOracle>,
 }
 
 #[derive(Accounts)]
-#[instruction(campaign_idx: u64, location_idx: u64, campaign_authority: Pubkey, provider_authority: Pubkey)]
+#[instruction(campaign_idx: u64, location_idx: u64)]
 pub struct SettleCampaignLocation<'info> {
-    #[account(mut, seeds = [CAMPAIGN_KEY , campaign_authority.as_ref() , &campaign_idx.to_le_bytes()], bump)]
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
     pub campaign: Account<'info, Campaign>,
 
-    #[account(seeds = [PROVIDER_KEY, provider_authority.as_ref()], bump)]
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY , provider_authority.as_ref() , &location_idx.to_le_bytes()], bump)]
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
     #[account(
@@ -254,22 +471,78 @@ pub struct SettleCampaignLocation<'info> {
     )]
     pub campaign_location: Account<'info, CampaignLocation>,
 
-    /// CHECK: receives settlement funds; validated in instruction
+    /// Required when the booking charged the per-location spend counter.
+    #[account(mut, seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()], bump)]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    /// CHECK: delegate PDA the booking charged; credited back if it has not been revoked
+    #[account(
+        mut,
+        seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), campaign_location.charges.delegate.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub spend_delegate: Option<UncheckedAccount<'info>>,
+
+    #[account(seeds = [SOULBOARD_CONFIG_KEY], bump)]
+    pub config: Account<'info, SoulboardConfig>,
+
+    /// CHECK: provider fee override PDA; may be uninitialized
+    #[account(seeds = [FEE_OVERRIDE_KEY, provider.key().as_ref()], bump)]
+    pub provider_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: location fee override PDA; may be uninitialized
+    #[account(seeds = [FEE_OVERRIDE_KEY, location.key().as_ref()], bump)]
+    pub location_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: validated via PDA derivation and owner check
+    #[account(mut)]
+    pub oracle_device: AccountInfo<'info>,
+
+    /// CHECK: used for PDA derivation and device authority validation
+    pub device_authority: AccountInfo<'info>,
+
+    /// CHECK: PDA signer for oracle device booking locks
+    #[account(seeds = [BOOKING_AUTHORITY_KEY], bump)]
+    pub booking_authority: AccountInfo<'info>,
+
+    #[account(mut, seeds = [PROVIDER_EARNINGS_KEY, provider.key().as_ref()], bump)]
+    pub earnings: Box<Account<'info, ProviderEarnings>>,
+
+    #[account(mut, seeds = [TREASURY_KEY], bump)]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(seeds = [AGENCY_KEY, agency.creator.as_ref()], bump)]
+    pub agency: Option<Box<Account<'info, Agency>>>,
+
+    /// CHECK: receives the agency commission; validated in instruction
+    #[account(mut)]
+    pub agency_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: receives the campaign referral payout; validated in instruction
+    #[account(mut)]
+    pub campaign_referrer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: receives the location referral payout; validated in instruction
     #[account(mut)]
-    pub location_authority: AccountInfo<'info>,
+    pub location_referrer: Option<UncheckedAccount<'info>>,
 
     pub oracle_authority: Signer<'info>,
+
+    pub oracle_program: Program<'info, SoulBoardOracle>,
 }
 
 #[derive(Accounts)]
 #[instruction(location_idx: u64, max_slots: u32)]
 pub struct CreateLocationSchedule<'info> {
-    #[account(seeds = [PROVIDER_KEY, authority.key().as_ref()], bump, has_one = authority)]
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY, authority.key().as_ref(), &location_idx.to_le_bytes()], bump)]
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
     #[account(
         init,
         payer = authority,
@@ -288,12 +561,15 @@ pub struct CreateLocationSchedule<'info> {
 #[derive(Accounts)]
 #[instruction(location_idx: u64)]
 pub struct AddLocationSlot<'info> {
-    #[account(seeds = [PROVIDER_KEY, authority.key().as_ref()], bump, has_one = authority)]
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY, authority.key().as_ref(), &location_idx.to_le_bytes()], bump)]
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
     #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
     pub schedule: Account<'info, LocationSchedule>,
 
@@ -301,40 +577,25 @@ pub struct AddLocationSlot<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(
-    campaign_idx: u64,
-    location_idx: u64,
-    range_start_ts: i64,
-    range_end_ts: i64,
-    device_idx: u64
-)]
-pub struct BookLocationRange<'info> {
-    #[account(mut, has_one = authority, seeds = [CAMPAIGN_KEY, authority.key().as_ref(), &campaign_idx.to_le_bytes()], bump)]
+#[instruction(campaign_idx: u64, location_idx: u64)]
+pub struct HoldLocationSlots<'info> {
+    #[account(seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
     pub campaign: Account<'info, Campaign>,
 
-    #[account(seeds = [PROVIDER_KEY, provider.authority.as_ref()], bump)]
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY, provider.authority.as_ref(), &location_idx.to_le_bytes()], bump)]
+    #[account(has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
     #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
     pub schedule: Account<'info, LocationSchedule>,
 
-    #[account(
-        init,
-        payer = authority,
-        space = ANCHOR_DISCRIMINATOR_SIZE + CampaignBooking::INIT_SPACE,
-        seeds = [
-            CAMPAIGN_BOOKING_KEY,
-            campaign.key().as_ref(),
-            location.key().as_ref(),
-            &range_start_ts.to_le_bytes(),
-            &range_end_ts.to_le_bytes()
-        ],
-        bump,
-    )]
-    pub booking: Account<'info, CampaignBooking>,
+    #[account(seeds = [LOCATION_POLICY_KEY, location.key().as_ref()], bump)]
+    pub location_policy: Option<Account<'info, LocationPolicy>>,
 
     /// CHECK: validated via PDA derivation and owner check
     pub oracle_device: AccountInfo<'info>,
@@ -342,97 +603,1013 @@ pub struct BookLocationRange<'info> {
     /// CHECK: used for PDA derivation and device authority validation
     pub device_authority: AccountInfo<'info>,
 
-    #[account(mut)]
     pub authority: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(campaign_idx: u64, location_idx: u64, range_start_ts: i64, range_end_ts: i64)]
-pub struct CancelLocationBooking<'info> {
-    #[account(mut, has_one = authority, seeds = [CAMPAIGN_KEY, authority.key().as_ref(), &campaign_idx.to_le_bytes()], bump)]
-    pub campaign: Account<'info, Campaign>,
-
-    #[account(seeds = [PROVIDER_KEY, provider.authority.as_ref()], bump)]
+#[instruction(location_idx: u64)]
+pub struct ReleaseSlotHolds<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY, provider.authority.as_ref(), &location_idx.to_le_bytes()], bump)]
+    #[account(has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
     #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
     pub schedule: Account<'info, LocationSchedule>,
+}
 
-    #[account(
-        mut,
-        seeds = [
-            CAMPAIGN_BOOKING_KEY,
-            campaign.key().as_ref(),
-            location.key().as_ref(),
-            &range_start_ts.to_le_bytes(),
-            &range_end_ts.to_le_bytes()
-        ],
-        bump,
-        close = campaign
-    )]
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct AcceptBooking<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(mut, has_one = location)]
     pub booking: Account<'info, CampaignBooking>,
 
-    #[account(mut)]
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
     pub authority: Signer<'info>,
 }
 
+/// Shared by `reject_booking` (provider) and `expire_pending_booking` (anyone, after the
+/// deadline).
 #[derive(Accounts)]
-#[instruction(
-    campaign_idx: u64,
-    location_idx: u64,
-    range_start_ts: i64,
-    range_end_ts: i64,
-    campaign_authority: Pubkey,
-    provider_authority: Pubkey
-)]
-pub struct SettleLocationBooking<'info> {
-    #[account(mut, seeds = [CAMPAIGN_KEY, campaign_authority.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+#[instruction(location_idx: u64)]
+pub struct ResolvePendingBooking<'info> {
+    #[account(mut)]
     pub campaign: Account<'info, Campaign>,
 
-    #[account(seeds = [PROVIDER_KEY, provider_authority.as_ref()], bump)]
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
     pub provider: Account<'info, Provider>,
 
-    #[account(mut, seeds = [LOCATION_KEY, provider_authority.as_ref(), &location_idx.to_le_bytes()], bump)]
+    #[account(has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
     pub location: Account<'info, Location>,
 
     #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
     pub schedule: Account<'info, LocationSchedule>,
 
+    #[account(mut, has_one = campaign, has_one = location, close = campaign)]
+    pub booking: Account<'info, CampaignBooking>,
+
+    /// Required when the booking belongs to a `BookingOrder`.
+    #[account(mut, has_one = campaign)]
+    pub order: Option<Account<'info, BookingOrder>>,
+
+    /// Required when the booking charged the per-location spend counter.
+    #[account(mut, seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()], bump)]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    /// CHECK: delegate PDA the booking charged; credited back if it has not been revoked
     #[account(
         mut,
-        seeds = [
-            CAMPAIGN_BOOKING_KEY,
-            campaign.key().as_ref(),
-            location.key().as_ref(),
-            &range_start_ts.to_le_bytes(),
-            &range_end_ts.to_le_bytes()
-        ],
+        seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), booking.charges.delegate.unwrap_or_default().as_ref()],
         bump,
-        close = campaign
     )]
-    pub booking: Account<'info, CampaignBooking>,
+    pub spend_delegate: Option<UncheckedAccount<'info>>,
 
-    #[account(mut, seeds = [SOULBOARD_CONFIG_KEY], bump)]
-    pub config: Account<'info, SoulboardConfig>,
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
 
-    /// CHECK: validated via PDA derivation and owner check
+    /// CHECK: validated against the booking and via the oracle program
+    #[account(mut, address = booking.device)]
     pub oracle_device: AccountInfo<'info>,
 
     /// CHECK: used for PDA derivation and device authority validation
+    #[account(address = booking.device_authority)]
     pub device_authority: AccountInfo<'info>,
 
-    /// CHECK: receives settlement funds; validated in instruction
-    #[account(mut)]
-    pub location_authority: AccountInfo<'info>,
+    /// CHECK: PDA signer for oracle device booking locks
+    #[account(seeds = [BOOKING_AUTHORITY_KEY], bump)]
+    pub booking_authority: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
 
-    /// CHECK: receives platform fee; validated in instruction
+    pub oracle_program: Program<'info, SoulBoardOracle>,
+}
+
+/// Provider-initiated cancellation; compensation is paid from the provider's earnings vault.
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct ProviderCancelBooking<'info> {
     #[account(mut)]
-    pub treasury: AccountInfo<'info>,
+    pub campaign: Account<'info, Campaign>,
 
-    pub oracle_authority: Signer<'info>,
+    #[account(mut, seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(mut, has_one = location, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
+    pub schedule: Account<'info, LocationSchedule>,
+
+    #[account(mut, has_one = campaign, has_one = location, close = campaign)]
+    pub booking: Account<'info, CampaignBooking>,
+
+    #[account(mut, has_one = provider, seeds = [PROVIDER_EARNINGS_KEY, provider.key().as_ref()], bump)]
+    pub earnings: Box<Account<'info, ProviderEarnings>>,
+
+    /// Required when the booking belongs to a `BookingOrder`.
+    #[account(mut, has_one = campaign)]
+    pub order: Option<Account<'info, BookingOrder>>,
+
+    /// Required when the booking charged the per-location spend counter.
+    #[account(mut, seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()], bump)]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    /// CHECK: delegate PDA the booking charged; credited back if it has not been revoked
+    #[account(
+        mut,
+        seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), booking.charges.delegate.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub spend_delegate: Option<UncheckedAccount<'info>>,
+
+    #[account(seeds = [SOULBOARD_CONFIG_KEY], bump)]
+    pub config: Account<'info, SoulboardConfig>,
+
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    /// CHECK: validated against the booking and via the oracle program
+    #[account(mut, address = booking.device)]
+    pub oracle_device: AccountInfo<'info>,
+
+    /// CHECK: used for PDA derivation and device authority validation
+    #[account(address = booking.device_authority)]
+    pub device_authority: AccountInfo<'info>,
+
+    /// CHECK: PDA signer for oracle device booking locks
+    #[account(seeds = [BOOKING_AUTHORITY_KEY], bump)]
+    pub booking_authority: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub oracle_program: Program<'info, SoulBoardOracle>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    campaign_idx: u64,
+    location_idx: u64,
+    range_start_ts: i64,
+    range_end_ts: i64,
+    device_idx: u64
+)]
+pub struct BookLocationRange<'info> {
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut, seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
+    pub schedule: Account<'info, LocationSchedule>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + CampaignBooking::INIT_SPACE,
+        seeds = [
+            CAMPAIGN_BOOKING_KEY,
+            campaign.key().as_ref(),
+            location.key().as_ref(),
+            &range_start_ts.to_le_bytes(),
+            &range_end_ts.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub booking: Account<'info, CampaignBooking>,
+
+    #[account(seeds = [LOCATION_POLICY_KEY, location.key().as_ref()], bump)]
+    pub location_policy: Option<Account<'info, LocationPolicy>>,
+
+    /// Required when the campaign has a per-location pacing cap.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + CampaignLocationSpend::INIT_SPACE,
+        seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()],
+        bump,
+    )]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    /// CHECK: validated via PDA derivation and owner check
+    #[account(mut)]
+    pub oracle_device: AccountInfo<'info>,
+
+    /// CHECK: used for PDA derivation and device authority validation
+    pub device_authority: AccountInfo<'info>,
+
+    /// CHECK: PDA signer for oracle device booking locks
+    #[account(seeds = [BOOKING_AUTHORITY_KEY], bump)]
+    pub booking_authority: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub oracle_program: Program<'info, SoulBoardOracle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64)]
+pub struct CreateBookingOrder<'info> {
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + BookingOrder::INIT_SPACE,
+        seeds = [BOOKING_ORDER_KEY, campaign.key().as_ref(), &campaign.next_order_id.to_le_bytes()],
+        bump,
+    )]
+    pub order: Account<'info, BookingOrder>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64, order_id: u64, location_idx: u64)]
+pub struct BookOrderSlots<'info> {
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut, seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(
+        mut,
+        has_one = campaign,
+        seeds = [BOOKING_ORDER_KEY, campaign.key().as_ref(), &order_id.to_le_bytes()],
+        bump,
+    )]
+    pub order: Account<'info, BookingOrder>,
+
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
+    pub schedule: Account<'info, LocationSchedule>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + CampaignBooking::INIT_SPACE,
+        seeds = [CAMPAIGN_BOOKING_KEY, order.key().as_ref(), location.key().as_ref()],
+        bump,
+    )]
+    pub booking: Account<'info, CampaignBooking>,
+
+    #[account(seeds = [LOCATION_POLICY_KEY, location.key().as_ref()], bump)]
+    pub location_policy: Option<Account<'info, LocationPolicy>>,
+
+    /// Required when the campaign has a per-location pacing cap.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + CampaignLocationSpend::INIT_SPACE,
+        seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()],
+        bump,
+    )]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    /// CHECK: validated via PDA derivation and owner check
+    #[account(mut)]
+    pub oracle_device: AccountInfo<'info>,
+
+    /// CHECK: used for PDA derivation and device authority validation
+    pub device_authority: AccountInfo<'info>,
+
+    /// CHECK: PDA signer for oracle device booking locks
+    #[account(seeds = [BOOKING_AUTHORITY_KEY], bump)]
+    pub booking_authority: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub oracle_program: Program<'info, SoulBoardOracle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64, order_id: u64)]
+pub struct CloseBookingOrder<'info> {
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(
+        mut,
+        has_one = campaign,
+        close = campaign,
+        seeds = [BOOKING_ORDER_KEY, campaign.key().as_ref(), &order_id.to_le_bytes()],
+        bump,
+    )]
+    pub order: Account<'info, BookingOrder>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64, location_idx: u64)]
+pub struct ModifyBooking<'info> {
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut, seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
+    pub schedule: Account<'info, LocationSchedule>,
+
+    #[account(mut, has_one = campaign, has_one = location)]
+    pub booking: Account<'info, CampaignBooking>,
+
+    /// Required when the booking belongs to a `BookingOrder`.
+    #[account(mut, has_one = campaign)]
+    pub order: Option<Account<'info, BookingOrder>>,
+
+    #[account(seeds = [LOCATION_POLICY_KEY, location.key().as_ref()], bump)]
+    pub location_policy: Option<Account<'info, LocationPolicy>>,
+
+    /// CHECK: validated via PDA derivation, owner check and the booking's device
+    pub oracle_device: AccountInfo<'info>,
+
+    /// CHECK: used for PDA derivation and device authority validation
+    pub device_authority: AccountInfo<'info>,
+
+    /// Required when the campaign has a per-location pacing cap and the change costs more.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + CampaignLocationSpend::INIT_SPACE,
+        seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()],
+        bump,
+    )]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    /// CHECK: delegate PDA the booking charged; credited back when the owner reschedules
+    #[account(
+        mut,
+        seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), booking.charges.delegate.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub spend_delegate: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64, location_idx: u64)]
+pub struct CancelBookingSlots<'info> {
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
+    pub schedule: Account<'info, LocationSchedule>,
+
+    #[account(mut, has_one = campaign, has_one = location)]
+    pub booking: Account<'info, CampaignBooking>,
+
+    /// Required when the booking belongs to a `BookingOrder`.
+    #[account(mut, has_one = campaign)]
+    pub order: Option<Account<'info, BookingOrder>>,
+
+    /// Required when the booking charged the per-location spend counter.
+    #[account(mut, seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()], bump)]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    /// CHECK: delegate PDA the booking charged; credited back if it has not been revoked
+    #[account(
+        mut,
+        seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), booking.charges.delegate.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub spend_delegate: Option<UncheckedAccount<'info>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64, location_idx: u64)]
+pub struct CancelLocationBooking<'info> {
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
+    pub schedule: Account<'info, LocationSchedule>,
+
+    #[account(
+        mut,
+        has_one = campaign,
+        has_one = location,
+        close = campaign
+    )]
+    pub booking: Account<'info, CampaignBooking>,
+
+    /// Required when the booking belongs to a `BookingOrder`.
+    #[account(mut, has_one = campaign)]
+    pub order: Option<Account<'info, BookingOrder>>,
+
+    /// Required when the booking charged the per-location spend counter.
+    #[account(mut, seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()], bump)]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    /// CHECK: delegate PDA the booking charged; credited back if it has not been revoked
+    #[account(
+        mut,
+        seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), booking.charges.delegate.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub spend_delegate: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: validated against the booking and via the oracle program
+    #[account(mut)]
+    pub oracle_device: AccountInfo<'info>,
+
+    /// CHECK: used for PDA derivation and device authority validation
+    pub device_authority: AccountInfo<'info>,
+
+    /// CHECK: PDA signer for oracle device booking locks
+    #[account(seeds = [BOOKING_AUTHORITY_KEY], bump)]
+    pub booking_authority: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub oracle_program: Program<'info, SoulBoardOracle>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64, location_idx: u64)]
+pub struct SettleLocationBooking<'info> {
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
+    pub schedule: Account<'info, LocationSchedule>,
+
+    #[account(
+        mut,
+        has_one = campaign,
+        has_one = location,
+        close = campaign
+    )]
+    pub booking: Account<'info, CampaignBooking>,
+
+    /// Required when the booking belongs to a `BookingOrder`.
+    #[account(mut, has_one = campaign)]
+    pub order: Option<Account<'info, BookingOrder>>,
+
+    /// Required when the booking charged the per-location spend counter.
+    #[account(mut, seeds = [CAMPAIGN_LOCATION_SPEND_KEY, campaign.key().as_ref(), location.key().as_ref()], bump)]
+    pub location_spend: Option<Box<Account<'info, CampaignLocationSpend>>>,
+
+    /// CHECK: delegate PDA the booking charged; credited back if it has not been revoked
+    #[account(
+        mut,
+        seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), booking.charges.delegate.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub spend_delegate: Option<UncheckedAccount<'info>>,
+
+    #[account(mut, seeds = [SOULBOARD_CONFIG_KEY], bump)]
+    pub config: Account<'info, SoulboardConfig>,
+
+    /// CHECK: provider fee override PDA; may be uninitialized
+    #[account(seeds = [FEE_OVERRIDE_KEY, provider.key().as_ref()], bump)]
+    pub provider_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: location fee override PDA; may be uninitialized
+    #[account(seeds = [FEE_OVERRIDE_KEY, location.key().as_ref()], bump)]
+    pub location_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: validated via PDA derivation and owner check
+    #[account(mut)]
+    pub oracle_device: AccountInfo<'info>,
+
+    /// CHECK: used for PDA derivation and device authority validation
+    pub device_authority: AccountInfo<'info>,
+
+    /// CHECK: PDA signer for oracle device booking locks
+    #[account(seeds = [BOOKING_AUTHORITY_KEY], bump)]
+    pub booking_authority: AccountInfo<'info>,
+
+    #[account(mut, seeds = [PROVIDER_EARNINGS_KEY, provider.key().as_ref()], bump)]
+    pub earnings: Box<Account<'info, ProviderEarnings>>,
+
+    #[account(mut, seeds = [TREASURY_KEY], bump)]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(seeds = [AGENCY_KEY, agency.creator.as_ref()], bump)]
+    pub agency: Option<Box<Account<'info, Agency>>>,
+
+    /// CHECK: receives the agency commission; validated in instruction
+    #[account(mut)]
+    pub agency_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: receives the campaign referral payout; validated in instruction
+    #[account(mut)]
+    pub campaign_referrer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: receives the location referral payout; validated in instruction
+    #[account(mut)]
+    pub location_referrer: Option<UncheckedAccount<'info>>,
+
+    /// Omit to settle permissionlessly once the config grace period has passed.
+    pub oracle_authority: Option<Signer<'info>>,
+
+    pub oracle_program: Program<'info, SoulBoardOracle>,
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct SetDelegate<'info> {
+    /// CHECK: `Advertiser` or `Provider` owned by `owner`; validated in instruction
+    pub principal: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Delegate::INIT_SPACE,
+        seeds = [DELEGATE_KEY, principal.key().as_ref(), delegate.as_ref()],
+        bump,
+    )]
+    pub delegate_account: Account<'info, Delegate>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct RevokeDelegate<'info> {
+    /// CHECK: `Advertiser` or `Provider` owned by `owner`; validated in instruction
+    pub principal: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [DELEGATE_KEY, principal.key().as_ref(), delegate.as_ref()],
+        bump,
+    )]
+    pub delegate_account: Account<'info, Delegate>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferAdvertiser<'info> {
+    #[account(mut, seeds = [ADVERTISER_KEY, advertiser.creator.as_ref()], bump, has_one = authority)]
+    pub advertiser: Account<'info, Advertiser>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferProvider<'info> {
+    #[account(mut, seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump, has_one = authority)]
+    pub provider: Account<'info, Provider>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64)]
+pub struct SyncCampaignAuthority<'info> {
+    #[account(seeds = [ADVERTISER_KEY, campaign.creator.as_ref()], bump)]
+    pub advertiser: Account<'info, Advertiser>,
+
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+}
+
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct SyncLocationAuthority<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
+    pub schedule: Option<Account<'info, LocationSchedule>>,
+}
+
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct TransferLocation<'info> {
+    #[account(mut, seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump, has_one = authority)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        mut,
+        constraint = new_provider.authority == new_authority.key() @ SoulboardError::InvalidAuthority,
+        seeds = [PROVIDER_KEY, new_provider.creator.as_ref()],
+        bump,
+    )]
+    pub new_provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    /// CHECK: schedule PDA, always passed so live bookings cannot be hidden by omitting
+    /// it; deserialized in the handler when it has been created.
+    #[account(mut, seeds = [LOCATION_SCHEDULE_KEY, location.key().as_ref()], bump)]
+    pub schedule: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut, seeds = [SOULBOARD_CONFIG_KEY], bump, has_one = authority)]
+    pub config: Account<'info, SoulboardConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_idx: u64)]
+pub struct SetCampaignReferral<'info> {
+    #[account(mut, seeds = [CAMPAIGN_KEY, campaign.creator.as_ref(), &campaign_idx.to_le_bytes()], bump)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(seeds = [DELEGATE_KEY, campaign.advertiser.as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    /// CHECK: delegate PDA the new referrer would hold; must be uninitialized, validated in instruction
+    pub referrer_delegate: Option<UncheckedAccount<'info>>,
+
+    #[account(seeds = [SOULBOARD_CONFIG_KEY], bump)]
+    pub config: Account<'info, SoulboardConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct SetLocationReferral<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    /// CHECK: delegate PDA the new referrer would hold; must be uninitialized, validated in instruction
+    pub referrer_delegate: Option<UncheckedAccount<'info>>,
+
+    #[account(seeds = [SOULBOARD_CONFIG_KEY], bump)]
+    pub config: Account<'info, SoulboardConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct SetLocationPolicy<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + LocationPolicy::INIT_SPACE,
+        seeds = [LOCATION_POLICY_KEY, location.key().as_ref()],
+        bump,
+    )]
+    pub location_policy: Account<'info, LocationPolicy>,
+
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(location_idx: u64)]
+pub struct RemoveLocationPolicy<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [LOCATION_KEY, location.creator.as_ref(), &location_idx.to_le_bytes()], bump)]
+    pub location: Account<'info, Location>,
+
+    #[account(mut, close = owner, seeds = [LOCATION_POLICY_KEY, location.key().as_ref()], bump)]
+    pub location_policy: Account<'info, LocationPolicy>,
+
+    #[account(seeds = [DELEGATE_KEY, provider.key().as_ref(), authority.key().as_ref()], bump)]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: provider owner receiving the policy rent
+    #[account(mut, address = provider.authority)]
+    pub owner: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(target: Pubkey)]
+pub struct SetFeeOverride<'info> {
+    #[account(seeds = [SOULBOARD_CONFIG_KEY], bump, has_one = authority)]
+    pub config: Account<'info, SoulboardConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + FeeOverride::INIT_SPACE,
+        seeds = [FEE_OVERRIDE_KEY, target.as_ref()],
+        bump,
+    )]
+    pub fee_override: Account<'info, FeeOverride>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(target: Pubkey)]
+pub struct RemoveFeeOverride<'info> {
+    #[account(seeds = [SOULBOARD_CONFIG_KEY], bump, has_one = authority)]
+    pub config: Account<'info, SoulboardConfig>,
+
+    #[account(mut, close = authority, seeds = [FEE_OVERRIDE_KEY, target.as_ref()], bump)]
+    pub fee_override: Account<'info, FeeOverride>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(seeds = [SOULBOARD_CONFIG_KEY], bump, has_one = authority, has_one = treasury)]
+    pub config: Account<'info, SoulboardConfig>,
+
+    #[account(mut, seeds = [TREASURY_KEY], bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: receives the share of the withdrawal not assigned to split beneficiaries
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawEarnings<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump, has_one = authority)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, has_one = provider, seeds = [PROVIDER_EARNINGS_KEY, provider.key().as_ref()], bump)]
+    pub earnings: Account<'info, ProviderEarnings>,
+
+    /// CHECK: optional payout address; defaults to the provider authority
+    #[account(mut)]
+    pub payout: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferConfigAuthority<'info> {
+    #[account(mut, seeds = [SOULBOARD_CONFIG_KEY], bump, has_one = authority)]
+    pub config: Account<'info, SoulboardConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateAgency<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Agency::INIT_SPACE,
+        seeds = [AGENCY_KEY, authority.key().as_ref()],
+        bump,
+    )]
+    pub agency: Account<'info, Agency>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAgency<'info> {
+    #[account(mut, seeds = [AGENCY_KEY, agency.creator.as_ref()], bump, has_one = authority)]
+    pub agency: Account<'info, Agency>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct LinkAgency<'info> {
+    #[account(mut, seeds = [ADVERTISER_KEY, advertiser.creator.as_ref()], bump, has_one = authority)]
+    pub advertiser: Account<'info, Advertiser>,
+
+    #[account(mut, seeds = [AGENCY_KEY, agency.creator.as_ref()], bump)]
+    pub agency: Account<'info, Agency>,
+
+    /// Must not exist yet: a grant the advertiser already gave the operator is left alone.
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Delegate::INIT_SPACE,
+        seeds = [DELEGATE_KEY, advertiser.key().as_ref(), agency.authority.as_ref()],
+        bump,
+    )]
+    pub delegate_account: Account<'info, Delegate>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnlinkAgency<'info> {
+    #[account(mut, seeds = [ADVERTISER_KEY, advertiser.creator.as_ref()], bump, has_one = authority)]
+    pub advertiser: Account<'info, Advertiser>,
+
+    #[account(mut, seeds = [AGENCY_KEY, agency.creator.as_ref()], bump)]
+    pub agency: Account<'info, Agency>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [DELEGATE_KEY, advertiser.key().as_ref(), agency.authority.as_ref()],
+        bump,
+    )]
+    pub delegate_account: Account<'info, Delegate>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: pre-upgrade config; layout is validated in the instruction
+    #[account(mut, seeds = [SOULBOARD_CONFIG_KEY], bump)]
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Treasury::INIT_SPACE,
+        seeds = [TREASURY_KEY],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAdvertiser<'info> {
+    /// CHECK: pre-upgrade advertiser; layout and address are validated in the instruction
+    #[account(mut)]
+    pub advertiser: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateProvider<'info> {
+    /// CHECK: pre-upgrade provider; layout and address are validated in the instruction
+    #[account(mut)]
+    pub provider: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProviderEarnings<'info> {
+    #[account(seeds = [PROVIDER_KEY, provider.creator.as_ref()], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + ProviderEarnings::INIT_SPACE,
+        seeds = [PROVIDER_EARNINGS_KEY, provider.key().as_ref()],
+        bump,
+    )]
+    pub earnings: Account<'info, ProviderEarnings>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateCampaign<'info> {
+    /// CHECK: pre-upgrade campaign; layout and address are validated in the instruction
+    #[account(mut)]
+    pub campaign: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateLocation<'info> {
+    /// CHECK: pre-upgrade location; layout and address are validated in the instruction
+    #[account(mut)]
+    pub location: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
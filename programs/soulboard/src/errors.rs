@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+/// Codes are positional and clients match on them: add new variants at the end.
 #[error_code]
 pub enum SoulboardError {
     #[msg("Invalid authority")]
@@ -82,4 +83,85 @@ pub enum SoulboardError {
 
     #[msg("Arithmetic underflow")]
     ArithmeticUnderflow,
+
+    #[msg("Oracle device is offline")]
+    OracleDeviceOffline,
+
+    #[msg("Coordinates out of range")]
+    InvalidCoordinates,
+
+    #[msg("Slot is outside location operating hours")]
+    SlotOutsideOperatingHours,
+
+    #[msg("Delegate spend limit exceeded")]
+    DelegateSpendLimitExceeded,
+
+    #[msg("Location has live bookings")]
+    LocationHasBookings,
+
+    #[msg("Agency does not manage this advertiser")]
+    InvalidAgency,
+
+    #[msg("Agency still manages advertisers")]
+    AgencyHasAdvertisers,
+
+    #[msg("Referrer is a party to the campaign or location")]
+    InvalidReferrer,
+
+    #[msg("Campaign is not paused")]
+    CampaignNotPaused,
+
+    #[msg("Campaign has not ended")]
+    CampaignNotClosed,
+
+    #[msg("Booking range is outside the campaign window")]
+    OutsideCampaignWindow,
+
+    #[msg("Campaign has not reached its end time")]
+    CampaignNotExpired,
+
+    #[msg("Booking exceeds the campaign pacing limits")]
+    PacingLimitExceeded,
+
+    #[msg("Location does not match the campaign targeting")]
+    TargetingMismatch,
+
+    #[msg("Location policy does not accept this campaign")]
+    RejectedByLocationPolicy,
+
+    #[msg("Booking is not awaiting approval")]
+    BookingNotPending,
+
+    #[msg("Booking approval deadline has passed")]
+    ApprovalDeadlinePassed,
+
+    #[msg("Booking approval deadline has not passed")]
+    ApprovalDeadlineNotReached,
+
+    #[msg("Slot is already held by this campaign")]
+    SlotAlreadyHeld,
+
+    #[msg("Order still has live bookings")]
+    OrderHasBookings,
+
+    #[msg("Location does not allow partial cancellation")]
+    PartialCancelNotAllowed,
+
+    #[msg("Slot is inside the cancellation cutoff")]
+    CancellationCutoffPassed,
+
+    #[msg("Slot does not belong to this booking")]
+    SlotNotInBooking,
+
+    #[msg("Location does not allow booking modifications")]
+    ModificationsNotAllowed,
+
+    #[msg("Slots are not adjacent to the booking")]
+    SlotsNotAdjacent,
+
+    #[msg("Settlement grace period has not elapsed")]
+    SettlementGracePending,
+
+    #[msg("Account is not in a pre-upgrade layout")]
+    AccountAlreadyMigrated,
 }
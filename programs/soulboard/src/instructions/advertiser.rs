@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::context::{CreateAdvertiser, CreateProvider};
+use crate::context::{CreateAdvertiser, CreateProvider, TransferAdvertiser, TransferProvider};
+use crate::errors::SoulboardError;
+use crate::states::{AdvertiserTransferred, ProviderTransferred};
 
 pub fn create_advertiser(ctx: Context<CreateAdvertiser>) -> Result<()> {
     let advertiser = &mut ctx.accounts.advertiser;
     advertiser.authority = ctx.accounts.authority.key();
+    advertiser.creator = ctx.accounts.authority.key();
     advertiser.last_campaign_id = 0;
     advertiser.campaign_count = 0;
     Ok(())
@@ -13,7 +16,53 @@ pub fn create_advertiser(ctx: Context<CreateAdvertiser>) -> Result<()> {
 pub fn create_provider(ctx: Context<CreateProvider>) -> Result<()> {
     let provider = &mut ctx.accounts.provider;
     provider.authority = ctx.accounts.authority.key();
+    provider.creator = ctx.accounts.authority.key();
     provider.last_location_id = 0;
     provider.location_count = 0;
+    provider.cancellation_count = 0;
+
+    let earnings = &mut ctx.accounts.earnings;
+    earnings.provider = provider.key();
+    earnings.pending = 0;
+    earnings.withdrawn = 0;
+    earnings.lifetime = 0;
+    Ok(())
+}
+
+pub fn transfer_advertiser(ctx: Context<TransferAdvertiser>, new_authority: Pubkey) -> Result<()> {
+    require!(
+        new_authority != Pubkey::default(),
+        SoulboardError::InvalidParameters
+    );
+
+    let advertiser = &mut ctx.accounts.advertiser;
+    let previous_authority = advertiser.authority;
+    advertiser.authority = new_authority;
+
+    emit!(AdvertiserTransferred {
+        advertiser: advertiser.key(),
+        previous_authority,
+        new_authority,
+    });
+
+    Ok(())
+}
+
+pub fn transfer_provider(ctx: Context<TransferProvider>, new_authority: Pubkey) -> Result<()> {
+    require!(
+        new_authority != Pubkey::default(),
+        SoulboardError::InvalidParameters
+    );
+
+    let provider = &mut ctx.accounts.provider;
+    let previous_authority = provider.authority;
+    provider.authority = new_authority;
+
+    emit!(ProviderTransferred {
+        provider: provider.key(),
+        previous_authority,
+        new_authority,
+    });
+
     Ok(())
 }
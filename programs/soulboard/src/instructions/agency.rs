@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+
+use crate::constant::{AGENCY_PERMISSIONS, MAX_AGENCY_COMMISSION_BPS};
+use crate::context::{CreateAgency, LinkAgency, UnlinkAgency, UpdateAgency};
+use crate::errors::SoulboardError;
+use crate::states::{AdvertiserAgencyLinked, AdvertiserAgencyUnlinked, AgencyUpdated};
+
+pub fn create_agency(ctx: Context<CreateAgency>, commission_bps: u16) -> Result<()> {
+    require!(
+        commission_bps <= MAX_AGENCY_COMMISSION_BPS,
+        SoulboardError::InvalidParameters
+    );
+
+    let agency = &mut ctx.accounts.agency;
+    agency.authority = ctx.accounts.authority.key();
+    agency.creator = ctx.accounts.authority.key();
+    agency.commission_bps = commission_bps;
+    agency.advertiser_count = 0;
+
+    emit!(AgencyUpdated {
+        agency: agency.key(),
+        authority: agency.authority,
+        commission_bps,
+    });
+
+    Ok(())
+}
+
+/// Changes the commission applied to campaigns created from now on.
+pub fn set_agency_commission(ctx: Context<UpdateAgency>, commission_bps: u16) -> Result<()> {
+    require!(
+        commission_bps <= MAX_AGENCY_COMMISSION_BPS,
+        SoulboardError::InvalidParameters
+    );
+
+    let agency = &mut ctx.accounts.agency;
+    agency.commission_bps = commission_bps;
+
+    emit!(AgencyUpdated {
+        agency: agency.key(),
+        authority: agency.authority,
+        commission_bps,
+    });
+
+    Ok(())
+}
+
+/// Hands the agency to a new operator. Linked advertisers granted their delegate to the
+/// current operator, so they must unlink first; otherwise the old operator would keep
+/// its campaign permissions.
+pub fn transfer_agency(ctx: Context<UpdateAgency>, new_authority: Pubkey) -> Result<()> {
+    require!(
+        new_authority != Pubkey::default(),
+        SoulboardError::InvalidParameters
+    );
+
+    let agency = &mut ctx.accounts.agency;
+    require!(
+        agency.advertiser_count == 0,
+        SoulboardError::AgencyHasAdvertisers
+    );
+    agency.authority = new_authority;
+
+    emit!(AgencyUpdated {
+        agency: agency.key(),
+        authority: new_authority,
+        commission_bps: agency.commission_bps,
+    });
+
+    Ok(())
+}
+
+/// Opts an advertiser in to an agency and grants the agency operator campaign permissions.
+pub fn link_agency(ctx: Context<LinkAgency>) -> Result<()> {
+    let advertiser = &mut ctx.accounts.advertiser;
+    let agency = &mut ctx.accounts.agency;
+
+    require!(advertiser.agency.is_none(), SoulboardError::InvalidAgency);
+    require_keys_neq!(
+        agency.authority,
+        advertiser.authority,
+        SoulboardError::InvalidParameters
+    );
+
+    advertiser.agency = Some(agency.key());
+    agency.advertiser_count = agency
+        .advertiser_count
+        .checked_add(1)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+
+    let delegate_account = &mut ctx.accounts.delegate_account;
+    delegate_account.principal = advertiser.key();
+    delegate_account.owner = advertiser.authority;
+    delegate_account.delegate = agency.authority;
+    delegate_account.permissions = AGENCY_PERMISSIONS;
+
+    emit!(AdvertiserAgencyLinked {
+        advertiser: advertiser.key(),
+        agency: agency.key(),
+    });
+
+    Ok(())
+}
+
+/// Ends the agency relationship and closes the operator's delegate. Campaigns created
+/// under it keep their commission terms.
+pub fn unlink_agency(ctx: Context<UnlinkAgency>) -> Result<()> {
+    let advertiser = &mut ctx.accounts.advertiser;
+    let agency = &mut ctx.accounts.agency;
+
+    require!(
+        advertiser.agency == Some(agency.key()),
+        SoulboardError::InvalidAgency
+    );
+
+    advertiser.agency = None;
+    agency.advertiser_count = agency
+        .advertiser_count
+        .checked_sub(1)
+        .ok_or(SoulboardError::ArithmeticUnderflow)?;
+
+    emit!(AdvertiserAgencyUnlinked {
+        advertiser: advertiser.key(),
+        agency: agency.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::constant::PERMISSION_APPROVE_BOOKINGS;
+use crate::context::{AcceptBooking, ResolvePendingBooking};
+use crate::errors::SoulboardError;
+use crate::instructions::slot::credit_booking_spend;
+use crate::states::{
+    BookingStatus, CampaignBookingAccepted, CampaignBookingRejected, SlotStatus,
+};
+use crate::utils::{booking_order, refund_booking_escrow, release_oracle_device, require_owner_or_delegate};
+
+/// Confirms a pending booking before its approval deadline.
+pub fn accept_booking(ctx: Context<AcceptBooking>, _location_idx: u64) -> Result<()> {
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_APPROVE_BOOKINGS,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let booking = &mut ctx.accounts.booking;
+    require!(
+        booking.status == BookingStatus::Pending,
+        SoulboardError::BookingNotPending
+    );
+    require!(
+        matches!(booking.approval_deadline, Some(deadline) if now <= deadline),
+        SoulboardError::ApprovalDeadlinePassed
+    );
+
+    booking.status = BookingStatus::Active;
+    booking.approval_deadline = None;
+    booking.updated_at = now;
+
+    emit!(CampaignBookingAccepted {
+        booking: booking.key(),
+        campaign: booking.campaign,
+        location: booking.location,
+    });
+
+    Ok(())
+}
+
+/// Declines a pending booking, returning the escrow to the campaign.
+pub fn reject_booking(ctx: Context<ResolvePendingBooking>, _location_idx: u64) -> Result<()> {
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_APPROVE_BOOKINGS,
+    )?;
+    release_pending_booking(ctx, false)
+}
+
+/// Permissionless crank for pending bookings the provider never answered.
+pub fn expire_pending_booking(ctx: Context<ResolvePendingBooking>, _location_idx: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        matches!(ctx.accounts.booking.approval_deadline, Some(deadline) if now > deadline),
+        SoulboardError::ApprovalDeadlineNotReached
+    );
+    release_pending_booking(ctx, true)
+}
+
+fn release_pending_booking(ctx: Context<ResolvePendingBooking>, expired: bool) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let schedule = &mut ctx.accounts.schedule;
+    let booking = &mut ctx.accounts.booking;
+
+    require!(
+        booking.status == BookingStatus::Pending,
+        SoulboardError::BookingNotPending
+    );
+    require!(
+        schedule.location == ctx.accounts.location.key(),
+        SoulboardError::InvalidParameters
+    );
+
+    let refunded_amount = refund_booking_escrow(campaign, schedule, booking, SlotStatus::Available)?;
+    credit_booking_spend(
+        campaign,
+        None,
+        ctx.accounts.spend_delegate.as_deref(),
+        ctx.accounts.location_spend.as_deref_mut(),
+        &mut booking.charges,
+        refunded_amount,
+    )?;
+    if let Some(order) = booking_order(ctx.accounts.order.as_mut(), booking)? {
+        order.release(refunded_amount, true)?;
+    }
+    booking.status = BookingStatus::Cancelled;
+    booking.updated_at = Clock::get()?.unix_timestamp;
+
+    release_oracle_device(
+        &ctx.accounts.oracle_program.to_account_info(),
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        &ctx.accounts.booking_authority,
+        ctx.bumps.booking_authority,
+        booking.device_idx,
+    )?;
+
+    emit!(CampaignBookingRejected {
+        booking: booking.key(),
+        campaign: campaign.key(),
+        location: booking.location,
+        refunded_amount,
+        expired,
+    });
+
+    Ok(())
+}
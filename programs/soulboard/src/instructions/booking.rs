@@ -1,22 +1,50 @@
 use anchor_lang::prelude::*;
 
+use crate::constant::{PERMISSION_BOOK, PERMISSION_CANCEL};
 use crate::context::{AddCampaignLocation, RemoveCampaignLocation, SettleCampaignLocation};
 use crate::errors::SoulboardError;
+use crate::instructions::slot::{charge_booking_spend, credit_booking_spend};
 use crate::states::{
     CampaignLocationBooked, CampaignLocationCancelled, CampaignLocationSettled, CampaignLocationStatus,
     LocationStatus,
 };
-use crate::utils::{ensure_rent_exempt_after_withdraw, move_lamports, require_campaign_active};
+use crate::utils::{
+    ensure_rent_exempt_after_withdraw, load_oracle_device, lock_oracle_device,
+    move_lamports, pay_out_settlement, release_oracle_device, require_campaign_active,
+    require_owner_or_delegate, require_policy_admits, require_targeted, snapshot_referrals, SettlementPayees,
+};
 
 pub fn add_campaign_location(
     ctx: Context<AddCampaignLocation>,
     _campaign_idx: u64,
     _location_idx: u64,
+    device_idx: u64,
 ) -> Result<()> {
     let campaign = &mut ctx.accounts.campaign;
     let location = &mut ctx.accounts.location;
     let campaign_location = &mut ctx.accounts.campaign_location;
 
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_BOOK,
+    )?;
+
+    let device = load_oracle_device(
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        device_idx,
+    )?;
+    require_keys_eq!(device.location, location.key(), SoulboardError::InvalidOracleDevice);
+    require_keys_eq!(
+        device.oracle_authority,
+        location.oracle_authority,
+        SoulboardError::InvalidOracleAuthority
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(device.is_online(now), SoulboardError::OracleDeviceOffline);
+
     require_campaign_active(campaign)?;
     require_keys_eq!(
         ctx.accounts.provider.authority,
@@ -36,11 +64,28 @@ pub fn add_campaign_location(
     }
 
     let price = location.price;
+    require_targeted(campaign, location)?;
+    require_policy_admits(campaign, location, ctx.accounts.location_policy.as_deref())?;
+    if let Some(targeting) = &campaign.targeting {
+        require!(
+            targeting.allows_price(price),
+            SoulboardError::TargetingMismatch
+        );
+    }
     require!(
         campaign.available_budget >= price,
         SoulboardError::InsufficientBudget
     );
     ensure_rent_exempt_after_withdraw(&campaign.to_account_info(), price)?;
+    charge_booking_spend(
+        campaign,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref_mut(),
+        ctx.accounts.location_spend.as_deref_mut(),
+        location.key(),
+        &mut campaign_location.charges,
+        price,
+    )?;
 
     campaign.available_budget = campaign
         .available_budget
@@ -61,17 +106,28 @@ pub fn add_campaign_location(
         campaign: campaign.key(),
     };
 
-    let now = Clock::get()?.unix_timestamp;
     campaign_location.campaign = campaign.key();
     campaign_location.location = location.key();
     campaign_location.advertiser = campaign.authority;
     campaign_location.provider = location.authority;
     campaign_location.oracle_authority = location.oracle_authority;
+    campaign_location.device = ctx.accounts.oracle_device.key();
+    campaign_location.device_authority = ctx.accounts.device_authority.key();
+    campaign_location.device_idx = device_idx;
     campaign_location.price = price;
     campaign_location.status = CampaignLocationStatus::Active;
     campaign_location.created_at = now;
     campaign_location.updated_at = now;
-    campaign_location.settled_amount = 0;
+    campaign_location.referrals = snapshot_referrals(campaign, location)?;
+
+    lock_oracle_device(
+        &ctx.accounts.oracle_program.to_account_info(),
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        &ctx.accounts.booking_authority,
+        ctx.bumps.booking_authority,
+        device_idx,
+    )?;
 
     emit!(CampaignLocationBooked {
         campaign: campaign.key(),
@@ -91,6 +147,12 @@ pub fn remove_campaign_location(
     let location = &mut ctx.accounts.location;
     let campaign_location = &mut ctx.accounts.campaign_location;
 
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_CANCEL,
+    )?;
     require_keys_eq!(
         ctx.accounts.provider.authority,
         location.authority,
@@ -106,9 +168,27 @@ pub fn remove_campaign_location(
         }
         _ => return Err(SoulboardError::LocationUnavailable.into()),
     }
+    require_keys_eq!(
+        campaign_location.device,
+        ctx.accounts.oracle_device.key(),
+        SoulboardError::InvalidOracleDevice
+    );
+    require_keys_eq!(
+        campaign_location.device_authority,
+        ctx.accounts.device_authority.key(),
+        SoulboardError::InvalidOracleDevice
+    );
 
     let price = campaign_location.price;
     ensure_rent_exempt_after_withdraw(&campaign_location.to_account_info(), price)?;
+    credit_booking_spend(
+        campaign,
+        None,
+        ctx.accounts.spend_delegate.as_deref(),
+        ctx.accounts.location_spend.as_deref_mut(),
+        &mut campaign_location.charges,
+        price,
+    )?;
 
     campaign.available_budget = campaign
         .available_budget
@@ -129,6 +209,15 @@ pub fn remove_campaign_location(
     campaign_location.status = CampaignLocationStatus::Cancelled;
     campaign_location.updated_at = Clock::get()?.unix_timestamp;
 
+    release_oracle_device(
+        &ctx.accounts.oracle_program.to_account_info(),
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        &ctx.accounts.booking_authority,
+        ctx.bumps.booking_authority,
+        campaign_location.device_idx,
+    )?;
+
     emit!(CampaignLocationCancelled {
         campaign: campaign.key(),
         location: location.key(),
@@ -142,13 +231,34 @@ pub fn settle_campaign_location(
     ctx: Context<SettleCampaignLocation>,
     _campaign_idx: u64,
     _location_idx: u64,
-    _campaign_authority: Pubkey,
-    _provider_authority: Pubkey,
     settlement_amount: u64,
+    device_idx: u64,
 ) -> Result<()> {
     let campaign = &mut ctx.accounts.campaign;
     let location = &mut ctx.accounts.location;
     let campaign_location = &mut ctx.accounts.campaign_location;
+    let config = &ctx.accounts.config;
+
+    require_keys_eq!(
+        campaign_location.device,
+        ctx.accounts.oracle_device.key(),
+        SoulboardError::InvalidOracleDevice
+    );
+    let device = load_oracle_device(
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        device_idx,
+    )?;
+    require_keys_eq!(
+        device.location,
+        location.key(),
+        SoulboardError::InvalidOracleDevice
+    );
+    require_keys_eq!(
+        device.oracle_authority,
+        campaign_location.oracle_authority,
+        SoulboardError::InvalidOracleAuthority
+    );
 
     require_keys_eq!(
         ctx.accounts.provider.authority,
@@ -169,11 +279,6 @@ pub fn settle_campaign_location(
         campaign_location.oracle_authority,
         SoulboardError::InvalidOracleAuthority
     );
-    require_keys_eq!(
-        ctx.accounts.location_authority.key(),
-        location.authority,
-        SoulboardError::InvalidAuthority
-    );
 
     match location.location_status {
         LocationStatus::Booked { campaign: booked_campaign } => {
@@ -188,12 +293,16 @@ pub fn settle_campaign_location(
         .checked_sub(settlement_amount)
         .ok_or(SoulboardError::ArithmeticUnderflow)?;
     ensure_rent_exempt_after_withdraw(&campaign_location.to_account_info(), price)?;
-
-    move_lamports(
-        &campaign_location.to_account_info(),
-        &ctx.accounts.location_authority.to_account_info(),
-        settlement_amount,
+    credit_booking_spend(
+        campaign,
+        None,
+        ctx.accounts.spend_delegate.as_deref(),
+        ctx.accounts.location_spend.as_deref_mut(),
+        &mut campaign_location.charges,
+        refund,
     )?;
+
+    let now = Clock::get()?.unix_timestamp;
     move_lamports(
         &campaign_location.to_account_info(),
         &campaign.to_account_info(),
@@ -209,15 +318,47 @@ pub fn settle_campaign_location(
         .checked_add(refund)
         .ok_or(SoulboardError::ArithmeticOverflow)?;
 
+    let split = pay_out_settlement(
+        &campaign_location.to_account_info(),
+        campaign,
+        &campaign_location.referrals,
+        SettlementPayees {
+            config,
+            provider_fee_override: &ctx.accounts.provider_fee_override,
+            location_fee_override: &ctx.accounts.location_fee_override,
+            earnings: &mut ctx.accounts.earnings,
+            treasury: &mut ctx.accounts.treasury,
+            agency: ctx.accounts.agency.as_deref(),
+            agency_authority: ctx.accounts.agency_authority.as_deref(),
+            campaign_referrer: ctx.accounts.campaign_referrer.as_deref(),
+            location_referrer: ctx.accounts.location_referrer.as_deref(),
+        },
+        settlement_amount,
+        now,
+    )?;
+
     location.location_status = LocationStatus::Available;
     campaign_location.status = CampaignLocationStatus::Settled;
-    campaign_location.updated_at = Clock::get()?.unix_timestamp;
-    campaign_location.settled_amount = settlement_amount;
+    campaign_location.updated_at = now;
+
+    release_oracle_device(
+        &ctx.accounts.oracle_program.to_account_info(),
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        &ctx.accounts.booking_authority,
+        ctx.bumps.booking_authority,
+        campaign_location.device_idx,
+    )?;
 
     emit!(CampaignLocationSettled {
         campaign: campaign.key(),
         location: location.key(),
+        device: ctx.accounts.oracle_device.key(),
         settled_amount: settlement_amount,
+        fee_amount: split.fee_amount,
+        agency: campaign.agency,
+        agency_commission: split.agency_commission,
+        referral_amount: split.referral_amount,
         refunded_amount: refund,
     });
 
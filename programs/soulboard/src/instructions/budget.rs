@@ -1,15 +1,23 @@
 use anchor_lang::prelude::*;
 
+use crate::constant::PERMISSION_MANAGE_BUDGET;
 use crate::context::{AddBudget, WithdrawBudget};
 use crate::errors::SoulboardError;
 use crate::states::{BudgetAdded, BudgetWithdrawn};
 use crate::utils::{
-    ensure_rent_exempt_after_withdraw, move_lamports, require_campaign_active, transfer_from_signer,
+    ensure_rent_exempt_after_withdraw, move_lamports, require_campaign_open,
+    require_owner_or_delegate, transfer_from_signer,
 };
 
 pub fn add_budget(ctx: Context<AddBudget>, _campaign_idx: u64, amount: u64) -> Result<()> {
     require!(amount > 0, SoulboardError::InvalidParameters);
-    require_campaign_active(&ctx.accounts.campaign)?;
+    require_owner_or_delegate(
+        &ctx.accounts.campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_MANAGE_BUDGET,
+    )?;
+    require_campaign_open(&ctx.accounts.campaign)?;
 
     transfer_from_signer(
         &ctx.accounts.authority.to_account_info(),
@@ -35,7 +43,12 @@ pub fn add_budget(ctx: Context<AddBudget>, _campaign_idx: u64, amount: u64) -> R
 
 pub fn withdraw_budget(ctx: Context<WithdrawBudget>, _campaign_idx: u64, amount: u64) -> Result<()> {
     require!(amount > 0, SoulboardError::InvalidParameters);
-    require_campaign_active(&ctx.accounts.campaign)?;
+    require_owner_or_delegate(
+        &ctx.accounts.campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_MANAGE_BUDGET,
+    )?;
     require!(
         ctx.accounts.campaign.available_budget >= amount,
         SoulboardError::InsufficientBudget
@@ -56,7 +69,7 @@ pub fn withdraw_budget(ctx: Context<WithdrawBudget>, _campaign_idx: u64, amount:
 
     move_lamports(
         &ctx.accounts.campaign.to_account_info(),
-        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
         amount,
     )?;
 
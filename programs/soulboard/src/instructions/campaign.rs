@@ -2,11 +2,24 @@ use anchor_lang::prelude::*;
 
 use crate::constant::{
     MAX_CAMPAIGN_DESC_LEN, MAX_CAMPAIGN_IMAGE_URL_LEN, MAX_CAMPAIGN_NAME_LEN,
+    PERMISSION_EDIT_METADATA, PERMISSION_MANAGE_BUDGET,
+};
+use crate::context::{
+    ArchiveCampaign, CloseCampaign, CreateCampaign, EndCampaign, ExpireCampaign, SetCampaignReferral,
+    SetCampaignStatus, SetCampaignTargeting, SyncCampaignAuthority, UpdateCampaign,
 };
-use crate::context::{CloseCampaign, CreateCampaign, UpdateCampaign};
 use crate::errors::SoulboardError;
-use crate::states::{CampaignCreated, CampaignStatus, CampaignUpdated, CampaignClosed};
-use crate::utils::{ensure_string_len, require_campaign_active, set_optional_string, transfer_from_signer};
+use crate::states::{
+    Advertiser, Campaign, CampaignArchived, CampaignClosed, CampaignContentUpdated, CampaignCreated,
+    CampaignExpired, CampaignPacing, CampaignPacingUpdated, CampaignStatus, CampaignStatusChanged,
+    CampaignTargeting, CampaignTargetingUpdated, CampaignUpdated, CampaignWindowUpdated,
+    ContentCategory, Referral, ReferralUpdated,
+};
+use crate::utils::{
+    ensure_rent_exempt_after_withdraw, ensure_string_len, move_lamports, require_campaign_active,
+    require_campaign_open, require_owner_or_delegate, set_optional_string, transfer_from_signer,
+    require_independent_referrer, validate_referral, validate_targeting,
+};
 
 pub fn create_campaign(
     ctx: Context<CreateCampaign>,
@@ -30,8 +43,42 @@ pub fn create_campaign(
 
     let campaign = &mut ctx.accounts.campaign;
     let advertiser = &mut ctx.accounts.advertiser;
+    let signer = ctx.accounts.authority.key();
 
-    campaign.authority = ctx.accounts.authority.key();
+    let (agency, agency_commission_bps) = match advertiser.agency {
+        Some(linked) => {
+            let agency = ctx
+                .accounts
+                .agency
+                .as_ref()
+                .ok_or(SoulboardError::InvalidAgency)?;
+            require_keys_eq!(agency.key(), linked, SoulboardError::InvalidAgency);
+            require!(
+                signer == advertiser.authority || signer == agency.authority,
+                SoulboardError::Unauthorized
+            );
+            (Some(linked), agency.commission_bps)
+        }
+        None => {
+            require_keys_eq!(signer, advertiser.authority, SoulboardError::Unauthorized);
+            (None, 0)
+        }
+    };
+
+    campaign.authority = advertiser.authority;
+    campaign.creator = advertiser.creator;
+    campaign.advertiser = advertiser.key();
+    campaign.agency = agency;
+    campaign.agency_commission_bps = agency_commission_bps;
+    campaign.referral = None;
+    campaign.starts_at = None;
+    campaign.ends_at = None;
+    campaign.pacing = None;
+    campaign.pacing_window_start = 0;
+    campaign.pacing_window_spent = 0;
+    campaign.targeting = None;
+    campaign.content_categories = 0;
+    campaign.next_order_id = 0;
     campaign.campaign_name = campaign_name;
     campaign.campaign_idx = advertiser.last_campaign_id;
     campaign.campaign_description = campaign_description;
@@ -51,7 +98,7 @@ pub fn create_campaign(
 
     emit!(CampaignCreated {
         campaign: campaign.key(),
-        authority: ctx.accounts.authority.key(),
+        authority: campaign.authority,
         campaign_idx: campaign.campaign_idx,
     });
 
@@ -66,7 +113,13 @@ pub fn update_campaign(
     campaign_image_url: Option<String>,
 ) -> Result<()> {
     let campaign = &mut ctx.accounts.campaign;
-    require_campaign_active(campaign)?;
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_METADATA,
+    )?;
+    require_campaign_open(campaign)?;
 
     set_optional_string(&mut campaign.campaign_name, campaign_name, MAX_CAMPAIGN_NAME_LEN)?;
     set_optional_string(
@@ -87,11 +140,221 @@ pub fn update_campaign(
     Ok(())
 }
 
-pub fn close_campaign(ctx: Context<CloseCampaign>, _campaign_idx: u64) -> Result<()> {
-    let advertiser = &mut ctx.accounts.advertiser;
+/// Stops new bookings; existing bookings still settle or cancel as usual.
+pub fn pause_campaign(ctx: Context<SetCampaignStatus>, _campaign_idx: u64) -> Result<()> {
     let campaign = &mut ctx.accounts.campaign;
-
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_MANAGE_BUDGET,
+    )?;
     require_campaign_active(campaign)?;
+
+    campaign.status = CampaignStatus::Paused;
+
+    emit!(CampaignStatusChanged {
+        campaign: campaign.key(),
+        status: campaign.status.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn resume_campaign(ctx: Context<SetCampaignStatus>, _campaign_idx: u64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_MANAGE_BUDGET,
+    )?;
+    require!(
+        campaign.status == CampaignStatus::Paused,
+        SoulboardError::CampaignNotPaused
+    );
+
+    campaign.status = CampaignStatus::Active;
+
+    emit!(CampaignStatusChanged {
+        campaign: campaign.key(),
+        status: campaign.status.clone(),
+    });
+
+    Ok(())
+}
+
+/// Sets the window booked ranges must fall inside. `None` leaves that side open.
+pub fn set_campaign_window(
+    ctx: Context<UpdateCampaign>,
+    _campaign_idx: u64,
+    starts_at: Option<i64>,
+    ends_at: Option<i64>,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_MANAGE_BUDGET,
+    )?;
+    require_campaign_open(campaign)?;
+    if let (Some(starts_at), Some(ends_at)) = (starts_at, ends_at) {
+        require!(starts_at < ends_at, SoulboardError::InvalidTimeRange);
+    }
+
+    campaign.starts_at = starts_at;
+    campaign.ends_at = ends_at;
+
+    emit!(CampaignWindowUpdated {
+        campaign: campaign.key(),
+        starts_at,
+        ends_at,
+    });
+
+    Ok(())
+}
+
+/// Sets or clears the campaign's spend pacing. Counters carry over so a tighter cap
+/// applies to spend already booked in the current window.
+pub fn set_campaign_pacing(
+    ctx: Context<UpdateCampaign>,
+    _campaign_idx: u64,
+    pacing: Option<CampaignPacing>,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_MANAGE_BUDGET,
+    )?;
+    require_campaign_open(campaign)?;
+    if let Some(pacing) = &pacing {
+        let limits = [
+            pacing.max_per_period,
+            pacing.max_per_booking,
+            pacing.max_per_location,
+        ];
+        require!(
+            limits.iter().flatten().all(|limit| *limit > 0),
+            SoulboardError::InvalidParameters
+        );
+    }
+
+    campaign.pacing = pacing;
+
+    emit!(CampaignPacingUpdated {
+        campaign: campaign.key(),
+        pacing,
+    });
+
+    Ok(())
+}
+
+pub fn set_campaign_content(
+    ctx: Context<UpdateCampaign>,
+    _campaign_idx: u64,
+    content_categories: u16,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_METADATA,
+    )?;
+    require_campaign_open(campaign)?;
+    require!(
+        content_categories & !ContentCategory::ALL_MASK == 0,
+        SoulboardError::InvalidParameters
+    );
+
+    campaign.content_categories = content_categories;
+
+    emit!(CampaignContentUpdated {
+        campaign: campaign.key(),
+        content_categories,
+    });
+
+    Ok(())
+}
+
+/// Owner-only, so delegated operators stay bound by the rules they book under.
+pub fn set_campaign_targeting(
+    ctx: Context<SetCampaignTargeting>,
+    _campaign_idx: u64,
+    targeting: Option<CampaignTargeting>,
+) -> Result<()> {
+    if let Some(targeting) = &targeting {
+        validate_targeting(targeting)?;
+    }
+
+    let campaign = &mut ctx.accounts.campaign;
+    require_campaign_open(campaign)?;
+    campaign.targeting = targeting.clone();
+
+    emit!(CampaignTargetingUpdated {
+        campaign: campaign.key(),
+        targeting,
+    });
+
+    Ok(())
+}
+
+/// Permissionless crank: ends a campaign whose window has passed and returns its
+/// unreserved budget to the owner. Outstanding bookings still settle or cancel.
+pub fn expire_campaign(ctx: Context<ExpireCampaign>, _campaign_idx: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let campaign = &ctx.accounts.campaign;
+    require_campaign_open(campaign)?;
+    require!(campaign.has_expired(now), SoulboardError::CampaignNotExpired);
+
+    let released = campaign.available_budget;
+    if released > 0 {
+        ensure_rent_exempt_after_withdraw(&campaign.to_account_info(), released)?;
+        move_lamports(
+            &campaign.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            released,
+        )?;
+    }
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.available_budget = 0;
+    campaign.status = CampaignStatus::Closed;
+
+    let advertiser = &mut ctx.accounts.advertiser;
+    advertiser.campaign_count = advertiser
+        .campaign_count
+        .checked_sub(1)
+        .ok_or(SoulboardError::ArithmeticUnderflow)?;
+
+    emit!(CampaignExpired {
+        campaign: campaign.key(),
+        released_budget: released,
+        reserved_budget: campaign.reserved_budget,
+    });
+
+    Ok(())
+}
+
+/// Ends the campaign for good. The account stays on chain as history until archived;
+/// its index is never handed out again.
+pub fn end_campaign(ctx: Context<EndCampaign>, _campaign_idx: u64) -> Result<()> {
+    mark_campaign_ended(
+        &mut ctx.accounts.advertiser,
+        &mut ctx.accounts.campaign,
+        &ctx.accounts.authority.key(),
+    )
+}
+
+fn mark_campaign_ended(
+    advertiser: &mut Advertiser,
+    campaign: &mut Account<Campaign>,
+    authority: &Pubkey,
+) -> Result<()> {
+    require_campaign_open(campaign)?;
     require!(
         campaign.reserved_budget == 0,
         SoulboardError::CampaignHasActiveBookings
@@ -104,8 +367,89 @@ pub fn close_campaign(ctx: Context<CloseCampaign>, _campaign_idx: u64) -> Result
         .ok_or(SoulboardError::ArithmeticUnderflow)?;
 
     emit!(CampaignClosed {
+        campaign: campaign.key(),
+        authority: *authority,
+    });
+
+    Ok(())
+}
+
+/// Closes an ended campaign, returning rent and any unspent budget to the authority.
+pub fn archive_campaign(ctx: Context<ArchiveCampaign>, _campaign_idx: u64) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    require!(
+        campaign.status == CampaignStatus::Closed,
+        SoulboardError::CampaignNotClosed
+    );
+    require!(
+        campaign.reserved_budget == 0,
+        SoulboardError::CampaignHasActiveBookings
+    );
+
+    emit!(CampaignArchived {
         campaign: campaign.key(),
         authority: ctx.accounts.authority.key(),
+        refunded_budget: campaign.available_budget,
+    });
+
+    Ok(())
+}
+
+/// Deprecated: `end_campaign` followed by `archive_campaign` in one call, for clients
+/// built against the original `close_campaign`.
+pub fn close_campaign(ctx: Context<CloseCampaign>, _campaign_idx: u64) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let campaign = &mut ctx.accounts.campaign;
+    mark_campaign_ended(&mut ctx.accounts.advertiser, campaign, &authority)?;
+
+    emit!(CampaignArchived {
+        campaign: campaign.key(),
+        authority,
+        refunded_budget: campaign.available_budget,
+    });
+
+    Ok(())
+}
+
+/// Re-points a campaign at its advertiser's current authority after an ownership transfer.
+pub fn sync_campaign_authority(ctx: Context<SyncCampaignAuthority>, _campaign_idx: u64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.authority = ctx.accounts.advertiser.authority;
+
+    emit!(CampaignUpdated {
+        campaign: campaign.key(),
+    });
+
+    Ok(())
+}
+
+pub fn set_campaign_referral(
+    ctx: Context<SetCampaignReferral>,
+    _campaign_idx: u64,
+    referral: Option<Referral>,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_METADATA,
+    )?;
+    if let Some(referral) = &referral {
+        validate_referral(referral, &ctx.accounts.config)?;
+        require_independent_referrer(
+            referral,
+            &campaign.authority,
+            &campaign.advertiser,
+            ctx.accounts.referrer_delegate.as_deref(),
+        )?;
+    }
+
+    campaign.referral = referral;
+
+    emit!(ReferralUpdated {
+        target: campaign.key(),
+        referral,
     });
 
     Ok(())
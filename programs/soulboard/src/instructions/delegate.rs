@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::context::{RevokeDelegate, SetDelegate};
+use crate::errors::SoulboardError;
+use crate::states::{DelegateRevoked, DelegateUpdated};
+use crate::utils::delegate_scope;
+
+/// Grants `delegate` permissions over an advertiser's campaigns or a provider's locations.
+/// Only permissions belonging to the principal's role may be granted.
+pub fn set_delegate(
+    ctx: Context<SetDelegate>,
+    delegate: Pubkey,
+    permissions: u16,
+    spend_limit: Option<u64>,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let grantable = delegate_scope(&ctx.accounts.principal, &owner)?;
+    require!(
+        delegate != Pubkey::default() && delegate != owner,
+        SoulboardError::InvalidParameters
+    );
+    require!(
+        permissions != 0 && permissions & !grantable == 0,
+        SoulboardError::InvalidParameters
+    );
+
+    let delegate_account = &mut ctx.accounts.delegate_account;
+    // A grant left behind by a previous authority starts over.
+    if delegate_account.owner != owner {
+        delegate_account.spent = 0;
+    }
+    delegate_account.principal = ctx.accounts.principal.key();
+    delegate_account.owner = owner;
+    delegate_account.delegate = delegate;
+    delegate_account.permissions = permissions;
+    delegate_account.spend_limit = spend_limit;
+
+    emit!(DelegateUpdated {
+        delegate_account: delegate_account.key(),
+        owner: delegate_account.owner,
+        delegate,
+        permissions,
+        spend_limit,
+    });
+
+    Ok(())
+}
+
+pub fn revoke_delegate(ctx: Context<RevokeDelegate>, delegate: Pubkey) -> Result<()> {
+    delegate_scope(&ctx.accounts.principal, &ctx.accounts.owner.key())?;
+
+    emit!(DelegateRevoked {
+        delegate_account: ctx.accounts.delegate_account.key(),
+        owner: ctx.accounts.owner.key(),
+        delegate,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::context::WithdrawEarnings;
+use crate::errors::SoulboardError;
+use crate::states::EarningsWithdrawn;
+use crate::utils::{ensure_rent_exempt_after_withdraw, move_lamports};
+
+/// Pays settled earnings out of the provider vault, to `payout` when given and to the
+/// provider authority otherwise.
+pub fn withdraw_earnings(ctx: Context<WithdrawEarnings>, amount: u64) -> Result<()> {
+    require!(amount > 0, SoulboardError::InvalidParameters);
+
+    let earnings_info = ctx.accounts.earnings.to_account_info();
+    ensure_rent_exempt_after_withdraw(&earnings_info, amount)?;
+
+    let earnings = &mut ctx.accounts.earnings;
+    earnings.debit(amount)?;
+
+    let payout = match ctx.accounts.payout.as_ref() {
+        Some(payout) => payout.to_account_info(),
+        None => ctx.accounts.authority.to_account_info(),
+    };
+    move_lamports(&earnings_info, &payout, amount)?;
+
+    emit!(EarningsWithdrawn {
+        provider: ctx.accounts.provider.key(),
+        earnings: earnings.key(),
+        amount,
+        payout: payout.key(),
+        pending: earnings.pending,
+        withdrawn: earnings.withdrawn,
+    });
+
+    Ok(())
+}
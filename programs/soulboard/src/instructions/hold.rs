@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+
+use crate::constant::{MAX_SLOT_HOLD_SECS, PERMISSION_BOOK};
+use crate::context::{HoldLocationSlots, ReleaseSlotHolds};
+use crate::errors::SoulboardError;
+use crate::states::{LocationSlotHoldsReleased, LocationSlotsHeld, LocationStatus, SlotStatus};
+use crate::utils::{
+    load_oracle_device, require_campaign_active, require_owner_or_delegate, require_policy_admits,
+    require_targeted,
+};
+
+/// Puts the slots in a range on hold for the campaign without escrowing funds. The
+/// campaign converts the hold with `book_location_range`, and a hold is admitted under
+/// the same campaign, location and device checks as a booking. The available budget must
+/// cover the held prices, and a live hold cannot be renewed before it lapses.
+pub fn hold_location_slots(
+    ctx: Context<HoldLocationSlots>,
+    _campaign_idx: u64,
+    _location_idx: u64,
+    range_start_ts: i64,
+    range_end_ts: i64,
+    hold_secs: u32,
+    device_idx: u64,
+) -> Result<()> {
+    require!(range_start_ts < range_end_ts, SoulboardError::InvalidTimeRange);
+    require!(
+        hold_secs > 0 && hold_secs <= MAX_SLOT_HOLD_SECS,
+        SoulboardError::InvalidParameters
+    );
+
+    let campaign = &ctx.accounts.campaign;
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_BOOK,
+    )?;
+    let location = &ctx.accounts.location;
+    let device = load_oracle_device(
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        device_idx,
+    )?;
+    require_keys_eq!(device.location, location.key(), SoulboardError::InvalidOracleDevice);
+    require_keys_eq!(
+        device.oracle_authority,
+        location.oracle_authority,
+        SoulboardError::InvalidOracleAuthority
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(device.is_online(now), SoulboardError::OracleDeviceOffline);
+
+    require_campaign_active(campaign)?;
+    require!(
+        campaign.window_contains(range_start_ts, range_end_ts),
+        SoulboardError::OutsideCampaignWindow
+    );
+    require_targeted(campaign, location)?;
+    require_policy_admits(campaign, location, ctx.accounts.location_policy.as_deref())?;
+    require!(
+        location.location_status != LocationStatus::Inactive,
+        SoulboardError::LocationInactive
+    );
+
+    let expires_at = now
+        .checked_add(hold_secs as i64)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+    let campaign_key = campaign.key();
+    let schedule = &mut ctx.accounts.schedule;
+
+    let mut slot_count: u32 = 0;
+    let mut held_price: u64 = 0;
+    for slot in schedule.slots.iter_mut() {
+        if slot.start_ts >= range_start_ts
+            && slot.end_ts <= range_end_ts
+            && slot.status != SlotStatus::Cancelled
+        {
+            require!(slot.start_ts > now, SoulboardError::SlotInPast);
+            require!(
+                slot.is_bookable_by(&campaign_key, now),
+                SoulboardError::SlotUnavailable
+            );
+            require!(
+                !matches!(
+                    slot.status,
+                    SlotStatus::Held { campaign: holder, expires_at }
+                        if holder == campaign_key && expires_at > now
+                ),
+                SoulboardError::SlotAlreadyHeld
+            );
+            held_price = held_price
+                .checked_add(slot.price)
+                .ok_or(SoulboardError::ArithmeticOverflow)?;
+            slot.status = SlotStatus::Held {
+                campaign: campaign_key,
+                expires_at,
+            };
+            slot_count = slot_count
+                .checked_add(1)
+                .ok_or(SoulboardError::ArithmeticOverflow)?;
+        }
+    }
+    require!(slot_count > 0, SoulboardError::SlotNotFound);
+    require!(
+        campaign.available_budget >= held_price,
+        SoulboardError::InsufficientBudget
+    );
+
+    emit!(LocationSlotsHeld {
+        schedule: schedule.key(),
+        campaign: campaign_key,
+        range_start_ts,
+        range_end_ts,
+        slot_count,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+/// Permissionless: frees every hold on the schedule whose expiry has passed.
+pub fn release_slot_holds(ctx: Context<ReleaseSlotHolds>, _location_idx: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let schedule = &mut ctx.accounts.schedule;
+
+    let mut slot_count: u32 = 0;
+    for slot in schedule.slots.iter_mut() {
+        if matches!(slot.status, SlotStatus::Held { expires_at, .. } if expires_at <= now) {
+            slot.status = SlotStatus::Available;
+            slot_count = slot_count
+                .checked_add(1)
+                .ok_or(SoulboardError::ArithmeticOverflow)?;
+        }
+    }
+    require!(slot_count > 0, SoulboardError::SlotNotFound);
+
+    emit!(LocationSlotHoldsReleased {
+        schedule: schedule.key(),
+        slot_count,
+    });
+
+    Ok(())
+}
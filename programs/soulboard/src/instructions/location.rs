@@ -1,10 +1,26 @@
 use anchor_lang::prelude::*;
 
-use crate::constant::{MAX_LOCATION_DESC_LEN, MAX_LOCATION_NAME_LEN};
-use crate::context::{RegisterLocation, SetLocationStatus, UpdateLocationDetails, UpdateLocationPrice};
+use crate::constant::{
+    MAX_APPROVAL_WINDOW_SECS, MAX_CANCEL_CUTOFF_SECS, MAX_LOCATION_DESC_LEN, MAX_LOCATION_NAME_LEN, MAX_POLICY_ADVERTISERS,
+    PERMISSION_EDIT_LOCATION, PERMISSION_EDIT_POLICY,
+};
+use crate::context::{
+    RegisterLocation, RemoveLocationPolicy, SetLocationApproval, SetLocationCancellation,
+    SetLocationModifications, SetLocationOperatingHours, SetLocationPolicy, SetLocationReferral,
+    SetLocationStatus, SyncLocationAuthority, TransferLocation, UpdateLocationDetails,
+    UpdateLocationPrice,
+};
 use crate::errors::SoulboardError;
-use crate::states::{LocationRegistered, LocationStatus, LocationUpdated};
-use crate::utils::{ensure_string_len, set_optional_string};
+use crate::states::{
+    CancellationPolicy, ContentCategory, LocationApprovalUpdated, LocationCancellationUpdated,
+    LocationModificationsUpdated, LocationPolicyRemoved, LocationPolicyUpdated, LocationProfile,
+    LocationRegistered, LocationSchedule, LocationStatus, LocationTransferred, LocationUpdated,
+    OperatingHours, Referral, ReferralUpdated, SlotStatus,
+};
+use crate::utils::{
+    ensure_string_len, require_owner_or_delegate, set_optional_string, validate_location_profile,
+    validate_operating_hours, require_independent_referrer, validate_referral,
+};
 
 pub fn register_location(
     ctx: Context<RegisterLocation>,
@@ -12,24 +28,36 @@ pub fn register_location(
     location_description: String,
     price: u64,
     oracle_authority: Pubkey,
+    profile: LocationProfile,
 ) -> Result<()> {
     let provider = &mut ctx.accounts.provider;
     let location = &mut ctx.accounts.location;
 
     ensure_string_len(&location_name, MAX_LOCATION_NAME_LEN)?;
     ensure_string_len(&location_description, MAX_LOCATION_DESC_LEN)?;
+    validate_location_profile(&profile)?;
     require!(
         oracle_authority != Pubkey::default(),
         SoulboardError::OracleNotConfigured
     );
 
     location.authority = provider.authority;
+    location.creator = provider.creator;
+    location.provider = provider.key();
     location.location_name = location_name;
     location.location_description = location_description;
     location.location_idx = provider.last_location_id;
     location.price = price;
     location.oracle_authority = oracle_authority;
     location.location_status = LocationStatus::Available;
+    location.profile = profile;
+    location.operating_hours = None;
+    location.referral = None;
+    location.has_policy = false;
+    location.requires_approval = false;
+    location.approval_window_secs = 0;
+    location.cancellation = CancellationPolicy::default();
+    location.allow_modifications = false;
 
     provider.last_location_id = provider
         .last_location_id
@@ -44,6 +72,7 @@ pub fn register_location(
         location: location.key(),
         authority: ctx.accounts.authority.key(),
         location_idx: location.location_idx,
+        profile: location.profile.clone(),
     });
 
     Ok(())
@@ -54,8 +83,15 @@ pub fn update_location_details(
     _location_idx: u64,
     location_name: Option<String>,
     location_description: Option<String>,
+    profile: Option<LocationProfile>,
 ) -> Result<()> {
     let location = &mut ctx.accounts.location;
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_LOCATION,
+    )?;
 
     set_optional_string(&mut location.location_name, location_name, MAX_LOCATION_NAME_LEN)?;
     set_optional_string(
@@ -63,6 +99,10 @@ pub fn update_location_details(
         location_description,
         MAX_LOCATION_DESC_LEN,
     )?;
+    if let Some(profile) = profile {
+        validate_location_profile(&profile)?;
+        location.profile = profile;
+    }
 
     emit!(LocationUpdated {
         location: location.key(),
@@ -77,6 +117,12 @@ pub fn update_location_price(
     price: u64,
 ) -> Result<()> {
     let location = &mut ctx.accounts.location;
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_LOCATION,
+    )?;
     location.price = price;
 
     emit!(LocationUpdated {
@@ -86,12 +132,43 @@ pub fn update_location_price(
     Ok(())
 }
 
+pub fn set_location_operating_hours(
+    ctx: Context<SetLocationOperatingHours>,
+    _location_idx: u64,
+    operating_hours: Option<OperatingHours>,
+) -> Result<()> {
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_LOCATION,
+    )?;
+    if let Some(hours) = &operating_hours {
+        validate_operating_hours(hours)?;
+    }
+
+    let location = &mut ctx.accounts.location;
+    location.operating_hours = operating_hours;
+
+    emit!(LocationUpdated {
+        location: location.key(),
+    });
+
+    Ok(())
+}
+
 pub fn set_location_status(
     ctx: Context<SetLocationStatus>,
     _location_idx: u64,
     status: LocationStatus,
 ) -> Result<()> {
     let location = &mut ctx.accounts.location;
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_LOCATION,
+    )?;
     require!(
         !matches!(status, LocationStatus::Booked { .. }),
         SoulboardError::InvalidParameters
@@ -108,3 +185,250 @@ pub fn set_location_status(
 
     Ok(())
 }
+
+/// Switches the location between instant booking and provider approval.
+pub fn set_location_approval(
+    ctx: Context<SetLocationApproval>,
+    _location_idx: u64,
+    requires_approval: bool,
+    approval_window_secs: u32,
+) -> Result<()> {
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_POLICY,
+    )?;
+    require!(
+        !requires_approval
+            || (approval_window_secs > 0 && approval_window_secs <= MAX_APPROVAL_WINDOW_SECS),
+        SoulboardError::InvalidParameters
+    );
+
+    let location = &mut ctx.accounts.location;
+    location.requires_approval = requires_approval;
+    location.approval_window_secs = approval_window_secs;
+
+    emit!(LocationApprovalUpdated {
+        location: location.key(),
+        requires_approval,
+        approval_window_secs,
+    });
+
+    Ok(())
+}
+
+pub fn set_location_cancellation(
+    ctx: Context<SetLocationCancellation>,
+    _location_idx: u64,
+    cancellation: CancellationPolicy,
+) -> Result<()> {
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_POLICY,
+    )?;
+    require!(
+        cancellation.cutoff_secs <= MAX_CANCEL_CUTOFF_SECS,
+        SoulboardError::InvalidParameters
+    );
+
+    let location = &mut ctx.accounts.location;
+    location.cancellation = cancellation;
+
+    emit!(LocationCancellationUpdated {
+        location: location.key(),
+        cancellation,
+    });
+
+    Ok(())
+}
+
+pub fn set_location_modifications(
+    ctx: Context<SetLocationModifications>,
+    _location_idx: u64,
+    allow_modifications: bool,
+) -> Result<()> {
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_POLICY,
+    )?;
+
+    let location = &mut ctx.accounts.location;
+    location.allow_modifications = allow_modifications;
+
+    emit!(LocationModificationsUpdated {
+        location: location.key(),
+        allow_modifications,
+    });
+
+    Ok(())
+}
+
+pub fn set_location_referral(
+    ctx: Context<SetLocationReferral>,
+    _location_idx: u64,
+    referral: Option<Referral>,
+) -> Result<()> {
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_LOCATION,
+    )?;
+    if let Some(referral) = &referral {
+        validate_referral(referral, &ctx.accounts.config)?;
+        require_independent_referrer(
+            referral,
+            &ctx.accounts.provider.authority,
+            &ctx.accounts.provider.key(),
+            ctx.accounts.referrer_delegate.as_deref(),
+        )?;
+    }
+
+    let location = &mut ctx.accounts.location;
+    location.referral = referral;
+
+    emit!(ReferralUpdated {
+        target: location.key(),
+        referral,
+    });
+
+    Ok(())
+}
+
+/// Creates or replaces the location's booking policy.
+pub fn set_location_policy(
+    ctx: Context<SetLocationPolicy>,
+    _location_idx: u64,
+    allowed_advertisers: Vec<Pubkey>,
+    denied_advertisers: Vec<Pubkey>,
+    forbidden_categories: u16,
+) -> Result<()> {
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_POLICY,
+    )?;
+    require!(
+        allowed_advertisers.len() <= MAX_POLICY_ADVERTISERS
+            && denied_advertisers.len() <= MAX_POLICY_ADVERTISERS,
+        SoulboardError::InvalidParameters
+    );
+    require!(
+        forbidden_categories & !ContentCategory::ALL_MASK == 0,
+        SoulboardError::InvalidParameters
+    );
+
+    let location = &mut ctx.accounts.location;
+    location.has_policy = true;
+
+    let policy = &mut ctx.accounts.location_policy;
+    policy.location = location.key();
+    policy.allowed_advertisers = allowed_advertisers;
+    policy.denied_advertisers = denied_advertisers;
+    policy.forbidden_categories = forbidden_categories;
+
+    emit!(LocationPolicyUpdated {
+        location: location.key(),
+        policy: policy.key(),
+        allowed_advertisers: policy.allowed_advertisers.clone(),
+        denied_advertisers: policy.denied_advertisers.clone(),
+        forbidden_categories,
+    });
+
+    Ok(())
+}
+
+pub fn remove_location_policy(ctx: Context<RemoveLocationPolicy>, _location_idx: u64) -> Result<()> {
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_POLICY,
+    )?;
+
+    let location = &mut ctx.accounts.location;
+    location.has_policy = false;
+
+    emit!(LocationPolicyRemoved {
+        location: location.key(),
+        policy: ctx.accounts.location_policy.key(),
+    });
+
+    Ok(())
+}
+
+/// Re-points a location (and its schedule, when supplied) at the owning provider's authority.
+pub fn sync_location_authority(ctx: Context<SyncLocationAuthority>, _location_idx: u64) -> Result<()> {
+    let authority = ctx.accounts.provider.authority;
+    let location = &mut ctx.accounts.location;
+    location.authority = authority;
+    sync_schedule_authority(ctx.accounts.schedule.as_deref_mut(), authority);
+
+    emit!(LocationUpdated {
+        location: location.key(),
+    });
+
+    Ok(())
+}
+
+/// Moves a location to another provider, which must co-sign. Booked slots settle to the
+/// provider recorded on their booking, so the schedule must have none.
+pub fn transfer_location(ctx: Context<TransferLocation>, _location_idx: u64) -> Result<()> {
+    let provider = &mut ctx.accounts.provider;
+    let new_provider = &mut ctx.accounts.new_provider;
+    let location = &mut ctx.accounts.location;
+
+    require_keys_neq!(provider.key(), new_provider.key(), SoulboardError::InvalidParameters);
+    require!(
+        !matches!(location.location_status, LocationStatus::Booked { .. }),
+        SoulboardError::LocationAlreadyBooked
+    );
+
+    provider.location_count = provider
+        .location_count
+        .checked_sub(1)
+        .ok_or(SoulboardError::ArithmeticUnderflow)?;
+    new_provider.location_count = new_provider
+        .location_count
+        .checked_add(1)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+
+    location.provider = new_provider.key();
+    location.authority = new_provider.authority;
+
+    let schedule_info = ctx.accounts.schedule.to_account_info();
+    if schedule_info.owner == &crate::ID {
+        let mut data = schedule_info.try_borrow_mut_data()?;
+        let mut schedule = LocationSchedule::try_deserialize(&mut &data[..])?;
+        require!(
+            schedule
+                .slots
+                .iter()
+                .all(|slot| slot.status != SlotStatus::Booked),
+            SoulboardError::LocationHasBookings
+        );
+        sync_schedule_authority(Some(&mut schedule), location.authority);
+        schedule.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(LocationTransferred {
+        location: location.key(),
+        previous_provider: provider.key(),
+        new_provider: new_provider.key(),
+        authority: location.authority,
+    });
+
+    Ok(())
+}
+
+fn sync_schedule_authority(schedule: Option<&mut LocationSchedule>, authority: Pubkey) {
+    if let Some(schedule) = schedule {
+        schedule.authority = authority;
+    }
+}
@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+
+use crate::constant::{
+    ADVERTISER_KEY, CAMPAIGN_KEY, DEFAULT_PROVIDER_CANCEL_COMPENSATION_BPS, DEFAULT_SETTLEMENT_GRACE_SECS, LOCATION_KEY,
+    PROVIDER_KEY,
+};
+use crate::context::{
+    CreateProviderEarnings, MigrateAdvertiser, MigrateCampaign, MigrateConfig, MigrateLocation, MigrateProvider,
+};
+use crate::errors::SoulboardError;
+use crate::states::{
+    AccountMigrated, Advertiser, Campaign, CancellationPolicy, FallbackSettlement, LegacyAdvertiser, LegacyCampaign,
+    LegacyLocation, LegacyProvider, LegacySoulboardConfig, Location, LocationProfile, Provider, ReferralFeeMode,
+    SoulboardConfig, VenueCategory,
+};
+use crate::utils::{read_legacy_account, write_migrated_account};
+
+/// Rewrites the pre-upgrade config and creates the treasury PDA, which replaces the wallet
+/// fees used to be sent to. The fee is kept; the newer settings start at their defaults.
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let info = ctx.accounts.config.to_account_info();
+    let legacy = read_legacy_account::<SoulboardConfig, LegacySoulboardConfig>(&info)?;
+    require_keys_eq!(
+        legacy.authority,
+        ctx.accounts.authority.key(),
+        SoulboardError::Unauthorized
+    );
+
+    let migrated = SoulboardConfig {
+        authority: legacy.authority,
+        treasury: ctx.accounts.treasury.key(),
+        fee_bps: legacy.fee_bps,
+        max_referral_bps: 0,
+        referral_fee_mode: ReferralFeeMode::FromPlatformFee,
+        settlement_grace_secs: DEFAULT_SETTLEMENT_GRACE_SECS,
+        fallback_settlement: FallbackSettlement::UseMetrics,
+        provider_cancel_compensation_bps: DEFAULT_PROVIDER_CANCEL_COMPENSATION_BPS,
+    };
+    write_migrated_account(
+        &info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &migrated,
+    )?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        payer: ctx.accounts.authority.key(),
+    });
+    Ok(())
+}
+
+// Accounts created before the upgrade were seeded by their authority, which therefore
+// becomes their `creator`. Migration is permissionless; the payer funds the extra rent.
+
+pub fn migrate_advertiser(ctx: Context<MigrateAdvertiser>) -> Result<()> {
+    let info = ctx.accounts.advertiser.to_account_info();
+    let legacy = read_legacy_account::<Advertiser, LegacyAdvertiser>(&info)?;
+    require_legacy_address(&info, &[ADVERTISER_KEY, legacy.authority.as_ref()])?;
+
+    let migrated = Advertiser {
+        authority: legacy.authority,
+        creator: legacy.authority,
+        last_campaign_id: legacy.last_campaign_id,
+        campaign_count: legacy.campaign_count,
+        agency: None,
+    };
+    write_migrated_account(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &migrated,
+    )?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
+
+pub fn migrate_provider(ctx: Context<MigrateProvider>) -> Result<()> {
+    let info = ctx.accounts.provider.to_account_info();
+    let legacy = read_legacy_account::<Provider, LegacyProvider>(&info)?;
+    require_legacy_address(&info, &[PROVIDER_KEY, legacy.authority.as_ref()])?;
+
+    let migrated = Provider {
+        authority: legacy.authority,
+        creator: legacy.authority,
+        last_location_id: legacy.last_location_id,
+        location_count: legacy.location_count,
+        cancellation_count: 0,
+    };
+    write_migrated_account(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &migrated,
+    )?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
+
+/// Creates the earnings vault of a provider registered before vaults existed; settlements
+/// at its locations need it. Permissionless; the payer funds the rent.
+pub fn create_provider_earnings(ctx: Context<CreateProviderEarnings>) -> Result<()> {
+    let earnings = &mut ctx.accounts.earnings;
+    earnings.provider = ctx.accounts.provider.key();
+    earnings.pending = 0;
+    earnings.withdrawn = 0;
+    earnings.lifetime = 0;
+    Ok(())
+}
+
+pub fn migrate_campaign(ctx: Context<MigrateCampaign>) -> Result<()> {
+    let info = ctx.accounts.campaign.to_account_info();
+    let legacy = read_legacy_account::<Campaign, LegacyCampaign>(&info)?;
+    require_legacy_address(
+        &info,
+        &[CAMPAIGN_KEY, legacy.authority.as_ref(), &legacy.campaign_idx.to_le_bytes()],
+    )?;
+    let (advertiser, _) =
+        Pubkey::find_program_address(&[ADVERTISER_KEY, legacy.authority.as_ref()], &crate::ID);
+
+    let migrated = Campaign {
+        authority: legacy.authority,
+        creator: legacy.authority,
+        advertiser,
+        campaign_idx: legacy.campaign_idx,
+        campaign_name: legacy.campaign_name,
+        campaign_description: legacy.campaign_description,
+        campaign_image_url: legacy.campaign_image_url,
+        status: legacy.status,
+        available_budget: legacy.available_budget,
+        reserved_budget: legacy.reserved_budget,
+        agency: None,
+        agency_commission_bps: 0,
+        referral: None,
+        starts_at: None,
+        ends_at: None,
+        pacing: None,
+        pacing_window_start: 0,
+        pacing_window_spent: 0,
+        targeting: None,
+        content_categories: 0,
+        next_order_id: 0,
+    };
+    write_migrated_account(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &migrated,
+    )?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
+
+/// Legacy locations get an empty profile (`Other`, no coordinates or audience estimate)
+/// that the provider can fill in with `update_location_details`.
+pub fn migrate_location(ctx: Context<MigrateLocation>) -> Result<()> {
+    let info = ctx.accounts.location.to_account_info();
+    let legacy = read_legacy_account::<Location, LegacyLocation>(&info)?;
+    require_legacy_address(
+        &info,
+        &[LOCATION_KEY, legacy.authority.as_ref(), &legacy.location_idx.to_le_bytes()],
+    )?;
+    let (provider, _) =
+        Pubkey::find_program_address(&[PROVIDER_KEY, legacy.authority.as_ref()], &crate::ID);
+
+    let migrated = Location {
+        authority: legacy.authority,
+        creator: legacy.authority,
+        provider,
+        location_idx: legacy.location_idx,
+        price: legacy.price,
+        oracle_authority: legacy.oracle_authority,
+        location_name: legacy.location_name,
+        location_description: legacy.location_description,
+        location_status: legacy.location_status,
+        profile: LocationProfile {
+            latitude_e7: 0,
+            longitude_e7: 0,
+            venue_category: VenueCategory::Other,
+            daily_audience_estimate: 0,
+        },
+        operating_hours: None,
+        referral: None,
+        has_policy: false,
+        requires_approval: false,
+        approval_window_secs: 0,
+        cancellation: CancellationPolicy::default(),
+        allow_modifications: false,
+    };
+    write_migrated_account(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &migrated,
+    )?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
+
+fn require_legacy_address(info: &AccountInfo, seeds: &[&[u8]]) -> Result<()> {
+    let (expected, _) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(info.key(), expected, SoulboardError::InvalidParameters);
+    Ok(())
+}
@@ -4,3 +4,12 @@ pub mod budget;
 pub mod location;
 pub mod booking;
 pub mod slot;
+pub mod delegate;
+pub mod agency;
+pub mod treasury;
+pub mod earnings;
+pub mod approval;
+pub mod hold;
+pub mod order;
+pub mod modify;
+pub mod migrate;
@@ -0,0 +1,265 @@
+use anchor_lang::prelude::*;
+
+use crate::constant::PERMISSION_BOOK;
+use crate::context::ModifyBooking;
+use crate::errors::SoulboardError;
+use crate::instructions::slot::{
+    charge_booking_spend, credit_booking_spend, release_slots, reserve_slots,
+};
+use crate::states::{
+    BookingStatus, Campaign, CampaignBooking, CampaignBookingExtended, CampaignBookingRescheduled,
+    LocationStatus,
+};
+use crate::utils::{
+    booked_range, booking_order, load_oracle_device, require_campaign_active, require_owner_or_delegate,
+    require_policy_admits, require_targeted,
+};
+
+/// Appends available slots that sit directly before or after the booking's range and
+/// escrows their price from the campaign's available budget.
+pub fn extend_booking(
+    ctx: Context<ModifyBooking>,
+    _campaign_idx: u64,
+    _location_idx: u64,
+    slot_indexes: Vec<u32>,
+) -> Result<()> {
+    require_modifiable(&ctx)?;
+    require_ascending(&slot_indexes)?;
+
+    let accounts = ctx.accounts;
+    let campaign = &mut accounts.campaign;
+    let schedule = &mut accounts.schedule;
+    let booking = &mut accounts.booking;
+
+    let mut pending = Vec::with_capacity(slot_indexes.len());
+    for &index in slot_indexes.iter() {
+        let slot = schedule
+            .slots
+            .get(index as usize)
+            .ok_or(SoulboardError::SlotNotFound)?;
+        pending.push((slot.start_ts, slot.end_ts));
+    }
+    // Partial cancels and reschedules can shrink the booking, so adjacency is judged
+    // against the slots it still holds rather than the range it was opened with.
+    let (mut range_start_ts, mut range_end_ts) =
+        booked_range(schedule, &booking.key()).ok_or(SoulboardError::SlotNotFound)?;
+    while !pending.is_empty() {
+        let before = pending.len();
+        pending.retain(|&(start_ts, end_ts)| {
+            if end_ts == range_start_ts {
+                range_start_ts = start_ts;
+                false
+            } else if start_ts == range_end_ts {
+                range_end_ts = end_ts;
+                false
+            } else {
+                true
+            }
+        });
+        require!(pending.len() < before, SoulboardError::SlotsNotAdjacent);
+    }
+    require!(
+        campaign.window_contains(range_start_ts, range_end_ts),
+        SoulboardError::OutsideCampaignWindow
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let indexes: Vec<usize> = slot_indexes.iter().map(|&index| index as usize).collect();
+    let (charged_amount, added_slots) = reserve_slots(campaign, schedule, booking, &indexes, now)?;
+    charge_booking_spend(
+        campaign,
+        &accounts.authority.key(),
+        accounts.delegate.as_deref_mut(),
+        accounts.location_spend.as_deref_mut(),
+        booking.location,
+        &mut booking.charges,
+        charged_amount,
+    )?;
+
+    if let Some(order) = booking_order(accounts.order.as_mut(), booking)? {
+        order.reserve(charged_amount)?;
+    }
+
+    booking.total_price = booking
+        .total_price
+        .checked_add(charged_amount)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+    booking.slot_count = booking
+        .slot_count
+        .checked_add(added_slots)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+    booking.range_start_ts = range_start_ts;
+    booking.range_end_ts = range_end_ts;
+    booking.updated_at = now;
+    require_booking_cap(campaign, booking)?;
+
+    emit!(CampaignBookingExtended {
+        booking: booking.key(),
+        campaign: campaign.key(),
+        location: booking.location,
+        added_slots,
+        charged_amount,
+        range_start_ts,
+        range_end_ts,
+    });
+
+    Ok(())
+}
+
+/// Swaps some of a booking's slots for others at the same location. A price increase is
+/// escrowed from the campaign's available budget; a decrease is refunded to it.
+pub fn reschedule_booking(
+    ctx: Context<ModifyBooking>,
+    _campaign_idx: u64,
+    _location_idx: u64,
+    release_indexes: Vec<u32>,
+    book_indexes: Vec<u32>,
+) -> Result<()> {
+    require_modifiable(&ctx)?;
+    require_ascending(&release_indexes)?;
+    require_ascending(&book_indexes)?;
+
+    let accounts = ctx.accounts;
+    let campaign = &mut accounts.campaign;
+    let schedule = &mut accounts.schedule;
+    let booking = &mut accounts.booking;
+    let location = &accounts.location;
+
+    let now = Clock::get()?.unix_timestamp;
+    let cutoff = now
+        .checked_add(location.cancellation.cutoff_secs as i64)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+    let (refunded_amount, released_slots) =
+        release_slots(campaign, schedule, booking, &release_indexes, cutoff)?;
+
+    let indexes: Vec<usize> = book_indexes.iter().map(|&index| index as usize).collect();
+    let (booked_amount, added_slots) = reserve_slots(campaign, schedule, booking, &indexes, now)?;
+    let charged_amount = booked_amount.saturating_sub(refunded_amount);
+    if charged_amount > 0 {
+        charge_booking_spend(
+            campaign,
+            &accounts.authority.key(),
+            accounts.delegate.as_deref_mut(),
+            accounts.location_spend.as_deref_mut(),
+            booking.location,
+            &mut booking.charges,
+            charged_amount,
+        )?;
+    } else {
+        credit_booking_spend(
+            campaign,
+            accounts.delegate.as_deref_mut(),
+            accounts.spend_delegate.as_deref(),
+            accounts.location_spend.as_deref_mut(),
+            &mut booking.charges,
+            refunded_amount - booked_amount,
+        )?;
+    }
+
+    let (range_start_ts, range_end_ts) =
+        booked_range(schedule, &booking.key()).ok_or(SoulboardError::SlotNotFound)?;
+    require!(
+        campaign.window_contains(range_start_ts, range_end_ts),
+        SoulboardError::OutsideCampaignWindow
+    );
+
+    if let Some(order) = booking_order(accounts.order.as_mut(), booking)? {
+        order.release(refunded_amount, false)?;
+        order.reserve(booked_amount)?;
+    }
+
+    booking.total_price = booking
+        .total_price
+        .checked_sub(refunded_amount)
+        .ok_or(SoulboardError::ArithmeticUnderflow)?
+        .checked_add(booked_amount)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+    booking.slot_count = booking
+        .slot_count
+        .checked_sub(released_slots)
+        .ok_or(SoulboardError::ArithmeticUnderflow)?
+        .checked_add(added_slots)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+    booking.range_start_ts = range_start_ts;
+    booking.range_end_ts = range_end_ts;
+    booking.updated_at = now;
+    require_booking_cap(campaign, booking)?;
+
+    emit!(CampaignBookingRescheduled {
+        booking: booking.key(),
+        campaign: campaign.key(),
+        location: location.key(),
+        released_slots,
+        added_slots,
+        refunded_amount: refunded_amount.saturating_sub(booked_amount),
+        charged_amount,
+    });
+
+    Ok(())
+}
+
+/// Runs the same admission checks as a new booking, so a modification cannot reach
+/// slots the campaign would no longer be allowed to book.
+fn require_modifiable(ctx: &Context<ModifyBooking>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let location = &ctx.accounts.location;
+    let booking = &ctx.accounts.booking;
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_BOOK,
+    )?;
+    require_campaign_active(campaign)?;
+    require!(
+        location.allow_modifications,
+        SoulboardError::ModificationsNotAllowed
+    );
+    require_targeted(campaign, location)?;
+    require_policy_admits(campaign, location, ctx.accounts.location_policy.as_deref())?;
+    require!(
+        location.location_status != LocationStatus::Inactive,
+        SoulboardError::LocationInactive
+    );
+
+    require_keys_eq!(
+        booking.device,
+        ctx.accounts.oracle_device.key(),
+        SoulboardError::InvalidOracleDevice
+    );
+    let device = load_oracle_device(
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        booking.device_idx,
+    )?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(device.is_online(now), SoulboardError::OracleDeviceOffline);
+
+    require!(
+        matches!(
+            booking.status,
+            BookingStatus::Active | BookingStatus::Pending
+        ),
+        SoulboardError::BookingNotActive
+    );
+    Ok(())
+}
+
+fn require_ascending(slot_indexes: &[u32]) -> Result<()> {
+    require!(
+        !slot_indexes.is_empty() && slot_indexes.windows(2).all(|pair| pair[0] < pair[1]),
+        SoulboardError::InvalidParameters
+    );
+    Ok(())
+}
+
+/// The per-booking pacing cap applies to the booking's total, not just the change.
+fn require_booking_cap(campaign: &Campaign, booking: &CampaignBooking) -> Result<()> {
+    if let Some(max_per_booking) = campaign.pacing.and_then(|pacing| pacing.max_per_booking) {
+        require!(
+            booking.total_price <= max_per_booking,
+            SoulboardError::PacingLimitExceeded
+        );
+    }
+    Ok(())
+}
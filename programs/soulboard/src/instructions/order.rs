@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::constant::{MAX_SLOTS_PER_SCHEDULE, PERMISSION_BOOK, PERMISSION_CANCEL};
+use crate::context::{BookOrderSlots, CloseBookingOrder, CreateBookingOrder};
+use crate::errors::SoulboardError;
+use crate::instructions::slot::{open_booking, BookingTerms, NewBooking};
+use crate::states::{BookingOrderClosed, BookingOrderCreated, PricingModel};
+use crate::utils::{require_campaign_active, require_owner_or_delegate};
+
+/// Opens an empty order under the campaign's next order id.
+pub fn create_booking_order(ctx: Context<CreateBookingOrder>, _campaign_idx: u64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_BOOK,
+    )?;
+    require_campaign_active(campaign)?;
+
+    let order = &mut ctx.accounts.order;
+    order.campaign = campaign.key();
+    order.order_id = campaign.next_order_id;
+    order.booking_count = 0;
+    order.total_price = 0;
+    order.settled_amount = 0;
+    order.created_at = Clock::get()?.unix_timestamp;
+
+    campaign.next_order_id = campaign
+        .next_order_id
+        .checked_add(1)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+
+    emit!(BookingOrderCreated {
+        order: order.key(),
+        campaign: campaign.key(),
+        order_id: order.order_id,
+    });
+
+    Ok(())
+}
+
+/// Books an explicit list of schedule slots at one location as a child booking of the
+/// order. `slot_indexes` must be strictly ascending; the slots need not be contiguous.
+pub fn book_order_slots(
+    ctx: Context<BookOrderSlots>,
+    _campaign_idx: u64,
+    _order_id: u64,
+    _location_idx: u64,
+    slot_indexes: Vec<u32>,
+    device_idx: u64,
+    pricing_model: PricingModel,
+) -> Result<()> {
+    require!(
+        !slot_indexes.is_empty() && slot_indexes.len() <= MAX_SLOTS_PER_SCHEDULE as usize,
+        SoulboardError::InvalidParameters
+    );
+    require!(
+        slot_indexes.windows(2).all(|pair| pair[0] < pair[1]),
+        SoulboardError::InvalidParameters
+    );
+
+    let slots = &ctx.accounts.schedule.slots;
+    let mut range_start_ts = i64::MAX;
+    let mut range_end_ts = i64::MIN;
+    for &index in slot_indexes.iter() {
+        let slot = slots
+            .get(index as usize)
+            .ok_or(SoulboardError::SlotNotFound)?;
+        range_start_ts = range_start_ts.min(slot.start_ts);
+        range_end_ts = range_end_ts.max(slot.end_ts);
+    }
+
+    let accounts = ctx.accounts;
+    let order_key = accounts.order.key();
+    let total_price = open_booking(
+        NewBooking {
+            campaign: &mut accounts.campaign,
+            delegate: accounts.delegate.as_deref_mut(),
+            location: &accounts.location,
+            schedule: &mut accounts.schedule,
+            booking: &mut accounts.booking,
+            location_policy: accounts.location_policy.as_deref(),
+            location_spend: accounts.location_spend.as_deref_mut(),
+            oracle_device: &accounts.oracle_device,
+            device_authority: &accounts.device_authority,
+            booking_authority: &accounts.booking_authority,
+            booking_authority_bump: ctx.bumps.booking_authority,
+            oracle_program: accounts.oracle_program.to_account_info(),
+            authority: accounts.authority.key(),
+        },
+        BookingTerms {
+            slot_indexes: slot_indexes.into_iter().map(|index| index as usize).collect(),
+            range_start_ts,
+            range_end_ts,
+            device_idx,
+            pricing_model,
+            order: Some(order_key),
+        },
+    )?;
+
+    let order = &mut accounts.order;
+    order.booking_count = order
+        .booking_count
+        .checked_add(1)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+    order.reserve(total_price)?;
+
+    Ok(())
+}
+
+/// Closes an order once every child booking has been cancelled or settled, returning its
+/// rent to the campaign.
+pub fn close_booking_order(
+    ctx: Context<CloseBookingOrder>,
+    _campaign_idx: u64,
+    _order_id: u64,
+) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_CANCEL,
+    )?;
+
+    let order = &ctx.accounts.order;
+    require!(order.booking_count == 0, SoulboardError::OrderHasBookings);
+
+    emit!(BookingOrderClosed {
+        order: order.key(),
+        campaign: campaign.key(),
+        order_id: order.order_id,
+        settled_amount: order.settled_amount,
+    });
+
+    Ok(())
+}
@@ -1,78 +1,181 @@
 use anchor_lang::prelude::*;
-use anchor_lang::AccountDeserialize;
-use soul_board_oracle::constants::DEVICE_KEY as ORACLE_DEVICE_KEY;
-use soul_board_oracle::states::{Device as OracleDevice, DeviceStatus as OracleDeviceStatus};
+use soul_board_oracle::states::DeviceStatus as OracleDeviceStatus;
 
-use crate::constant::{BPS_DENOMINATOR, MAX_SLOTS_PER_SCHEDULE, PLATFORM_FEE_BPS};
+use crate::constant::{
+    BPS_DENOMINATOR, DEFAULT_PROVIDER_CANCEL_COMPENSATION_BPS, DEFAULT_SETTLEMENT_GRACE_SECS, MAX_SETTLEMENT_GRACE_SECS, MAX_SLOTS_PER_SCHEDULE, PERMISSION_BOOK, PERMISSION_CANCEL,
+    PERMISSION_EDIT_SLOTS, PERMISSION_PROVIDER_CANCEL, PLATFORM_FEE_BPS,
+};
 use crate::context::{
-    AddLocationSlot, BookLocationRange, CancelLocationBooking, CreateLocationSchedule,
-    InitializeConfig, SettleLocationBooking,
+    AddLocationSlot, BookLocationRange, CancelBookingSlots, CancelLocationBooking, CreateLocationSchedule,
+    InitializeConfig, ProviderCancelBooking, RemoveFeeOverride, SetFeeOverride, SettleLocationBooking,
+    TransferConfigAuthority, UpdateConfig,
 };
 use crate::errors::SoulboardError;
 use crate::states::{
-    BookingStatus, CampaignBookingCancelled, CampaignBookingCreated, CampaignBookingSettled,
+    BookingCharges, BookingStatus, Campaign, CampaignBooking, CampaignBookingCancelled,
+    CampaignBookingCancelledByProvider, CampaignBookingSlotsCancelled, CampaignBookingCreated, CampaignBookingSettled,
+    CampaignLocationSpend, ConfigAuthorityTransferred, Delegate, FallbackSettlement,
+    FeeOverrideRemoved,
+    FeeOverrideScope, FeeOverrideSet, Location, LocationPolicy, LocationSchedule,
     LocationScheduleCreated, LocationSlot, LocationSlotAdded, LocationStatus, PricingModel,
-    SlotStatus, SoulboardConfigInitialized,
+    ProviderCancelCompensationUpdated, ProviderCancelReason,
+    ReferralFeeMode, SettlementFallbackUpdated, SlotStatus, SoulboardConfigInitialized, SoulboardConfigUpdated,
+};
+use crate::utils::{
+    booked_range, booking_order, bps_share, charge_delegate_spend, credit_delegate_spend, ensure_rent_exempt_after_withdraw, load_oracle_device,
+    lock_oracle_device, move_lamports, pay_out_settlement, read_oracle_device, refund_booking_escrow,
+    release_oracle_device, require_campaign_active, require_owner_or_delegate, require_policy_admits,
+    require_targeted, snapshot_referrals, SettlementPayees,
 };
-use crate::utils::{ensure_rent_exempt_after_withdraw, move_lamports, require_campaign_active};
 
-fn load_oracle_device(
-    device_info: &AccountInfo,
-    device_authority: &AccountInfo,
-    device_idx: u64,
-) -> Result<OracleDevice> {
-    require_keys_eq!(
-        *device_info.owner,
-        soul_board_oracle::ID,
-        SoulboardError::InvalidOracleDevice
-    );
+pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.authority = ctx.accounts.authority.key();
+    config.treasury = ctx.accounts.treasury.key();
+    config.fee_bps = PLATFORM_FEE_BPS as u16;
+    config.max_referral_bps = 0;
+    config.referral_fee_mode = ReferralFeeMode::FromPlatformFee;
+    config.settlement_grace_secs = DEFAULT_SETTLEMENT_GRACE_SECS;
+    config.fallback_settlement = FallbackSettlement::UseMetrics;
+    config.provider_cancel_compensation_bps = DEFAULT_PROVIDER_CANCEL_COMPENSATION_BPS;
 
-    let (expected, _) = Pubkey::find_program_address(
-        &[
-            ORACLE_DEVICE_KEY,
-            device_authority.key().as_ref(),
-            &device_idx.to_le_bytes(),
-        ],
-        &soul_board_oracle::ID,
+    emit!(SoulboardConfigInitialized {
+        config: config.key(),
+        authority: config.authority,
+        treasury: config.treasury,
+        fee_bps: config.fee_bps,
+    });
+
+    Ok(())
+}
+
+pub fn update_config(
+    ctx: Context<UpdateConfig>,
+    max_referral_bps: u16,
+    referral_fee_mode: ReferralFeeMode,
+) -> Result<()> {
+    require!(
+        (max_referral_bps as u64) <= BPS_DENOMINATOR,
+        SoulboardError::InvalidParameters
     );
-    require_keys_eq!(expected, device_info.key(), SoulboardError::InvalidOracleDevice);
 
-    let mut data: &[u8] = &device_info.data.borrow();
-    let device = OracleDevice::try_deserialize(&mut data)
-        .map_err(|_| SoulboardError::InvalidOracleDevice)?;
+    let config = &mut ctx.accounts.config;
+    config.max_referral_bps = max_referral_bps;
+    config.referral_fee_mode = referral_fee_mode;
+
+    emit!(SoulboardConfigUpdated {
+        config: config.key(),
+        max_referral_bps,
+        referral_fee_mode,
+    });
+
+    Ok(())
+}
+
+pub fn set_settlement_fallback(
+    ctx: Context<UpdateConfig>,
+    settlement_grace_secs: u32,
+    fallback_settlement: FallbackSettlement,
+) -> Result<()> {
     require!(
-        device.status == OracleDeviceStatus::Active,
-        SoulboardError::OracleDeviceInactive
+        settlement_grace_secs > 0 && settlement_grace_secs <= MAX_SETTLEMENT_GRACE_SECS,
+        SoulboardError::InvalidParameters
     );
-    require_keys_eq!(
-        device.authority,
-        device_authority.key(),
-        SoulboardError::InvalidOracleDevice
+
+    let config = &mut ctx.accounts.config;
+    config.settlement_grace_secs = settlement_grace_secs;
+    config.fallback_settlement = fallback_settlement;
+
+    emit!(SettlementFallbackUpdated {
+        config: config.key(),
+        settlement_grace_secs,
+        fallback_settlement,
+    });
+
+    Ok(())
+}
+
+pub fn set_provider_cancel_compensation(
+    ctx: Context<UpdateConfig>,
+    compensation_bps: u16,
+) -> Result<()> {
+    require!(
+        (compensation_bps as u64) <= BPS_DENOMINATOR,
+        SoulboardError::InvalidParameters
     );
+
+    let config = &mut ctx.accounts.config;
+    config.provider_cancel_compensation_bps = compensation_bps;
+
+    emit!(ProviderCancelCompensationUpdated {
+        config: config.key(),
+        compensation_bps,
+    });
+
+    Ok(())
+}
+
+pub fn set_fee_override(
+    ctx: Context<SetFeeOverride>,
+    target: Pubkey,
+    scope: FeeOverrideScope,
+    fee_bps: u16,
+    expires_at: Option<i64>,
+) -> Result<()> {
     require!(
-        device.device_idx == device_idx,
-        SoulboardError::InvalidOracleDevice
+        (fee_bps as u64) <= BPS_DENOMINATOR,
+        SoulboardError::InvalidParameters
     );
+    if let Some(expires_at) = expires_at {
+        require!(
+            expires_at > Clock::get()?.unix_timestamp,
+            SoulboardError::InvalidTimeRange
+        );
+    }
+
+    let fee_override = &mut ctx.accounts.fee_override;
+    fee_override.target = target;
+    fee_override.scope = scope;
+    fee_override.fee_bps = fee_bps;
+    fee_override.expires_at = expires_at;
 
-    Ok(device)
+    emit!(FeeOverrideSet {
+        fee_override: fee_override.key(),
+        target,
+        scope,
+        fee_bps,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+pub fn remove_fee_override(ctx: Context<RemoveFeeOverride>, target: Pubkey) -> Result<()> {
+    emit!(FeeOverrideRemoved {
+        fee_override: ctx.accounts.fee_override.key(),
+        target,
+    });
+
+    Ok(())
 }
 
-pub fn initialize_config(ctx: Context<InitializeConfig>, treasury: Pubkey) -> Result<()> {
+pub fn transfer_config_authority(
+    ctx: Context<TransferConfigAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
     require!(
-        treasury != Pubkey::default(),
+        new_authority != Pubkey::default(),
         SoulboardError::InvalidParameters
     );
 
     let config = &mut ctx.accounts.config;
-    config.authority = ctx.accounts.authority.key();
-    config.treasury = treasury;
-    config.fee_bps = PLATFORM_FEE_BPS as u16;
+    let previous_authority = config.authority;
+    config.authority = new_authority;
 
-    emit!(SoulboardConfigInitialized {
+    emit!(ConfigAuthorityTransferred {
         config: config.key(),
-        authority: config.authority,
-        treasury: config.treasury,
-        fee_bps: config.fee_bps,
+        previous_authority,
+        new_authority,
     });
 
     Ok(())
@@ -88,10 +191,16 @@ pub fn create_location_schedule(
         max_slots <= MAX_SLOTS_PER_SCHEDULE,
         SoulboardError::InvalidParameters
     );
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_SLOTS,
+    )?;
 
     let schedule = &mut ctx.accounts.schedule;
     schedule.location = ctx.accounts.location.key();
-    schedule.authority = ctx.accounts.authority.key();
+    schedule.authority = ctx.accounts.location.authority;
     schedule.max_slots = max_slots;
     schedule.slot_count = 0;
     schedule.slots = Vec::new();
@@ -112,9 +221,22 @@ pub fn add_location_slot(
     start_ts: i64,
     end_ts: i64,
     price: u64,
+    allow_outside_hours: bool,
 ) -> Result<()> {
     require!(start_ts < end_ts, SoulboardError::InvalidTimeRange);
     require!(price > 0, SoulboardError::InvalidParameters);
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_EDIT_SLOTS,
+    )?;
+    if let Some(hours) = &ctx.accounts.location.operating_hours {
+        require!(
+            allow_outside_hours || hours.contains(start_ts, end_ts),
+            SoulboardError::SlotOutsideOperatingHours
+        );
+    }
 
     let schedule = &mut ctx.accounts.schedule;
     require!(
@@ -122,7 +244,7 @@ pub fn add_location_slot(
         SoulboardError::InvalidParameters
     );
     require!(
-        schedule.authority == ctx.accounts.authority.key(),
+        schedule.authority == ctx.accounts.location.authority,
         SoulboardError::InvalidAuthority
     );
     require!(
@@ -131,7 +253,10 @@ pub fn add_location_slot(
     );
 
     for slot in schedule.slots.iter() {
-        if matches!(slot.status, SlotStatus::Available | SlotStatus::Booked) {
+        if matches!(
+            slot.status,
+            SlotStatus::Available | SlotStatus::Booked | SlotStatus::Held { .. }
+        ) {
             let overlaps = start_ts < slot.end_ts && end_ts > slot.start_ts;
             require!(!overlaps, SoulboardError::SlotOverlap);
         }
@@ -169,21 +294,118 @@ pub fn book_location_range(
     pricing_model: PricingModel,
 ) -> Result<()> {
     require!(range_start_ts < range_end_ts, SoulboardError::InvalidTimeRange);
+
+    let slot_indexes = ctx
+        .accounts
+        .schedule
+        .slots
+        .iter()
+        .enumerate()
+        .filter(|(_, slot)| {
+            slot.start_ts >= range_start_ts
+                && slot.end_ts <= range_end_ts
+                && slot.status != SlotStatus::Cancelled
+        })
+        .map(|(index, _)| index)
+        .collect();
+
+    let accounts = ctx.accounts;
+    open_booking(
+        NewBooking {
+            campaign: &mut accounts.campaign,
+            delegate: accounts.delegate.as_deref_mut(),
+            location: &accounts.location,
+            schedule: &mut accounts.schedule,
+            booking: &mut accounts.booking,
+            location_policy: accounts.location_policy.as_deref(),
+            location_spend: accounts.location_spend.as_deref_mut(),
+            oracle_device: &accounts.oracle_device,
+            device_authority: &accounts.device_authority,
+            booking_authority: &accounts.booking_authority,
+            booking_authority_bump: ctx.bumps.booking_authority,
+            oracle_program: accounts.oracle_program.to_account_info(),
+            authority: accounts.authority.key(),
+        },
+        BookingTerms {
+            slot_indexes,
+            range_start_ts,
+            range_end_ts,
+            device_idx,
+            pricing_model,
+            order: None,
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Accounts shared by every instruction that opens a `CampaignBooking`.
+pub(crate) struct NewBooking<'a, 'info> {
+    pub campaign: &'a mut Account<'info, Campaign>,
+    pub delegate: Option<&'a mut Delegate>,
+    pub location: &'a Account<'info, Location>,
+    pub schedule: &'a mut LocationSchedule,
+    pub booking: &'a mut Account<'info, CampaignBooking>,
+    pub location_policy: Option<&'a LocationPolicy>,
+    pub location_spend: Option<&'a mut Account<'info, CampaignLocationSpend>>,
+    pub oracle_device: &'a AccountInfo<'info>,
+    pub device_authority: &'a AccountInfo<'info>,
+    pub booking_authority: &'a AccountInfo<'info>,
+    pub booking_authority_bump: u8,
+    pub oracle_program: AccountInfo<'info>,
+    pub authority: Pubkey,
+}
+
+/// What a new booking covers: the schedule slots it reserves and the range they span.
+pub(crate) struct BookingTerms {
+    pub slot_indexes: Vec<usize>,
+    pub range_start_ts: i64,
+    pub range_end_ts: i64,
+    pub device_idx: u64,
+    pub pricing_model: PricingModel,
+    pub order: Option<Pubkey>,
+}
+
+/// Validates and prices the selected slots, moves their escrow into the booking and
+/// locks the oracle device. Returns the escrowed amount.
+pub(crate) fn open_booking(accounts: NewBooking, terms: BookingTerms) -> Result<u64> {
+    let NewBooking {
+        campaign,
+        delegate,
+        location,
+        schedule,
+        booking,
+        location_policy,
+        location_spend,
+        oracle_device,
+        device_authority,
+        booking_authority,
+        booking_authority_bump,
+        oracle_program,
+        authority,
+    } = accounts;
+    let BookingTerms {
+        slot_indexes,
+        range_start_ts,
+        range_end_ts,
+        device_idx,
+        pricing_model,
+        order,
+    } = terms;
+
     if let PricingModel::PerImpression { price } | PricingModel::Cpm { price } = &pricing_model {
         require!(*price > 0, SoulboardError::InvalidParameters);
     }
 
-    let campaign = &mut ctx.accounts.campaign;
-    let location = &mut ctx.accounts.location;
-    let schedule = &mut ctx.accounts.schedule;
-    let booking = &mut ctx.accounts.booking;
-
-    let device = load_oracle_device(
-        &ctx.accounts.oracle_device,
-        &ctx.accounts.device_authority,
-        device_idx,
+    require_owner_or_delegate(
+        &campaign.authority,
+        &authority,
+        delegate.as_deref(),
+        PERMISSION_BOOK,
     )?;
 
+    let device = load_oracle_device(oracle_device, device_authority, device_idx)?;
+
     require_keys_eq!(device.location, location.key(), SoulboardError::InvalidOracleDevice);
     require_keys_eq!(
         device.oracle_authority,
@@ -191,7 +413,16 @@ pub fn book_location_range(
         SoulboardError::InvalidOracleAuthority
     );
 
+    let now = Clock::get()?.unix_timestamp;
+    require!(device.is_online(now), SoulboardError::OracleDeviceOffline);
+
     require_campaign_active(campaign)?;
+    require!(
+        campaign.window_contains(range_start_ts, range_end_ts),
+        SoulboardError::OutsideCampaignWindow
+    );
+    require_targeted(campaign, location)?;
+    require_policy_admits(campaign, location, location_policy)?;
     require!(
         location.location_status != LocationStatus::Inactive,
         SoulboardError::LocationInactive
@@ -204,33 +435,119 @@ pub fn book_location_range(
         schedule.authority == location.authority,
         SoulboardError::InvalidAuthority
     );
-    require!(
-        schedule.authority == location.authority,
-        SoulboardError::InvalidAuthority
-    );
     require!(
         location.oracle_authority != Pubkey::default(),
         SoulboardError::OracleNotConfigured
     );
 
-    let now = Clock::get()?.unix_timestamp;
+    let (total_price, slot_count) = reserve_slots(campaign, schedule, booking, &slot_indexes, now)?;
+    booking.location = location.key();
+    booking.charges = BookingCharges::default();
+    charge_booking_spend(
+        campaign,
+        &authority,
+        delegate,
+        location_spend,
+        location.key(),
+        &mut booking.charges,
+        total_price,
+    )?;
+
+    booking.campaign = campaign.key();
+    booking.location = location.key();
+    booking.advertiser = campaign.authority;
+    booking.provider = location.authority;
+    booking.oracle_authority = location.oracle_authority;
+    booking.device = oracle_device.key();
+    booking.device_authority = device_authority.key();
+    booking.device_idx = device_idx;
+    booking.range_start_ts = range_start_ts;
+    booking.range_end_ts = range_end_ts;
+    booking.slot_count = slot_count;
+    booking.total_price = total_price;
+    booking.pricing_model = pricing_model;
+    booking.start_impressions = device.metrics.total_impressions;
+    booking.status = if location.requires_approval {
+        BookingStatus::Pending
+    } else {
+        BookingStatus::Active
+    };
+    booking.approval_deadline = if location.requires_approval {
+        // The requested range may open before the first slot it actually booked.
+        let (first_slot_start, _) =
+            booked_range(schedule, &booking.key()).ok_or(SoulboardError::SlotNotFound)?;
+        let deadline = now
+            .checked_add(location.approval_window_secs as i64)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        Some(deadline.min(first_slot_start))
+    } else {
+        None
+    };
+    booking.created_at = now;
+    booking.updated_at = now;
+    booking.referrals = snapshot_referrals(campaign, location)?;
+    booking.order = order;
+
+    lock_oracle_device(
+        &oracle_program,
+        oracle_device,
+        device_authority,
+        booking_authority,
+        booking_authority_bump,
+        device_idx,
+    )?;
+
+    emit!(CampaignBookingCreated {
+        booking: booking.key(),
+        campaign: campaign.key(),
+        location: location.key(),
+        slot_count,
+        total_price,
+        status: booking.status.clone(),
+        order,
+    });
+
+    Ok(total_price)
+}
+
+/// Validates and prices schedule slots for `booking`, moves their escrow out of the
+/// campaign's available budget and marks them booked. Returns the price and slot count.
+pub(crate) fn reserve_slots(
+    campaign: &mut Account<Campaign>,
+    schedule: &mut LocationSchedule,
+    booking: &Account<CampaignBooking>,
+    slot_indexes: &[usize],
+    now: i64,
+) -> Result<(u64, u32)> {
     let mut total_price: u64 = 0;
     let mut slot_count: u32 = 0;
+    let campaign_key = campaign.key();
 
-    for slot in schedule.slots.iter() {
-        if slot.start_ts >= range_start_ts && slot.end_ts <= range_end_ts {
+    for &index in slot_indexes.iter() {
+        let slot = schedule
+            .slots
+            .get(index)
+            .ok_or(SoulboardError::SlotNotFound)?;
+        require!(
+            slot.start_ts > now,
+            SoulboardError::SlotInPast
+        );
+        require!(
+            slot.is_bookable_by(&campaign_key, now),
+            SoulboardError::SlotUnavailable
+        );
+        if let Some(targeting) = &campaign.targeting {
             require!(
-                slot.start_ts > now,
-                SoulboardError::SlotInPast
+                targeting.allows_price(slot.price),
+                SoulboardError::TargetingMismatch
             );
-            require!(slot.status == SlotStatus::Available, SoulboardError::SlotUnavailable);
-            total_price = total_price
-                .checked_add(slot.price)
-                .ok_or(SoulboardError::ArithmeticOverflow)?;
-            slot_count = slot_count
-                .checked_add(1)
-                .ok_or(SoulboardError::ArithmeticOverflow)?;
         }
+        total_price = total_price
+            .checked_add(slot.price)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        slot_count = slot_count
+            .checked_add(1)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
     }
 
     require!(slot_count > 0, SoulboardError::SlotNotFound);
@@ -255,42 +572,122 @@ pub fn book_location_range(
         total_price,
     )?;
 
-    for slot in schedule.slots.iter_mut() {
-        if slot.start_ts >= range_start_ts && slot.end_ts <= range_end_ts {
-            slot.status = SlotStatus::Booked;
-            slot.booking = booking.key();
-        }
+    for &index in slot_indexes.iter() {
+        let slot = &mut schedule.slots[index];
+        slot.status = SlotStatus::Booked;
+        slot.booking = booking.key();
     }
 
+    Ok((total_price, slot_count))
+}
+
+/// Charges new booking spend at `location` against the signer's delegate allowance and the
+/// campaign's pacing caps, including the per-location running total.
+pub(crate) fn charge_booking_spend(
+    campaign: &mut Account<Campaign>,
+    authority: &Pubkey,
+    delegate: Option<&mut Delegate>,
+    location_spend: Option<&mut Account<CampaignLocationSpend>>,
+    location: Pubkey,
+    charges: &mut BookingCharges,
+    amount: u64,
+) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
-    booking.campaign = campaign.key();
-    booking.location = location.key();
-    booking.advertiser = campaign.authority;
-    booking.provider = location.authority;
-    booking.oracle_authority = location.oracle_authority;
-    booking.device = ctx.accounts.oracle_device.key();
-    booking.device_authority = ctx.accounts.device_authority.key();
-    booking.device_idx = device_idx;
-    booking.range_start_ts = range_start_ts;
-    booking.range_end_ts = range_end_ts;
-    booking.slot_count = slot_count;
-    booking.total_price = total_price;
-    booking.pricing_model = pricing_model;
-    booking.start_impressions = device.metrics.total_impressions;
-    booking.status = BookingStatus::Active;
-    booking.created_at = now;
-    booking.updated_at = now;
-    booking.impressions = 0;
-    booking.settled_amount = 0;
-    booking.fee_amount = 0;
+    if *authority != campaign.authority {
+        // Refunds credit a single delegate, so only the one that opened the booking
+        // may add to it.
+        require!(
+            charges.delegate.is_none() || charges.delegate == Some(*authority),
+            SoulboardError::Unauthorized
+        );
+        charges.delegate = Some(*authority);
+        charges.delegate_amount = charges
+            .delegate_amount
+            .checked_add(amount)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+    }
+    charge_delegate_spend(&campaign.authority, authority, delegate, amount)?;
+    campaign.charge_pacing(amount, now)?;
+    if campaign.pacing.is_some() {
+        if charges.pacing_window_start != campaign.pacing_window_start {
+            charges.pacing_window_start = campaign.pacing_window_start;
+            charges.pacing_amount = 0;
+        }
+        charges.pacing_amount = charges
+            .pacing_amount
+            .checked_add(amount)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+    }
 
-    emit!(CampaignBookingCreated {
-        booking: booking.key(),
-        campaign: campaign.key(),
-        location: location.key(),
-        slot_count,
-        total_price,
-    });
+    let max_per_location = campaign.pacing.and_then(|pacing| pacing.max_per_location);
+    match location_spend {
+        Some(location_spend) => {
+            location_spend.campaign = campaign.key();
+            location_spend.location = location;
+            location_spend.spent = location_spend
+                .spent
+                .checked_add(amount)
+                .ok_or(SoulboardError::ArithmeticOverflow)?;
+            if let Some(max_per_location) = max_per_location {
+                require!(
+                    location_spend.spent <= max_per_location,
+                    SoulboardError::PacingLimitExceeded
+                );
+            }
+            charges.location_amount = charges
+                .location_amount
+                .checked_add(amount)
+                .ok_or(SoulboardError::ArithmeticOverflow)?;
+        }
+        None => require!(max_per_location.is_none(), SoulboardError::InvalidParameters),
+    }
+
+    Ok(())
+}
+
+/// Gives `amount` of a refunded booking back to the spend counters it charged. The
+/// pacing window is only credited while it is still the window the booking charged.
+/// `signer_delegate` is the signer's already loaded delegate, which must be credited in
+/// place when it is the one the booking charged.
+pub(crate) fn credit_booking_spend(
+    campaign: &mut Campaign,
+    signer_delegate: Option<&mut Delegate>,
+    spend_delegate: Option<&AccountInfo>,
+    location_spend: Option<&mut Account<CampaignLocationSpend>>,
+    charges: &mut BookingCharges,
+    amount: u64,
+) -> Result<()> {
+    let delegate_credit = amount.min(charges.delegate_amount);
+    if let (Some(operator), true) = (charges.delegate, delegate_credit > 0) {
+        match signer_delegate {
+            Some(delegate) if delegate.delegate == operator => {
+                delegate.spent = delegate.spent.saturating_sub(delegate_credit);
+            }
+            _ => {
+                let spend_delegate = spend_delegate.ok_or(SoulboardError::InvalidParameters)?;
+                credit_delegate_spend(
+                    spend_delegate,
+                    &campaign.authority,
+                    &operator,
+                    delegate_credit,
+                )?;
+            }
+        }
+        charges.delegate_amount -= delegate_credit;
+    }
+
+    let location_credit = amount.min(charges.location_amount);
+    if location_credit > 0 {
+        let location_spend = location_spend.ok_or(SoulboardError::InvalidParameters)?;
+        location_spend.spent = location_spend.spent.saturating_sub(location_credit);
+        charges.location_amount -= location_credit;
+    }
+
+    let pacing_credit = amount.min(charges.pacing_amount);
+    if campaign.pacing_window_start == charges.pacing_window_start {
+        campaign.pacing_window_spent = campaign.pacing_window_spent.saturating_sub(pacing_credit);
+    }
+    charges.pacing_amount -= pacing_credit;
 
     Ok(())
 }
@@ -299,16 +696,20 @@ pub fn cancel_location_booking(
     ctx: Context<CancelLocationBooking>,
     _campaign_idx: u64,
     _location_idx: u64,
-    _range_start_ts: i64,
-    _range_end_ts: i64,
 ) -> Result<()> {
     let campaign = &mut ctx.accounts.campaign;
     let location = &mut ctx.accounts.location;
     let schedule = &mut ctx.accounts.schedule;
     let booking = &mut ctx.accounts.booking;
 
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_CANCEL,
+    )?;
     require!(
-        booking.status == BookingStatus::Active,
+        matches!(booking.status, BookingStatus::Active | BookingStatus::Pending),
         SoulboardError::BookingNotActive
     );
     require_keys_eq!(booking.campaign, campaign.key(), SoulboardError::InvalidParameters);
@@ -321,40 +722,256 @@ pub fn cancel_location_booking(
         schedule.authority == location.authority,
         SoulboardError::InvalidAuthority
     );
+    require_keys_eq!(
+        booking.device,
+        ctx.accounts.oracle_device.key(),
+        SoulboardError::InvalidOracleDevice
+    );
+    require_keys_eq!(
+        booking.device_authority,
+        ctx.accounts.device_authority.key(),
+        SoulboardError::InvalidOracleDevice
+    );
+
+    let total_price = refund_booking_escrow(campaign, schedule, booking, SlotStatus::Available)?;
+    credit_booking_spend(
+        campaign,
+        None,
+        ctx.accounts.spend_delegate.as_deref(),
+        ctx.accounts.location_spend.as_deref_mut(),
+        &mut booking.charges,
+        total_price,
+    )?;
+    if let Some(order) = booking_order(ctx.accounts.order.as_mut(), booking)? {
+        order.release(total_price, true)?;
+    }
+
+    booking.status = BookingStatus::Cancelled;
+    booking.updated_at = Clock::get()?.unix_timestamp;
+
+    release_oracle_device(
+        &ctx.accounts.oracle_program.to_account_info(),
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        &ctx.accounts.booking_authority,
+        ctx.bumps.booking_authority,
+        booking.device_idx,
+    )?;
+
+    emit!(CampaignBookingCancelled {
+        booking: booking.key(),
+        campaign: campaign.key(),
+        location: location.key(),
+        refunded_amount: total_price,
+    });
+
+    Ok(())
+}
+
+/// Lets the provider back out of a booking before it ends: the campaign gets a full refund
+/// plus `compensation_bps` of the booking price taken from the provider's pending earnings,
+/// and the slots are marked `Cancelled`.
+pub fn provider_cancel_booking(
+    ctx: Context<ProviderCancelBooking>,
+    _location_idx: u64,
+    reason: ProviderCancelReason,
+) -> Result<()> {
+    require_owner_or_delegate(
+        &ctx.accounts.provider.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_PROVIDER_CANCEL,
+    )?;
+    let compensation_bps = ctx.accounts.config.provider_cancel_compensation_bps;
+
+    let campaign = &mut ctx.accounts.campaign;
+    let schedule = &mut ctx.accounts.schedule;
+    let booking = &mut ctx.accounts.booking;
+    require!(
+        matches!(booking.status, BookingStatus::Active | BookingStatus::Pending),
+        SoulboardError::BookingNotActive
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < booking.range_end_ts, SoulboardError::CancellationCutoffPassed);
+
+    let compensation = bps_share(booking.total_price, compensation_bps)?;
+    let refunded_amount = refund_booking_escrow(campaign, schedule, booking, SlotStatus::Cancelled)?;
+    credit_booking_spend(
+        campaign,
+        None,
+        ctx.accounts.spend_delegate.as_deref(),
+        ctx.accounts.location_spend.as_deref_mut(),
+        &mut booking.charges,
+        refunded_amount,
+    )?;
+    if let Some(order) = booking_order(ctx.accounts.order.as_mut(), booking)? {
+        order.release(refunded_amount, true)?;
+    }
+    if compensation > 0 {
+        let earnings_info = ctx.accounts.earnings.to_account_info();
+        ensure_rent_exempt_after_withdraw(&earnings_info, compensation)?;
+        ctx.accounts.earnings.forfeit(compensation)?;
+        move_lamports(&earnings_info, &campaign.to_account_info(), compensation)?;
+        campaign.available_budget = campaign
+            .available_budget
+            .checked_add(compensation)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+    }
 
-    let total_price = booking.total_price;
-    ensure_rent_exempt_after_withdraw(&booking.to_account_info(), total_price)?;
+    booking.status = BookingStatus::Cancelled;
+    booking.updated_at = now;
+
+    let provider = &mut ctx.accounts.provider;
+    provider.cancellation_count = provider
+        .cancellation_count
+        .checked_add(1)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+
+    release_oracle_device(
+        &ctx.accounts.oracle_program.to_account_info(),
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        &ctx.accounts.booking_authority,
+        ctx.bumps.booking_authority,
+        booking.device_idx,
+    )?;
+
+    emit!(CampaignBookingCancelledByProvider {
+        booking: booking.key(),
+        campaign: campaign.key(),
+        location: booking.location,
+        reason,
+        refunded_amount,
+        compensation,
+        cancellation_count: provider.cancellation_count,
+    });
+
+    Ok(())
+}
+
+/// Frees booked slots that start at or after `cutoff` and returns their price from the
+/// booking escrow to the campaign. Returns the refund and slot count.
+pub(crate) fn release_slots(
+    campaign: &mut Account<Campaign>,
+    schedule: &mut LocationSchedule,
+    booking: &Account<CampaignBooking>,
+    slot_indexes: &[u32],
+    cutoff: i64,
+) -> Result<(u64, u32)> {
+    let booking_key = booking.key();
+    let mut refund: u64 = 0;
+    let mut slot_count: u32 = 0;
+    for &index in slot_indexes.iter() {
+        let slot = schedule
+            .slots
+            .get_mut(index as usize)
+            .ok_or(SoulboardError::SlotNotFound)?;
+        require!(
+            slot.booking == booking_key && slot.status == SlotStatus::Booked,
+            SoulboardError::SlotNotInBooking
+        );
+        require!(slot.start_ts >= cutoff, SoulboardError::CancellationCutoffPassed);
+        refund = refund
+            .checked_add(slot.price)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        slot_count = slot_count
+            .checked_add(1)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        slot.status = SlotStatus::Available;
+        slot.booking = Pubkey::default();
+    }
 
     move_lamports(
         &booking.to_account_info(),
         &campaign.to_account_info(),
-        total_price,
+        refund,
     )?;
-
     campaign.reserved_budget = campaign
         .reserved_budget
-        .checked_sub(total_price)
+        .checked_sub(refund)
         .ok_or(SoulboardError::ArithmeticUnderflow)?;
     campaign.available_budget = campaign
         .available_budget
-        .checked_add(total_price)
+        .checked_add(refund)
         .ok_or(SoulboardError::ArithmeticOverflow)?;
 
-    for slot in schedule.slots.iter_mut() {
-        if slot.booking == booking.key() {
-            slot.status = SlotStatus::Available;
-            slot.booking = Pubkey::default();
-        }
+    Ok((refund, slot_count))
+}
+
+/// Drops some of a booking's slots, refunding their price and freeing them in the
+/// schedule. The booking keeps at least one slot; use `cancel_location_booking` for all.
+pub fn cancel_booking_slots(
+    ctx: Context<CancelBookingSlots>,
+    _campaign_idx: u64,
+    _location_idx: u64,
+    slot_indexes: Vec<u32>,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let location = &ctx.accounts.location;
+    let schedule = &mut ctx.accounts.schedule;
+    let booking = &mut ctx.accounts.booking;
+
+    require_owner_or_delegate(
+        &campaign.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        PERMISSION_CANCEL,
+    )?;
+    require!(
+        matches!(booking.status, BookingStatus::Active | BookingStatus::Pending),
+        SoulboardError::BookingNotActive
+    );
+    require!(
+        location.cancellation.allow_partial,
+        SoulboardError::PartialCancelNotAllowed
+    );
+    require!(
+        !slot_indexes.is_empty() && slot_indexes.windows(2).all(|pair| pair[0] < pair[1]),
+        SoulboardError::InvalidParameters
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let cutoff = now
+        .checked_add(location.cancellation.cutoff_secs as i64)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+    let booking_key = booking.key();
+    let (refund, cancelled_slots) = release_slots(campaign, schedule, booking, &slot_indexes, cutoff)?;
+    credit_booking_spend(
+        campaign,
+        None,
+        ctx.accounts.spend_delegate.as_deref(),
+        ctx.accounts.location_spend.as_deref_mut(),
+        &mut booking.charges,
+        refund,
+    )?;
+    if let Some(order) = booking_order(ctx.accounts.order.as_mut(), booking)? {
+        order.release(refund, false)?;
     }
 
-    booking.status = BookingStatus::Cancelled;
-    booking.updated_at = Clock::get()?.unix_timestamp;
+    let remaining_slots = booking
+        .slot_count
+        .checked_sub(cancelled_slots)
+        .ok_or(SoulboardError::ArithmeticUnderflow)?;
+    require!(remaining_slots > 0, SoulboardError::InvalidParameters);
+    let (range_start_ts, range_end_ts) =
+        booked_range(schedule, &booking_key).ok_or(SoulboardError::SlotNotFound)?;
 
-    emit!(CampaignBookingCancelled {
-        booking: booking.key(),
+    booking.total_price = booking
+        .total_price
+        .checked_sub(refund)
+        .ok_or(SoulboardError::ArithmeticUnderflow)?;
+    booking.slot_count = remaining_slots;
+    booking.range_start_ts = range_start_ts;
+    booking.range_end_ts = range_end_ts;
+    booking.updated_at = now;
+
+    emit!(CampaignBookingSlotsCancelled {
+        booking: booking_key,
         campaign: campaign.key(),
         location: location.key(),
-        refunded_amount: total_price,
+        cancelled_slots,
+        remaining_slots,
+        refunded_amount: refund,
     });
 
     Ok(())
@@ -364,8 +981,6 @@ pub fn settle_location_booking(
     ctx: Context<SettleLocationBooking>,
     _campaign_idx: u64,
     _location_idx: u64,
-    _range_start_ts: i64,
-    _range_end_ts: i64,
     campaign_authority: Pubkey,
     provider_authority: Pubkey,
 ) -> Result<()> {
@@ -375,11 +990,14 @@ pub fn settle_location_booking(
     let booking = &mut ctx.accounts.booking;
     let config = &ctx.accounts.config;
 
-    let device = load_oracle_device(
+    // The crank must still be able to close out a booking after the provider
+    // deactivated the device, so the status is only enforced for the oracle path.
+    let device = read_oracle_device(
         &ctx.accounts.oracle_device,
         &ctx.accounts.device_authority,
         booking.device_idx,
     )?;
+    let device_active = device.status == OracleDeviceStatus::Active;
 
     require_keys_eq!(campaign.authority, campaign_authority, SoulboardError::InvalidAuthority);
     require_keys_eq!(location.authority, provider_authority, SoulboardError::InvalidAuthority);
@@ -397,16 +1015,6 @@ pub fn settle_location_booking(
         schedule.authority == location.authority,
         SoulboardError::InvalidAuthority
     );
-    require_keys_eq!(
-        booking.oracle_authority,
-        ctx.accounts.oracle_authority.key(),
-        SoulboardError::InvalidOracleAuthority
-    );
-    require_keys_eq!(
-        location.oracle_authority,
-        booking.oracle_authority,
-        SoulboardError::InvalidOracleAuthority
-    );
     require_keys_eq!(
         booking.device,
         ctx.accounts.oracle_device.key(),
@@ -422,30 +1030,56 @@ pub fn settle_location_booking(
         location.key(),
         SoulboardError::InvalidOracleDevice
     );
-    require_keys_eq!(
-        device.oracle_authority,
-        booking.oracle_authority,
-        SoulboardError::InvalidOracleAuthority
-    );
-    require_keys_eq!(
-        ctx.accounts.location_authority.key(),
-        location.authority,
-        SoulboardError::InvalidAuthority
-    );
-    require_keys_eq!(
-        ctx.accounts.treasury.key(),
-        config.treasury,
-        SoulboardError::InvalidAuthority
-    );
 
-    let impressions = device
-        .metrics
-        .total_impressions
-        .checked_sub(booking.start_impressions)
-        .ok_or(SoulboardError::ArithmeticUnderflow)?;
+    // Without the oracle signer, anyone may settle after the grace period. Impression
+    // counts are only trusted if the booked oracle still reports for the device.
+    let now = Clock::get()?.unix_timestamp;
+    let permissionless = ctx.accounts.oracle_authority.is_none();
+    let metrics_trusted = match ctx.accounts.oracle_authority.as_ref() {
+        Some(oracle_authority) => {
+            require!(device_active, SoulboardError::OracleDeviceInactive);
+            require_keys_eq!(
+                booking.oracle_authority,
+                oracle_authority.key(),
+                SoulboardError::InvalidOracleAuthority
+            );
+            require_keys_eq!(
+                location.oracle_authority,
+                booking.oracle_authority,
+                SoulboardError::InvalidOracleAuthority
+            );
+            require_keys_eq!(
+                device.oracle_authority,
+                booking.oracle_authority,
+                SoulboardError::InvalidOracleAuthority
+            );
+            true
+        }
+        None => {
+            let settle_after = booking
+                .range_end_ts
+                .checked_add(config.settlement_grace_secs as i64)
+                .ok_or(SoulboardError::ArithmeticOverflow)?;
+            require!(now >= settle_after, SoulboardError::SettlementGracePending);
+            config.fallback_settlement == FallbackSettlement::UseMetrics
+                && device_active
+                && device.oracle_authority == booking.oracle_authority
+        }
+    };
+
+    let impressions = if metrics_trusted {
+        device
+            .metrics
+            .total_impressions
+            .checked_sub(booking.start_impressions)
+            .ok_or(SoulboardError::ArithmeticUnderflow)?
+    } else {
+        0
+    };
 
     let gross_raw = match booking.pricing_model {
         PricingModel::TimeSlot => booking.total_price,
+        _ if !metrics_trusted => 0,
         PricingModel::PerImpression { price } => price
             .checked_mul(impressions)
             .ok_or(SoulboardError::ArithmeticOverflow)?,
@@ -463,33 +1097,28 @@ pub fn settle_location_booking(
     } else {
         gross_raw
     };
-    let fee_amount = gross
-        .checked_mul(config.fee_bps as u64)
-        .ok_or(SoulboardError::ArithmeticOverflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(SoulboardError::ArithmeticUnderflow)?;
-    let net_amount = gross
-        .checked_sub(fee_amount)
-        .ok_or(SoulboardError::ArithmeticUnderflow)?;
     let refund = booking
         .total_price
         .checked_sub(gross)
         .ok_or(SoulboardError::ArithmeticUnderflow)?;
+    credit_booking_spend(
+        campaign,
+        None,
+        ctx.accounts.spend_delegate.as_deref(),
+        ctx.accounts.location_spend.as_deref_mut(),
+        &mut booking.charges,
+        refund,
+    )?;
+    if let Some(order) = booking_order(ctx.accounts.order.as_mut(), booking)? {
+        order.release(booking.total_price, true)?;
+        order.settled_amount = order
+            .settled_amount
+            .checked_add(gross)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+    }
 
     ensure_rent_exempt_after_withdraw(&booking.to_account_info(), booking.total_price)?;
 
-    move_lamports(
-        &booking.to_account_info(),
-        &ctx.accounts.location_authority.to_account_info(),
-        net_amount,
-    )?;
-    if fee_amount > 0 {
-        move_lamports(
-            &booking.to_account_info(),
-            &ctx.accounts.treasury.to_account_info(),
-            fee_amount,
-        )?;
-    }
     if refund > 0 {
         move_lamports(
             &booking.to_account_info(),
@@ -507,17 +1136,45 @@ pub fn settle_location_booking(
         .checked_add(refund)
         .ok_or(SoulboardError::ArithmeticOverflow)?;
 
+    let split = pay_out_settlement(
+        &booking.to_account_info(),
+        campaign,
+        &booking.referrals,
+        SettlementPayees {
+            config,
+            provider_fee_override: &ctx.accounts.provider_fee_override,
+            location_fee_override: &ctx.accounts.location_fee_override,
+            earnings: &mut ctx.accounts.earnings,
+            treasury: &mut ctx.accounts.treasury,
+            agency: ctx.accounts.agency.as_deref(),
+            agency_authority: ctx.accounts.agency_authority.as_deref(),
+            campaign_referrer: ctx.accounts.campaign_referrer.as_deref(),
+            location_referrer: ctx.accounts.location_referrer.as_deref(),
+        },
+        gross,
+        now,
+    )?;
+
+    // The booking is closed below; a later booking at the same address must not pick
+    // these slots up again.
     for slot in schedule.slots.iter_mut() {
         if slot.booking == booking.key() {
             slot.status = SlotStatus::Settled;
+            slot.booking = Pubkey::default();
         }
     }
 
     booking.status = BookingStatus::Settled;
-    booking.updated_at = Clock::get()?.unix_timestamp;
-    booking.impressions = impressions;
-    booking.settled_amount = gross;
-    booking.fee_amount = fee_amount;
+    booking.updated_at = now;
+
+    release_oracle_device(
+        &ctx.accounts.oracle_program.to_account_info(),
+        &ctx.accounts.oracle_device,
+        &ctx.accounts.device_authority,
+        &ctx.accounts.booking_authority,
+        ctx.bumps.booking_authority,
+        booking.device_idx,
+    )?;
 
     emit!(CampaignBookingSettled {
         booking: booking.key(),
@@ -525,8 +1182,12 @@ pub fn settle_location_booking(
         location: location.key(),
         impressions,
         settled_amount: gross,
-        fee_amount,
+        fee_amount: split.fee_amount,
+        agency: campaign.agency,
+        agency_commission: split.agency_commission,
+        referral_amount: split.referral_amount,
         refunded_amount: refund,
+        permissionless,
     });
 
     Ok(())
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::constant::{BPS_DENOMINATOR, MAX_TREASURY_BENEFICIARIES};
+use crate::context::WithdrawTreasury;
+use crate::errors::SoulboardError;
+use crate::states::{TreasurySplit, TreasuryWithdrawn};
+use crate::utils::{bps_share, ensure_rent_exempt_after_withdraw, move_lamports};
+
+/// Withdraws collected fees. Each split pays its bps share of `amount` to the matching
+/// beneficiary in `remaining_accounts`; whatever is left goes to `recipient`.
+pub fn withdraw_treasury<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawTreasury<'info>>,
+    amount: u64,
+    splits: Vec<TreasurySplit>,
+) -> Result<()> {
+    require!(amount > 0, SoulboardError::InvalidParameters);
+    require!(
+        splits.len() <= MAX_TREASURY_BENEFICIARIES,
+        SoulboardError::InvalidParameters
+    );
+    require!(
+        ctx.remaining_accounts.len() == splits.len(),
+        SoulboardError::InvalidParameters
+    );
+    let total_bps = splits
+        .iter()
+        .try_fold(0u64, |total, split| total.checked_add(split.bps as u64))
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+    require!(total_bps <= BPS_DENOMINATOR, SoulboardError::InvalidParameters);
+
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    ensure_rent_exempt_after_withdraw(&treasury_info, amount)?;
+
+    let mut remaining = amount;
+    for (split, beneficiary) in splits.iter().zip(ctx.remaining_accounts.iter()) {
+        require_keys_eq!(
+            beneficiary.key(),
+            split.beneficiary,
+            SoulboardError::InvalidParameters
+        );
+        require!(beneficiary.is_writable, SoulboardError::InvalidParameters);
+        let share = bps_share(amount, split.bps)?;
+        if share > 0 {
+            move_lamports(&treasury_info, beneficiary, share)?;
+        }
+        remaining = remaining
+            .checked_sub(share)
+            .ok_or(SoulboardError::ArithmeticUnderflow)?;
+    }
+    if remaining > 0 {
+        move_lamports(
+            &treasury_info,
+            &ctx.accounts.recipient.to_account_info(),
+            remaining,
+        )?;
+    }
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_withdrawn = treasury
+        .total_withdrawn
+        .checked_add(amount)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+
+    emit!(TreasuryWithdrawn {
+        treasury: treasury.key(),
+        amount,
+        recipient: ctx.accounts.recipient.key(),
+        recipient_amount: remaining,
+        splits,
+        total_withdrawn: treasury.total_withdrawn,
+    });
+
+    Ok(())
+}
@@ -7,7 +7,10 @@ pub mod states;
 pub mod utils;
 
 use context::*;
-use states::{LocationStatus, PricingModel};
+use states::{
+    CampaignPacing, CampaignTargeting, CancellationPolicy, FallbackSettlement, FeeOverrideScope, LocationProfile, LocationStatus, OperatingHours,
+    PricingModel, ProviderCancelReason, Referral, ReferralFeeMode, TreasurySplit,
+};
 declare_id!("915wZsHsUJ7Pdei1XUY8jtdfia7D8t4r9XkhGD3TvrDV");
 
 #[program]
@@ -18,14 +21,118 @@ pub mod soulboard {
         crate::instructions::advertiser::create_advertiser(ctx)
     }
 
-    pub fn initialize_config(ctx: Context<InitializeConfig>, treasury: Pubkey) -> Result<()> {
-        crate::instructions::slot::initialize_config(ctx, treasury)
+    pub fn transfer_advertiser(
+        ctx: Context<TransferAdvertiser>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        crate::instructions::advertiser::transfer_advertiser(ctx, new_authority)
+    }
+
+    pub fn create_agency(ctx: Context<CreateAgency>, commission_bps: u16) -> Result<()> {
+        crate::instructions::agency::create_agency(ctx, commission_bps)
+    }
+
+    pub fn set_agency_commission(ctx: Context<UpdateAgency>, commission_bps: u16) -> Result<()> {
+        crate::instructions::agency::set_agency_commission(ctx, commission_bps)
+    }
+
+    pub fn transfer_agency(ctx: Context<UpdateAgency>, new_authority: Pubkey) -> Result<()> {
+        crate::instructions::agency::transfer_agency(ctx, new_authority)
+    }
+
+    pub fn link_agency(ctx: Context<LinkAgency>) -> Result<()> {
+        crate::instructions::agency::link_agency(ctx)
+    }
+
+    pub fn unlink_agency(ctx: Context<UnlinkAgency>) -> Result<()> {
+        crate::instructions::agency::unlink_agency(ctx)
+    }
+
+    pub fn set_delegate(
+        ctx: Context<SetDelegate>,
+        delegate: Pubkey,
+        permissions: u16,
+        spend_limit: Option<u64>,
+    ) -> Result<()> {
+        crate::instructions::delegate::set_delegate(ctx, delegate, permissions, spend_limit)
+    }
+
+    pub fn revoke_delegate(ctx: Context<RevokeDelegate>, delegate: Pubkey) -> Result<()> {
+        crate::instructions::delegate::revoke_delegate(ctx, delegate)
+    }
+
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        crate::instructions::slot::initialize_config(ctx)
+    }
+
+    pub fn withdraw_treasury<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawTreasury<'info>>,
+        amount: u64,
+        splits: Vec<TreasurySplit>,
+    ) -> Result<()> {
+        crate::instructions::treasury::withdraw_treasury(ctx, amount, splits)
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        max_referral_bps: u16,
+        referral_fee_mode: ReferralFeeMode,
+    ) -> Result<()> {
+        crate::instructions::slot::update_config(ctx, max_referral_bps, referral_fee_mode)
+    }
+
+    pub fn set_settlement_fallback(
+        ctx: Context<UpdateConfig>,
+        settlement_grace_secs: u32,
+        fallback_settlement: FallbackSettlement,
+    ) -> Result<()> {
+        crate::instructions::slot::set_settlement_fallback(
+            ctx,
+            settlement_grace_secs,
+            fallback_settlement,
+        )
+    }
+
+    pub fn set_provider_cancel_compensation(
+        ctx: Context<UpdateConfig>,
+        compensation_bps: u16,
+    ) -> Result<()> {
+        crate::instructions::slot::set_provider_cancel_compensation(ctx, compensation_bps)
+    }
+
+    pub fn set_fee_override(
+        ctx: Context<SetFeeOverride>,
+        target: Pubkey,
+        scope: FeeOverrideScope,
+        fee_bps: u16,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        crate::instructions::slot::set_fee_override(ctx, target, scope, fee_bps, expires_at)
+    }
+
+    pub fn remove_fee_override(ctx: Context<RemoveFeeOverride>, target: Pubkey) -> Result<()> {
+        crate::instructions::slot::remove_fee_override(ctx, target)
+    }
+
+    pub fn transfer_config_authority(
+        ctx: Context<TransferConfigAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        crate::instructions::slot::transfer_config_authority(ctx, new_authority)
     }
 
     pub fn create_provider(ctx: Context<CreateProvider>) -> Result<()> {
         crate::instructions::advertiser::create_provider(ctx)
     }
 
+    pub fn transfer_provider(ctx: Context<TransferProvider>, new_authority: Pubkey) -> Result<()> {
+        crate::instructions::advertiser::transfer_provider(ctx, new_authority)
+    }
+
+    pub fn withdraw_earnings(ctx: Context<WithdrawEarnings>, amount: u64) -> Result<()> {
+        crate::instructions::earnings::withdraw_earnings(ctx, amount)
+    }
+
     pub fn create_campaign(
         ctx: Context<CreateCampaign>,
         campaign_name: String,
@@ -70,16 +177,85 @@ pub mod soulboard {
         crate::instructions::budget::withdraw_budget(ctx, campaign_idx, amount)
     }
 
+    pub fn pause_campaign(ctx: Context<SetCampaignStatus>, campaign_idx: u64) -> Result<()> {
+        crate::instructions::campaign::pause_campaign(ctx, campaign_idx)
+    }
+
+    pub fn resume_campaign(ctx: Context<SetCampaignStatus>, campaign_idx: u64) -> Result<()> {
+        crate::instructions::campaign::resume_campaign(ctx, campaign_idx)
+    }
+
+    pub fn set_campaign_window(
+        ctx: Context<UpdateCampaign>,
+        campaign_idx: u64,
+        starts_at: Option<i64>,
+        ends_at: Option<i64>,
+    ) -> Result<()> {
+        crate::instructions::campaign::set_campaign_window(ctx, campaign_idx, starts_at, ends_at)
+    }
+
+    pub fn set_campaign_pacing(
+        ctx: Context<UpdateCampaign>,
+        campaign_idx: u64,
+        pacing: Option<CampaignPacing>,
+    ) -> Result<()> {
+        crate::instructions::campaign::set_campaign_pacing(ctx, campaign_idx, pacing)
+    }
+
+    pub fn set_campaign_content(
+        ctx: Context<UpdateCampaign>,
+        campaign_idx: u64,
+        content_categories: u16,
+    ) -> Result<()> {
+        crate::instructions::campaign::set_campaign_content(ctx, campaign_idx, content_categories)
+    }
+
+    pub fn set_campaign_targeting(
+        ctx: Context<SetCampaignTargeting>,
+        campaign_idx: u64,
+        targeting: Option<CampaignTargeting>,
+    ) -> Result<()> {
+        crate::instructions::campaign::set_campaign_targeting(ctx, campaign_idx, targeting)
+    }
+
+    pub fn expire_campaign(ctx: Context<ExpireCampaign>, campaign_idx: u64) -> Result<()> {
+        crate::instructions::campaign::expire_campaign(ctx, campaign_idx)
+    }
+
+    pub fn end_campaign(ctx: Context<EndCampaign>, campaign_idx: u64) -> Result<()> {
+        crate::instructions::campaign::end_campaign(ctx, campaign_idx)
+    }
+
+    pub fn archive_campaign(ctx: Context<ArchiveCampaign>, campaign_idx: u64) -> Result<()> {
+        crate::instructions::campaign::archive_campaign(ctx, campaign_idx)
+    }
+
     pub fn close_campaign(ctx: Context<CloseCampaign>, campaign_idx: u64) -> Result<()> {
         crate::instructions::campaign::close_campaign(ctx, campaign_idx)
     }
 
+    pub fn set_campaign_referral(
+        ctx: Context<SetCampaignReferral>,
+        campaign_idx: u64,
+        referral: Option<Referral>,
+    ) -> Result<()> {
+        crate::instructions::campaign::set_campaign_referral(ctx, campaign_idx, referral)
+    }
+
+    pub fn sync_campaign_authority(
+        ctx: Context<SyncCampaignAuthority>,
+        campaign_idx: u64,
+    ) -> Result<()> {
+        crate::instructions::campaign::sync_campaign_authority(ctx, campaign_idx)
+    }
+
     pub fn register_location(
         ctx: Context<RegisterLocation>,
         location_name: String,
         location_description: String,
         price: u64,
         oracle_authority: Pubkey,
+        profile: LocationProfile,
     ) -> Result<()> {
         crate::instructions::location::register_location(
             ctx,
@@ -87,6 +263,7 @@ pub mod soulboard {
             location_description,
             price,
             oracle_authority,
+            profile,
         )
     }
 
@@ -104,8 +281,16 @@ pub mod soulboard {
         start_ts: i64,
         end_ts: i64,
         price: u64,
+        allow_outside_hours: bool,
     ) -> Result<()> {
-        crate::instructions::slot::add_location_slot(ctx, location_idx, start_ts, end_ts, price)
+        crate::instructions::slot::add_location_slot(
+            ctx,
+            location_idx,
+            start_ts,
+            end_ts,
+            price,
+            allow_outside_hours,
+        )
     }
 
     pub fn book_location_range(
@@ -128,19 +313,124 @@ pub mod soulboard {
         )
     }
 
+    pub fn create_booking_order(ctx: Context<CreateBookingOrder>, campaign_idx: u64) -> Result<()> {
+        crate::instructions::order::create_booking_order(ctx, campaign_idx)
+    }
+
+    pub fn book_order_slots(
+        ctx: Context<BookOrderSlots>,
+        campaign_idx: u64,
+        order_id: u64,
+        location_idx: u64,
+        slot_indexes: Vec<u32>,
+        device_idx: u64,
+        pricing_model: PricingModel,
+    ) -> Result<()> {
+        crate::instructions::order::book_order_slots(
+            ctx,
+            campaign_idx,
+            order_id,
+            location_idx,
+            slot_indexes,
+            device_idx,
+            pricing_model,
+        )
+    }
+
+    pub fn close_booking_order(
+        ctx: Context<CloseBookingOrder>,
+        campaign_idx: u64,
+        order_id: u64,
+    ) -> Result<()> {
+        crate::instructions::order::close_booking_order(ctx, campaign_idx, order_id)
+    }
+
     pub fn cancel_location_booking(
         ctx: Context<CancelLocationBooking>,
         campaign_idx: u64,
         location_idx: u64,
+    ) -> Result<()> {
+        crate::instructions::slot::cancel_location_booking(ctx, campaign_idx, location_idx)
+    }
+
+    pub fn hold_location_slots(
+        ctx: Context<HoldLocationSlots>,
+        campaign_idx: u64,
+        location_idx: u64,
         range_start_ts: i64,
         range_end_ts: i64,
+        hold_secs: u32,
+        device_idx: u64,
     ) -> Result<()> {
-        crate::instructions::slot::cancel_location_booking(
+        crate::instructions::hold::hold_location_slots(
             ctx,
             campaign_idx,
             location_idx,
             range_start_ts,
             range_end_ts,
+            hold_secs,
+            device_idx,
+        )
+    }
+
+    pub fn release_slot_holds(ctx: Context<ReleaseSlotHolds>, location_idx: u64) -> Result<()> {
+        crate::instructions::hold::release_slot_holds(ctx, location_idx)
+    }
+
+    pub fn accept_booking(ctx: Context<AcceptBooking>, location_idx: u64) -> Result<()> {
+        crate::instructions::approval::accept_booking(ctx, location_idx)
+    }
+
+    pub fn reject_booking(ctx: Context<ResolvePendingBooking>, location_idx: u64) -> Result<()> {
+        crate::instructions::approval::reject_booking(ctx, location_idx)
+    }
+
+    pub fn expire_pending_booking(
+        ctx: Context<ResolvePendingBooking>,
+        location_idx: u64,
+    ) -> Result<()> {
+        crate::instructions::approval::expire_pending_booking(ctx, location_idx)
+    }
+
+    pub fn provider_cancel_booking(
+        ctx: Context<ProviderCancelBooking>,
+        location_idx: u64,
+        reason: ProviderCancelReason,
+    ) -> Result<()> {
+        crate::instructions::slot::provider_cancel_booking(ctx, location_idx, reason)
+    }
+
+    pub fn cancel_booking_slots(
+        ctx: Context<CancelBookingSlots>,
+        campaign_idx: u64,
+        location_idx: u64,
+        slot_indexes: Vec<u32>,
+    ) -> Result<()> {
+        crate::instructions::slot::cancel_booking_slots(ctx, campaign_idx, location_idx, slot_indexes)
+    }
+
+    pub fn extend_booking(
+        ctx: Context<ModifyBooking>,
+        campaign_idx: u64,
+        location_idx: u64,
+        slot_indexes: Vec<u32>,
+    ) -> Result<()> {
+        crate::instructions::modify::extend_booking(ctx, campaign_idx, location_idx, slot_indexes)
+    }
+
+    pub fn reschedule_booking(
+        ctx: Context<ModifyBooking>,
+        campaign_idx: u64,
+        location_idx: u64,
+        release_indexes: Vec<u32>,
+        book_indexes: Vec<u32>,
+    ) -> Result<()> {
+        crate::instructions::modify::reschedule_booking(
+            ctx,
+            campaign_idx,
+            location_idx,
+            release_indexes,
+            book_indexes,
         )
     }
 
@@ -148,8 +438,6 @@ pub mod soulboard {
         ctx: Context<SettleLocationBooking>,
         campaign_idx: u64,
         location_idx: u64,
-        range_start_ts: i64,
-        range_end_ts: i64,
         campaign_authority: Pubkey,
         provider_authority: Pubkey,
     ) -> Result<()> {
@@ -157,24 +445,100 @@ pub mod soulboard {
             ctx,
             campaign_idx,
             location_idx,
-            range_start_ts,
-            range_end_ts,
             campaign_authority,
             provider_authority,
         )
     }
 
+    pub fn set_location_approval(
+        ctx: Context<SetLocationApproval>,
+        location_idx: u64,
+        requires_approval: bool,
+        approval_window_secs: u32,
+    ) -> Result<()> {
+        crate::instructions::location::set_location_approval(
+            ctx,
+            location_idx,
+            requires_approval,
+            approval_window_secs,
+        )
+    }
+
+    pub fn set_location_cancellation(
+        ctx: Context<SetLocationCancellation>,
+        location_idx: u64,
+        cancellation: CancellationPolicy,
+    ) -> Result<()> {
+        crate::instructions::location::set_location_cancellation(ctx, location_idx, cancellation)
+    }
+
+    pub fn set_location_modifications(
+        ctx: Context<SetLocationModifications>,
+        location_idx: u64,
+        allow_modifications: bool,
+    ) -> Result<()> {
+        crate::instructions::location::set_location_modifications(
+            ctx,
+            location_idx,
+            allow_modifications,
+        )
+    }
+
+    pub fn set_location_referral(
+        ctx: Context<SetLocationReferral>,
+        location_idx: u64,
+        referral: Option<Referral>,
+    ) -> Result<()> {
+        crate::instructions::location::set_location_referral(ctx, location_idx, referral)
+    }
+
+    pub fn set_location_policy(
+        ctx: Context<SetLocationPolicy>,
+        location_idx: u64,
+        allowed_advertisers: Vec<Pubkey>,
+        denied_advertisers: Vec<Pubkey>,
+        forbidden_categories: u16,
+    ) -> Result<()> {
+        crate::instructions::location::set_location_policy(
+            ctx,
+            location_idx,
+            allowed_advertisers,
+            denied_advertisers,
+            forbidden_categories,
+        )
+    }
+
+    pub fn remove_location_policy(
+        ctx: Context<RemoveLocationPolicy>,
+        location_idx: u64,
+    ) -> Result<()> {
+        crate::instructions::location::remove_location_policy(ctx, location_idx)
+    }
+
+    pub fn transfer_location(ctx: Context<TransferLocation>, location_idx: u64) -> Result<()> {
+        crate::instructions::location::transfer_location(ctx, location_idx)
+    }
+
+    pub fn sync_location_authority(
+        ctx: Context<SyncLocationAuthority>,
+        location_idx: u64,
+    ) -> Result<()> {
+        crate::instructions::location::sync_location_authority(ctx, location_idx)
+    }
+
     pub fn update_location_details(
         ctx: Context<UpdateLocationDetails>,
         location_idx: u64,
         location_name: Option<String>,
         location_description: Option<String>,
+        profile: Option<LocationProfile>,
     ) -> Result<()> {
         crate::instructions::location::update_location_details(
             ctx,
             location_idx,
             location_name,
             location_description,
+            profile,
         )
     }
 
@@ -186,6 +550,18 @@ pub mod soulboard {
         crate::instructions::location::update_location_price(ctx, location_idx, price)
     }
 
+    pub fn set_location_operating_hours(
+        ctx: Context<SetLocationOperatingHours>,
+        location_idx: u64,
+        operating_hours: Option<OperatingHours>,
+    ) -> Result<()> {
+        crate::instructions::location::set_location_operating_hours(
+            ctx,
+            location_idx,
+            operating_hours,
+        )
+    }
+
     pub fn set_location_status(
         ctx: Context<SetLocationStatus>,
         location_idx: u64,
@@ -198,8 +574,9 @@ pub mod soulboard {
         ctx: Context<AddCampaignLocation>,
         campaign_idx: u64,
         location_idx: u64,
+        device_idx: u64,
     ) -> Result<()> {
-        crate::instructions::booking::add_campaign_location(ctx, campaign_idx, location_idx)
+        crate::instructions::booking::add_campaign_location(ctx, campaign_idx, location_idx, device_idx)
     }
 
     pub fn remove_campaign_location(
@@ -214,17 +591,39 @@ pub mod soulboard {
         ctx: Context<SettleCampaignLocation>,
         campaign_idx: u64,
         location_idx: u64,
-        campaign_authority: Pubkey,
-        provider_authority: Pubkey,
         settlement_amount: u64,
+        device_idx: u64,
     ) -> Result<()> {
         crate::instructions::booking::settle_campaign_location(
             ctx,
             campaign_idx,
             location_idx,
-            campaign_authority,
-            provider_authority,
             settlement_amount,
+            device_idx,
         )
     }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        crate::instructions::migrate::migrate_config(ctx)
+    }
+
+    pub fn migrate_advertiser(ctx: Context<MigrateAdvertiser>) -> Result<()> {
+        crate::instructions::migrate::migrate_advertiser(ctx)
+    }
+
+    pub fn migrate_provider(ctx: Context<MigrateProvider>) -> Result<()> {
+        crate::instructions::migrate::migrate_provider(ctx)
+    }
+
+    pub fn create_provider_earnings(ctx: Context<CreateProviderEarnings>) -> Result<()> {
+        crate::instructions::migrate::create_provider_earnings(ctx)
+    }
+
+    pub fn migrate_campaign(ctx: Context<MigrateCampaign>) -> Result<()> {
+        crate::instructions::migrate::migrate_campaign(ctx)
+    }
+
+    pub fn migrate_location(ctx: Context<MigrateLocation>) -> Result<()> {
+        crate::instructions::migrate::migrate_location(ctx)
+    }
 }
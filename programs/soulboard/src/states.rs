@@ -1,15 +1,24 @@
 use anchor_lang::prelude::*;
 
-use crate::constant::ANCHOR_DISCRIMINATOR_SIZE;
+use crate::constant::{
+    ANCHOR_DISCRIMINATOR_SIZE, SECONDS_PER_DAY, SECONDS_PER_WEEK, TREASURY_PERIOD_SECS,
+};
+use crate::errors::SoulboardError;
 
 #[account]
 #[derive(InitSpace)]
 pub struct Advertiser {
     pub authority: Pubkey,
 
+    /// Original authority; PDA seeds stay bound to it across ownership transfers.
+    pub creator: Pubkey,
+
     pub last_campaign_id: u64,
 
     pub campaign_count: u64,
+
+    /// Agency the advertiser has opted in to be managed by.
+    pub agency: Option<Pubkey>,
 }
 
 #[account]
@@ -18,6 +27,117 @@ pub struct SoulboardConfig {
     pub authority: Pubkey,
     pub treasury: Pubkey,
     pub fee_bps: u16,
+    pub max_referral_bps: u16,
+    pub referral_fee_mode: ReferralFeeMode,
+    /// Anyone may settle a booking this long after `range_end_ts` without the oracle.
+    pub settlement_grace_secs: u32,
+    pub fallback_settlement: FallbackSettlement,
+    /// Share of a booking's price a provider pays the campaign when cancelling it.
+    pub provider_cancel_compensation_bps: u16,
+}
+
+/// Program-owned fee vault. `periods` is a ring of the most recent fee periods.
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    pub total_collected: u64,
+    pub total_withdrawn: u64,
+    pub periods: [TreasuryPeriod; 12],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Debug)]
+pub struct TreasuryPeriod {
+    pub period: u64,
+    pub collected: u64,
+}
+
+impl Treasury {
+    pub fn period_index(now: i64) -> u64 {
+        now.div_euclid(TREASURY_PERIOD_SECS).max(0) as u64
+    }
+
+    /// Adds a collected fee to the lifetime total and to the period containing `now`.
+    pub fn record_fee(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.total_collected = self
+            .total_collected
+            .checked_add(amount)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+
+        let period = Self::period_index(now);
+        let slot = (period % self.periods.len() as u64) as usize;
+        let entry = &mut self.periods[slot];
+        if entry.period != period {
+            *entry = TreasuryPeriod { period, collected: 0 };
+        }
+        entry.collected = entry
+            .collected
+            .checked_add(amount)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Share of a treasury withdrawal paid to one beneficiary.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub struct TreasurySplit {
+    pub beneficiary: Pubkey,
+    pub bps: u16,
+}
+
+/// Admin-negotiated platform fee for one provider or location, optionally time-limited.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeOverride {
+    pub target: Pubkey,
+    pub scope: FeeOverrideScope,
+    pub fee_bps: u16,
+    pub expires_at: Option<i64>,
+}
+
+impl FeeOverride {
+    pub fn is_active(&self, now: i64) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now < expires_at,
+            None => true,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub enum FeeOverrideScope {
+    Provider,
+    Location,
+}
+
+/// Whether referral payouts are carved out of the platform fee or charged in addition to it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub enum ReferralFeeMode {
+    FromPlatformFee,
+    OnTopOfFee,
+}
+
+/// How permissionless settlement prices impression-based bookings.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub enum FallbackSettlement {
+    /// Pay for the impressions the booked oracle last reported on-chain.
+    UseMetrics,
+    /// Refund the whole escrow to the campaign.
+    Refund,
+}
+
+/// Affiliate paid a share of each settlement; `bps` is capped by `max_referral_bps`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub struct Referral {
+    pub referrer: Pubkey,
+    pub bps: u16,
+}
+
+/// Referrals copied onto a booking when it is made; settlement pays these rather than
+/// whatever the campaign and location carry by then.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Debug)]
+pub struct BookingReferrals {
+    pub campaign: Option<Referral>,
+    pub location: Option<Referral>,
 }
 
 #[account]
@@ -25,9 +145,161 @@ pub struct SoulboardConfig {
 pub struct Provider {
     pub authority: Pubkey,
 
+    /// Original authority; PDA seeds stay bound to it across ownership transfers.
+    pub creator: Pubkey,
+
     pub last_location_id: u64,
 
     pub location_count: u64,
+
+    /// Bookings the provider has cancelled; a reliability signal for advertisers.
+    pub cancellation_count: u32,
+}
+
+/// Program-owned vault holding a provider's settled earnings until they are withdrawn.
+#[account]
+#[derive(InitSpace)]
+pub struct ProviderEarnings {
+    pub provider: Pubkey,
+    pub pending: u64,
+    pub withdrawn: u64,
+    pub lifetime: u64,
+}
+
+impl ProviderEarnings {
+    pub fn credit(&mut self, amount: u64) -> Result<()> {
+        self.pending = self
+            .pending
+            .checked_add(amount)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        self.lifetime = self
+            .lifetime
+            .checked_add(amount)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        require!(amount <= self.pending, SoulboardError::InsufficientEarnings);
+        self.pending -= amount;
+        self.withdrawn = self
+            .withdrawn
+            .checked_add(amount)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Takes pending earnings that leave the vault without being withdrawn, such as
+    /// cancellation compensation.
+    pub fn forfeit(&mut self, amount: u64) -> Result<()> {
+        require!(amount <= self.pending, SoulboardError::InsufficientEarnings);
+        self.pending -= amount;
+        Ok(())
+    }
+}
+
+/// Buyer operating campaigns for linked advertisers in exchange for a commission.
+#[account]
+#[derive(InitSpace)]
+pub struct Agency {
+    pub authority: Pubkey,
+    pub creator: Pubkey,
+    pub commission_bps: u16,
+    pub advertiser_count: u64,
+}
+
+/// Scoped operator acting on behalf of an advertiser or provider authority.
+#[account]
+#[derive(InitSpace)]
+pub struct Delegate {
+    /// `Advertiser` or `Provider` account the grant is scoped to.
+    pub principal: Pubkey,
+    /// Authority that made the grant; the grant lapses once the principal changes hands.
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub permissions: u16,
+    pub spend_limit: Option<u64>,
+    pub spent: u64,
+}
+
+/// Advertiser-set spend limits checked when a range is booked. `None` disables a limit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub struct CampaignPacing {
+    pub period: PacingPeriod,
+    pub max_per_period: Option<u64>,
+    pub max_per_booking: Option<u64>,
+    pub max_per_location: Option<u64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub enum PacingPeriod {
+    Daily,
+    Weekly,
+}
+
+impl PacingPeriod {
+    pub fn seconds(&self) -> i64 {
+        match self {
+            PacingPeriod::Daily => SECONDS_PER_DAY,
+            PacingPeriod::Weekly => SECONDS_PER_WEEK,
+        }
+    }
+}
+
+/// Booking constraints on the locations a campaign may use. Empty or `None` rules match
+/// everything; `allowed_categories` is a bitmask of `VenueCategory::mask` values.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
+pub struct CampaignTargeting {
+    pub allowed_categories: u16,
+    pub geo: Option<GeoBounds>,
+    pub max_price_per_slot: Option<u64>,
+    #[max_len(8)]
+    pub allowed_providers: Vec<Pubkey>,
+    #[max_len(8)]
+    pub denied_providers: Vec<Pubkey>,
+}
+
+/// Inclusive latitude/longitude box, degrees scaled by 1e7.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub struct GeoBounds {
+    pub min_latitude_e7: i32,
+    pub max_latitude_e7: i32,
+    pub min_longitude_e7: i32,
+    pub max_longitude_e7: i32,
+}
+
+impl CampaignTargeting {
+    /// Checks venue category, region and provider; slot prices go through `allows_price`.
+    pub fn matches(&self, location: &Location) -> bool {
+        let profile = &location.profile;
+        let category_ok = self.allowed_categories == 0
+            || self.allowed_categories & profile.venue_category.mask() != 0;
+        let geo_ok = match self.geo {
+            Some(geo) => {
+                (geo.min_latitude_e7..=geo.max_latitude_e7).contains(&profile.latitude_e7)
+                    && (geo.min_longitude_e7..=geo.max_longitude_e7)
+                        .contains(&profile.longitude_e7)
+            }
+            None => true,
+        };
+        let provider_ok = (self.allowed_providers.is_empty()
+            || self.allowed_providers.contains(&location.provider))
+            && !self.denied_providers.contains(&location.provider);
+        category_ok && geo_ok && provider_ok
+    }
+
+    pub fn allows_price(&self, price: u64) -> bool {
+        !matches!(self.max_price_per_slot, Some(max) if price > max)
+    }
+}
+
+/// Running total booked by a campaign at one location, for the per-location pacing cap.
+#[account]
+#[derive(InitSpace)]
+pub struct CampaignLocationSpend {
+    pub campaign: Pubkey,
+    pub location: Pubkey,
+    pub spent: u64,
 }
 
 #[account]
@@ -35,6 +307,12 @@ pub struct Provider {
 pub struct Campaign {
     pub authority: Pubkey,
 
+    /// Creator of the owning advertiser account, used for PDA seeds.
+    pub creator: Pubkey,
+
+    /// Owning advertiser account; campaign delegates are scoped to it.
+    pub advertiser: Pubkey,
+
     pub campaign_idx: u64,
 
     #[max_len(64)]
@@ -51,7 +329,72 @@ pub struct Campaign {
     pub available_budget: u64,
 
     pub reserved_budget: u64,
-     
+
+    /// Managing agency and its commission, fixed when the campaign is created.
+    pub agency: Option<Pubkey>,
+
+    pub agency_commission_bps: u16,
+
+    pub referral: Option<Referral>,
+
+    /// Optional booking window; booked ranges must fall inside it.
+    pub starts_at: Option<i64>,
+
+    pub ends_at: Option<i64>,
+
+    pub pacing: Option<CampaignPacing>,
+
+    /// Rolling pacing window: restarts once `pacing.period` has elapsed since it opened.
+    pub pacing_window_start: i64,
+
+    pub pacing_window_spent: u64,
+
+    pub targeting: Option<CampaignTargeting>,
+
+    /// Bitmask of `ContentCategory::mask` values the campaign's creatives contain.
+    pub content_categories: u16,
+
+    /// Id assigned to the next `BookingOrder` opened for this campaign.
+    pub next_order_id: u64,
+}
+
+impl Campaign {
+    /// Applies the per-booking and per-period caps to a new booking and records its spend.
+    pub fn charge_pacing(&mut self, amount: u64, now: i64) -> Result<()> {
+        let Some(pacing) = self.pacing else {
+            return Ok(());
+        };
+        if let Some(max_per_booking) = pacing.max_per_booking {
+            require!(amount <= max_per_booking, SoulboardError::PacingLimitExceeded);
+        }
+
+        let window_end = self
+            .pacing_window_start
+            .checked_add(pacing.period.seconds())
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        if now >= window_end {
+            self.pacing_window_start = now;
+            self.pacing_window_spent = 0;
+        }
+        let spent = self
+            .pacing_window_spent
+            .checked_add(amount)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        if let Some(max_per_period) = pacing.max_per_period {
+            require!(spent <= max_per_period, SoulboardError::PacingLimitExceeded);
+        }
+        self.pacing_window_spent = spent;
+        Ok(())
+    }
+
+    pub fn window_contains(&self, start_ts: i64, end_ts: i64) -> bool {
+        !matches!(self.starts_at, Some(starts_at) if start_ts < starts_at)
+            && !matches!(self.ends_at, Some(ends_at) if end_ts > ends_at)
+    }
+
+    pub fn has_expired(&self, now: i64) -> bool {
+        matches!(self.ends_at, Some(ends_at) if now >= ends_at)
+    }
 }
 
 #[account]
@@ -59,6 +402,12 @@ pub struct Campaign {
 pub struct Location {
     pub authority: Pubkey, // Ad Provider
 
+    /// Creator of the registering provider account, used for PDA seeds.
+    pub creator: Pubkey,
+
+    /// Provider account currently owning the location.
+    pub provider: Pubkey,
+
     pub location_idx: u64,
 
     pub price: u64,
@@ -72,6 +421,154 @@ pub struct Location {
     pub location_description: String,
 
     pub location_status: LocationStatus,
+
+    pub profile: LocationProfile,
+
+    pub operating_hours: Option<OperatingHours>,
+
+    pub referral: Option<Referral>,
+
+    /// Set while a `LocationPolicy` exists; bookings must then supply it.
+    pub has_policy: bool,
+
+    /// New bookings start `Pending` and must be accepted within `approval_window_secs`.
+    pub requires_approval: bool,
+
+    pub approval_window_secs: u32,
+
+    pub cancellation: CancellationPolicy,
+
+    /// Lets advertisers extend or reschedule existing bookings.
+    pub allow_modifications: bool,
+}
+
+/// How advertisers may drop individual slots from a booking at this location.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug, Default)]
+pub struct CancellationPolicy {
+    pub allow_partial: bool,
+    /// Slots starting within this many seconds can no longer be dropped.
+    pub cutoff_secs: u32,
+}
+
+/// Provider rules on who may book a location. Advertisers are identified by their
+/// advertiser account address; an empty allow list admits everyone not denied.
+#[account]
+#[derive(InitSpace)]
+pub struct LocationPolicy {
+    pub location: Pubkey,
+    #[max_len(16)]
+    pub allowed_advertisers: Vec<Pubkey>,
+    #[max_len(16)]
+    pub denied_advertisers: Vec<Pubkey>,
+    /// Bitmask of `ContentCategory::mask` values the venue refuses.
+    pub forbidden_categories: u16,
+}
+
+impl LocationPolicy {
+    pub fn admits(&self, advertiser: &Pubkey, content_categories: u16) -> bool {
+        (self.allowed_advertisers.is_empty() || self.allowed_advertisers.contains(advertiser))
+            && !self.denied_advertisers.contains(advertiser)
+            && self.forbidden_categories & content_categories == 0
+    }
+}
+
+/// Sensitive ad content a campaign declares and a location policy can refuse.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub enum ContentCategory {
+    Gambling,
+    Alcohol,
+    Tobacco,
+    Cannabis,
+    Adult,
+    Political,
+    Weapons,
+    Pharmaceutical,
+}
+
+impl ContentCategory {
+    pub const ALL_MASK: u16 = (1 << 8) - 1;
+
+    pub fn mask(&self) -> u16 {
+        1 << (*self as u16)
+    }
+}
+
+/// Structured venue data for map search. Coordinates are degrees scaled by 1e7.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
+pub struct LocationProfile {
+    pub latitude_e7: i32,
+    pub longitude_e7: i32,
+    pub venue_category: VenueCategory,
+    pub daily_audience_estimate: u32,
+}
+
+/// Weekly opening hours in venue-local time, Monday first.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
+pub struct OperatingHours {
+    pub utc_offset_minutes: i16,
+    pub days: [DayHours; 7],
+}
+
+/// Minutes since local midnight; a day with `open_minute == close_minute` is closed, and
+/// one with `close_minute < open_minute` stays open past midnight into the next day.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub struct DayHours {
+    pub open_minute: u16,
+    pub close_minute: u16,
+}
+
+impl OperatingHours {
+    /// Returns true when `[start_ts, end_ts)` falls inside a single opening window.
+    pub fn contains(&self, start_ts: i64, end_ts: i64) -> bool {
+        let offset = self.utc_offset_minutes as i64 * 60;
+        let local_start = start_ts + offset;
+        let local_end = end_ts + offset;
+
+        // A window opened the previous evening may still cover the slot.
+        let day = local_start.div_euclid(SECONDS_PER_DAY);
+        [day - 1, day].into_iter().any(|day| {
+            self.window(day)
+                .is_some_and(|(open, close)| local_start >= open && local_end <= close)
+        })
+    }
+
+    /// Opening window of local `day` in local seconds, or `None` when closed that day.
+    fn window(&self, day: i64) -> Option<(i64, i64)> {
+        // 1970-01-01 was a Thursday, index 3 when Monday is 0.
+        let hours = self.days[(day + 3).rem_euclid(7) as usize];
+        if hours.open_minute == hours.close_minute {
+            return None;
+        }
+
+        let day_start = day * SECONDS_PER_DAY;
+        let open = day_start + hours.open_minute as i64 * 60;
+        let mut close = day_start + hours.close_minute as i64 * 60;
+        if hours.close_minute < hours.open_minute {
+            close += SECONDS_PER_DAY;
+        }
+        Some((open, close))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub enum VenueCategory {
+    Retail,
+    Transit,
+    Restaurant,
+    Entertainment,
+    Office,
+    Education,
+    Healthcare,
+    Outdoor,
+    Other,
+}
+
+impl VenueCategory {
+    pub const ALL_MASK: u16 = (1 << 9) - 1;
+
+    pub fn mask(&self) -> u16 {
+        1 << (*self as u16)
+    }
 }
 
 #[account]
@@ -94,6 +591,18 @@ pub struct LocationSlot {
 
 impl LocationSlot {
     pub const SIZE: usize = 8 + 8 + 8 + SlotStatus::INIT_SPACE + 32;
+
+    /// Free slots, lapsed holds and the campaign's own hold can be booked.
+    pub fn is_bookable_by(&self, campaign: &Pubkey, now: i64) -> bool {
+        match &self.status {
+            SlotStatus::Available => true,
+            SlotStatus::Held {
+                campaign: holder,
+                expires_at,
+            } => holder == campaign || *expires_at <= now,
+            _ => false,
+        }
+    }
 }
 
 impl LocationSchedule {
@@ -116,11 +625,56 @@ pub struct CampaignLocation {
     pub advertiser: Pubkey,
     pub provider: Pubkey,
     pub oracle_authority: Pubkey,
+    /// Oracle device locked for the booking's lifetime.
+    pub device: Pubkey,
+    pub device_authority: Pubkey,
+    pub device_idx: u64,
     pub price: u64,
     pub status: CampaignLocationStatus,
     pub created_at: i64,
     pub updated_at: i64,
+    pub referrals: BookingReferrals,
+    pub charges: BookingCharges,
+}
+
+/// Groups bookings made from explicit slot lists, one child booking per location.
+#[account]
+#[derive(InitSpace)]
+pub struct BookingOrder {
+    pub campaign: Pubkey,
+    pub order_id: u64,
+    /// Child bookings not yet cancelled or settled.
+    pub booking_count: u32,
+    /// Escrow currently reserved across the live child bookings.
+    pub total_price: u64,
+    /// Gross paid out by settled child bookings.
     pub settled_amount: u64,
+    pub created_at: i64,
+}
+
+impl BookingOrder {
+    pub fn reserve(&mut self, amount: u64) -> Result<()> {
+        self.total_price = self
+            .total_price
+            .checked_add(amount)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Drops escrow a child gave back; `closed` when the child booking itself ended.
+    pub fn release(&mut self, amount: u64, closed: bool) -> Result<()> {
+        self.total_price = self
+            .total_price
+            .checked_sub(amount)
+            .ok_or(SoulboardError::ArithmeticUnderflow)?;
+        if closed {
+            self.booking_count = self
+                .booking_count
+                .checked_sub(1)
+                .ok_or(SoulboardError::ArithmeticUnderflow)?;
+        }
+        Ok(())
+    }
 }
 
 #[account]
@@ -143,15 +697,34 @@ pub struct CampaignBooking {
     pub status: BookingStatus,
     pub created_at: i64,
     pub updated_at: i64,
-    pub impressions: u64,
-    pub settled_amount: u64,
-    pub fee_amount: u64,
+    pub referrals: BookingReferrals,
+    /// Set on `Pending` bookings: the provider must accept before this time.
+    pub approval_deadline: Option<i64>,
+    /// Parent `BookingOrder` for bookings made from an explicit slot list.
+    pub order: Option<Pubkey>,
+    pub charges: BookingCharges,
+}
+
+/// Spend counters a booking has charged and not yet given back, so refunds can credit
+/// the same counters.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Debug)]
+pub struct BookingCharges {
+    /// Delegate signer whose `Delegate::spent` the booking charged, if any.
+    pub delegate: Option<Pubkey>,
+    pub delegate_amount: u64,
+    pub location_amount: u64,
+    /// Pacing window the booking was last charged in; earlier windows are not credited.
+    pub pacing_window_start: i64,
+    pub pacing_amount: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
 pub enum CampaignStatus {
     Active,
+    /// Ended by the owner or `expire_campaign`; kept for history until archived.
     Closed,
+    /// Temporarily refuses new bookings; `resume_campaign` returns it to `Active`.
+    Paused,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
@@ -173,14 +746,27 @@ pub enum BookingStatus {
     Active,
     Cancelled,
     Settled,
+    Pending,
+}
+
+/// Why a provider backed out of a booking.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Debug)]
+pub enum ProviderCancelReason {
+    ScreenFailure,
+    VenueClosed,
+    Maintenance,
+    Other,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
 pub enum SlotStatus {
     Available,
     Booked,
+    /// Withdrawn by the provider; range bookings and holds skip over it.
     Cancelled,
     Settled,
+    /// Reserved for `campaign` during checkout; others may book it once `expires_at` passes.
+    Held { campaign: Pubkey, expires_at: i64 },
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
@@ -192,6 +778,62 @@ pub enum LocationStatus {
     Inactive,
 }
 
+/// `SoulboardConfig` as written before the treasury PDA, referral and settlement settings
+/// existed; `treasury` was then a plain wallet.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacySoulboardConfig {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_bps: u16,
+}
+
+/// `Advertiser` as written before `creator` and `agency` existed; read by `migrate_advertiser`.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyAdvertiser {
+    pub authority: Pubkey,
+    pub last_campaign_id: u64,
+    pub campaign_count: u64,
+}
+
+/// `Provider` as written before `creator` and `cancellation_count` existed.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyProvider {
+    pub authority: Pubkey,
+    pub last_location_id: u64,
+    pub location_count: u64,
+}
+
+/// `Campaign` as written before ownership, agency, referral, window, pacing and
+/// targeting fields existed.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyCampaign {
+    pub authority: Pubkey,
+    pub campaign_idx: u64,
+    #[max_len(64)]
+    pub campaign_name: String,
+    #[max_len(256)]
+    pub campaign_description: String,
+    #[max_len(256)]
+    pub campaign_image_url: String,
+    pub status: CampaignStatus,
+    pub available_budget: u64,
+    pub reserved_budget: u64,
+}
+
+/// `Location` as written before ownership, profile, hours, referral and policy fields existed.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyLocation {
+    pub authority: Pubkey,
+    pub location_idx: u64,
+    pub price: u64,
+    pub oracle_authority: Pubkey,
+    #[max_len(64)]
+    pub location_name: String,
+    #[max_len(256)]
+    pub location_description: String,
+    pub location_status: LocationStatus,
+}
+
 #[event]
 pub struct CampaignCreated {
     pub campaign: Pubkey,
@@ -224,11 +866,136 @@ pub struct CampaignClosed {
     pub authority: Pubkey,
 }
 
+#[event]
+pub struct CampaignStatusChanged {
+    pub campaign: Pubkey,
+    pub status: CampaignStatus,
+}
+
+#[event]
+pub struct CampaignWindowUpdated {
+    pub campaign: Pubkey,
+    pub starts_at: Option<i64>,
+    pub ends_at: Option<i64>,
+}
+
+#[event]
+pub struct CampaignPacingUpdated {
+    pub campaign: Pubkey,
+    pub pacing: Option<CampaignPacing>,
+}
+
+#[event]
+pub struct CampaignTargetingUpdated {
+    pub campaign: Pubkey,
+    pub targeting: Option<CampaignTargeting>,
+}
+
+#[event]
+pub struct CampaignContentUpdated {
+    pub campaign: Pubkey,
+    pub content_categories: u16,
+}
+
+#[event]
+pub struct LocationPolicyUpdated {
+    pub location: Pubkey,
+    pub policy: Pubkey,
+    pub allowed_advertisers: Vec<Pubkey>,
+    pub denied_advertisers: Vec<Pubkey>,
+    pub forbidden_categories: u16,
+}
+
+#[event]
+pub struct LocationPolicyRemoved {
+    pub location: Pubkey,
+    pub policy: Pubkey,
+}
+
+#[event]
+pub struct CampaignExpired {
+    pub campaign: Pubkey,
+    pub released_budget: u64,
+    pub reserved_budget: u64,
+}
+
+#[event]
+pub struct CampaignArchived {
+    pub campaign: Pubkey,
+    pub authority: Pubkey,
+    pub refunded_budget: u64,
+}
+
+#[event]
+pub struct AgencyUpdated {
+    pub agency: Pubkey,
+    pub authority: Pubkey,
+    pub commission_bps: u16,
+}
+
+#[event]
+pub struct AdvertiserAgencyLinked {
+    pub advertiser: Pubkey,
+    pub agency: Pubkey,
+}
+
+#[event]
+pub struct AdvertiserAgencyUnlinked {
+    pub advertiser: Pubkey,
+    pub agency: Pubkey,
+}
+
+#[event]
+pub struct DelegateUpdated {
+    pub delegate_account: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub permissions: u16,
+    pub spend_limit: Option<u64>,
+}
+
+#[event]
+pub struct DelegateRevoked {
+    pub delegate_account: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct AdvertiserTransferred {
+    pub advertiser: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct ProviderTransferred {
+    pub provider: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct LocationTransferred {
+    pub location: Pubkey,
+    pub previous_provider: Pubkey,
+    pub new_provider: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ConfigAuthorityTransferred {
+    pub config: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
 #[event]
 pub struct LocationRegistered {
     pub location: Pubkey,
     pub authority: Pubkey,
     pub location_idx: u64,
+    pub profile: LocationProfile,
 }
 
 #[event]
@@ -244,6 +1011,67 @@ pub struct SoulboardConfigInitialized {
     pub fee_bps: u16,
 }
 
+#[event]
+pub struct SoulboardConfigUpdated {
+    pub config: Pubkey,
+    pub max_referral_bps: u16,
+    pub referral_fee_mode: ReferralFeeMode,
+}
+
+#[event]
+pub struct SettlementFallbackUpdated {
+    pub config: Pubkey,
+    pub settlement_grace_secs: u32,
+    pub fallback_settlement: FallbackSettlement,
+}
+
+#[event]
+pub struct ProviderCancelCompensationUpdated {
+    pub config: Pubkey,
+    pub compensation_bps: u16,
+}
+
+#[event]
+pub struct EarningsWithdrawn {
+    pub provider: Pubkey,
+    pub earnings: Pubkey,
+    pub amount: u64,
+    pub payout: Pubkey,
+    pub pending: u64,
+    pub withdrawn: u64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub recipient_amount: u64,
+    pub splits: Vec<TreasurySplit>,
+    pub total_withdrawn: u64,
+}
+
+#[event]
+pub struct FeeOverrideSet {
+    pub fee_override: Pubkey,
+    pub target: Pubkey,
+    pub scope: FeeOverrideScope,
+    pub fee_bps: u16,
+    pub expires_at: Option<i64>,
+}
+
+#[event]
+pub struct FeeOverrideRemoved {
+    pub fee_override: Pubkey,
+    pub target: Pubkey,
+}
+
+#[event]
+pub struct ReferralUpdated {
+    pub target: Pubkey,
+    pub referral: Option<Referral>,
+}
+
 #[event]
 pub struct LocationScheduleCreated {
     pub schedule: Pubkey,
@@ -278,7 +1106,12 @@ pub struct CampaignLocationCancelled {
 pub struct CampaignLocationSettled {
     pub campaign: Pubkey,
     pub location: Pubkey,
+    pub device: Pubkey,
     pub settled_amount: u64,
+    pub fee_amount: u64,
+    pub agency: Option<Pubkey>,
+    pub agency_commission: u64,
+    pub referral_amount: u64,
     pub refunded_amount: u64,
 }
 
@@ -289,6 +1122,117 @@ pub struct CampaignBookingCreated {
     pub location: Pubkey,
     pub slot_count: u32,
     pub total_price: u64,
+    pub status: BookingStatus,
+    pub order: Option<Pubkey>,
+}
+
+#[event]
+pub struct BookingOrderCreated {
+    pub order: Pubkey,
+    pub campaign: Pubkey,
+    pub order_id: u64,
+}
+
+#[event]
+pub struct BookingOrderClosed {
+    pub order: Pubkey,
+    pub campaign: Pubkey,
+    pub order_id: u64,
+    pub settled_amount: u64,
+}
+
+#[event]
+pub struct LocationSlotsHeld {
+    pub schedule: Pubkey,
+    pub campaign: Pubkey,
+    pub range_start_ts: i64,
+    pub range_end_ts: i64,
+    pub slot_count: u32,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct LocationSlotHoldsReleased {
+    pub schedule: Pubkey,
+    pub slot_count: u32,
+}
+
+#[event]
+pub struct CampaignBookingAccepted {
+    pub booking: Pubkey,
+    pub campaign: Pubkey,
+    pub location: Pubkey,
+}
+
+#[event]
+pub struct CampaignBookingRejected {
+    pub booking: Pubkey,
+    pub campaign: Pubkey,
+    pub location: Pubkey,
+    pub refunded_amount: u64,
+    pub expired: bool,
+}
+
+#[event]
+pub struct CampaignBookingCancelledByProvider {
+    pub booking: Pubkey,
+    pub campaign: Pubkey,
+    pub location: Pubkey,
+    pub reason: ProviderCancelReason,
+    pub refunded_amount: u64,
+    pub compensation: u64,
+    pub cancellation_count: u32,
+}
+
+#[event]
+pub struct CampaignBookingSlotsCancelled {
+    pub booking: Pubkey,
+    pub campaign: Pubkey,
+    pub location: Pubkey,
+    pub cancelled_slots: u32,
+    pub remaining_slots: u32,
+    pub refunded_amount: u64,
+}
+
+#[event]
+pub struct CampaignBookingExtended {
+    pub booking: Pubkey,
+    pub campaign: Pubkey,
+    pub location: Pubkey,
+    pub added_slots: u32,
+    pub charged_amount: u64,
+    pub range_start_ts: i64,
+    pub range_end_ts: i64,
+}
+
+#[event]
+pub struct CampaignBookingRescheduled {
+    pub booking: Pubkey,
+    pub campaign: Pubkey,
+    pub location: Pubkey,
+    pub released_slots: u32,
+    pub added_slots: u32,
+    pub refunded_amount: u64,
+    pub charged_amount: u64,
+}
+
+#[event]
+pub struct LocationModificationsUpdated {
+    pub location: Pubkey,
+    pub allow_modifications: bool,
+}
+
+#[event]
+pub struct LocationCancellationUpdated {
+    pub location: Pubkey,
+    pub cancellation: CancellationPolicy,
+}
+
+#[event]
+pub struct LocationApprovalUpdated {
+    pub location: Pubkey,
+    pub requires_approval: bool,
+    pub approval_window_secs: u32,
 }
 
 #[event]
@@ -307,5 +1251,16 @@ pub struct CampaignBookingSettled {
     pub impressions: u64,
     pub settled_amount: u64,
     pub fee_amount: u64,
+    pub agency: Option<Pubkey>,
+    pub agency_commission: u64,
+    pub referral_amount: u64,
     pub refunded_amount: u64,
+    /// Settled by the grace-period crank rather than the oracle.
+    pub permissionless: bool,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub payer: Pubkey,
 }
@@ -1,8 +1,18 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{program::invoke, system_instruction::transfer};
+use soul_board_oracle::constants::{BOOKING_AUTHORITY_KEY, DEVICE_KEY as ORACLE_DEVICE_KEY};
+use soul_board_oracle::states::{Device as OracleDevice, DeviceStatus as OracleDeviceStatus};
 
+use crate::constant::{
+    ADVERTISER_KEY, ADVERTISER_PERMISSIONS, ANCHOR_DISCRIMINATOR_SIZE, BPS_DENOMINATOR, DELEGATE_KEY, MAX_LATITUDE_E7, MAX_LONGITUDE_E7, MAX_TARGETING_PROVIDERS,
+    MAX_UTC_OFFSET_MINUTES, MINUTES_PER_DAY, PROVIDER_PERMISSIONS,
+};
 use crate::errors::SoulboardError;
-use crate::states::{Campaign, CampaignStatus};
+use crate::states::{
+    Advertiser, Agency, BookingOrder, BookingReferrals, Campaign, CampaignBooking, CampaignStatus, CampaignTargeting, Delegate, FeeOverride, Location,
+    LocationPolicy, LocationProfile, LocationSchedule, OperatingHours, Provider, ProviderEarnings, Referral,
+    ReferralFeeMode, SlotStatus, SoulboardConfig, Treasury, VenueCategory,
+};
 
 pub fn ensure_string_len(value: &str, max_len: usize) -> Result<()> {
     require!(value.len() <= max_len, SoulboardError::InvalidStringLength);
@@ -21,6 +31,221 @@ pub fn set_optional_string(
     Ok(())
 }
 
+pub fn validate_location_profile(profile: &LocationProfile) -> Result<()> {
+    require!(
+        (-MAX_LATITUDE_E7..=MAX_LATITUDE_E7).contains(&profile.latitude_e7)
+            && (-MAX_LONGITUDE_E7..=MAX_LONGITUDE_E7).contains(&profile.longitude_e7),
+        SoulboardError::InvalidCoordinates
+    );
+    Ok(())
+}
+
+pub fn validate_targeting(targeting: &CampaignTargeting) -> Result<()> {
+    require!(
+        targeting.allowed_categories & !VenueCategory::ALL_MASK == 0,
+        SoulboardError::InvalidParameters
+    );
+    if let Some(geo) = &targeting.geo {
+        require!(
+            geo.min_latitude_e7 <= geo.max_latitude_e7
+                && geo.min_longitude_e7 <= geo.max_longitude_e7
+                && (-MAX_LATITUDE_E7..=MAX_LATITUDE_E7).contains(&geo.min_latitude_e7)
+                && (-MAX_LATITUDE_E7..=MAX_LATITUDE_E7).contains(&geo.max_latitude_e7)
+                && (-MAX_LONGITUDE_E7..=MAX_LONGITUDE_E7).contains(&geo.min_longitude_e7)
+                && (-MAX_LONGITUDE_E7..=MAX_LONGITUDE_E7).contains(&geo.max_longitude_e7),
+            SoulboardError::InvalidCoordinates
+        );
+    }
+    require!(
+        targeting.allowed_providers.len() <= MAX_TARGETING_PROVIDERS
+            && targeting.denied_providers.len() <= MAX_TARGETING_PROVIDERS,
+        SoulboardError::InvalidParameters
+    );
+    Ok(())
+}
+
+/// Applies the location's booking policy. The policy account must be supplied whenever
+/// the location has one.
+pub fn require_policy_admits(
+    campaign: &Campaign,
+    location: &Location,
+    policy: Option<&LocationPolicy>,
+) -> Result<()> {
+    if !location.has_policy {
+        return Ok(());
+    }
+    let policy = policy.ok_or(SoulboardError::InvalidParameters)?;
+    let (advertiser, _) =
+        Pubkey::find_program_address(&[ADVERTISER_KEY, campaign.creator.as_ref()], &crate::ID);
+    require!(
+        policy.admits(&advertiser, campaign.content_categories),
+        SoulboardError::RejectedByLocationPolicy
+    );
+    Ok(())
+}
+
+/// Rejects locations outside the campaign's targeting, if it has any.
+pub fn require_targeted(campaign: &Campaign, location: &Location) -> Result<()> {
+    if let Some(targeting) = &campaign.targeting {
+        require!(targeting.matches(location), SoulboardError::TargetingMismatch);
+    }
+    Ok(())
+}
+
+pub fn validate_operating_hours(hours: &OperatingHours) -> Result<()> {
+    require!(
+        hours.utc_offset_minutes.abs() <= MAX_UTC_OFFSET_MINUTES,
+        SoulboardError::InvalidParameters
+    );
+    for day in hours.days.iter() {
+        require!(
+            day.open_minute <= MINUTES_PER_DAY && day.close_minute <= MINUTES_PER_DAY,
+            SoulboardError::InvalidParameters
+        );
+    }
+    Ok(())
+}
+
+/// Resolves the `Advertiser` or `Provider` a delegate is scoped to, requires `owner` to be
+/// its current authority and returns the permissions that may be granted from it.
+pub fn delegate_scope(principal: &AccountInfo, owner: &Pubkey) -> Result<u16> {
+    require_keys_eq!(*principal.owner, crate::ID, SoulboardError::InvalidParameters);
+    let data = principal.try_borrow_data()?;
+    let (authority, permissions) = match Advertiser::try_deserialize(&mut &data[..]) {
+        Ok(advertiser) => (advertiser.authority, ADVERTISER_PERMISSIONS),
+        Err(_) => {
+            let provider = Provider::try_deserialize(&mut &data[..])
+                .map_err(|_| SoulboardError::InvalidParameters)?;
+            (provider.authority, PROVIDER_PERMISSIONS)
+        }
+    };
+    require_keys_eq!(authority, *owner, SoulboardError::Unauthorized);
+    Ok(permissions)
+}
+
+/// Accepts the owner itself, or a delegate of the owner holding `permission`.
+pub fn require_owner_or_delegate(
+    owner: &Pubkey,
+    signer: &Pubkey,
+    delegate: Option<&Delegate>,
+    permission: u16,
+) -> Result<()> {
+    if signer == owner {
+        return Ok(());
+    }
+    let delegate = delegate.ok_or(SoulboardError::Unauthorized)?;
+    require_keys_eq!(delegate.owner, *owner, SoulboardError::Unauthorized);
+    require_keys_eq!(delegate.delegate, *signer, SoulboardError::Unauthorized);
+    require!(
+        delegate.permissions & permission == permission,
+        SoulboardError::Unauthorized
+    );
+    Ok(())
+}
+
+/// Records spend made by a delegate against its optional limit. Owners are never limited.
+pub fn charge_delegate_spend(
+    owner: &Pubkey,
+    signer: &Pubkey,
+    delegate: Option<&mut Delegate>,
+    amount: u64,
+) -> Result<()> {
+    if signer == owner {
+        return Ok(());
+    }
+    let delegate = delegate.ok_or(SoulboardError::Unauthorized)?;
+    let spent = delegate
+        .spent
+        .checked_add(amount)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+    if let Some(limit) = delegate.spend_limit {
+        require!(spent <= limit, SoulboardError::DelegateSpendLimitExceeded);
+    }
+    delegate.spent = spent;
+    Ok(())
+}
+
+/// Returns the parent order a child booking must keep in sync, checking the caller passed
+/// the right one. Bookings made outside an order return `None`.
+pub fn booking_order<'a, 'info>(
+    order: Option<&'a mut Account<'info, BookingOrder>>,
+    booking: &CampaignBooking,
+) -> Result<Option<&'a mut Account<'info, BookingOrder>>> {
+    let Some(expected) = booking.order else {
+        return Ok(None);
+    };
+    let order = order.ok_or(SoulboardError::InvalidParameters)?;
+    require_keys_eq!(order.key(), expected, SoulboardError::InvalidParameters);
+    Ok(Some(order))
+}
+
+/// Gives `amount` back to a delegate's spend counter. A revoked delegate has been closed
+/// and has nothing left to credit.
+pub fn credit_delegate_spend(
+    delegate_info: &AccountInfo,
+    owner: &Pubkey,
+    operator: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if delegate_info.owner != &crate::ID {
+        return Ok(());
+    }
+    let mut data = delegate_info.try_borrow_mut_data()?;
+    let mut delegate = Delegate::try_deserialize(&mut &data[..])?;
+    require_keys_eq!(delegate.owner, *owner, SoulboardError::InvalidParameters);
+    require_keys_eq!(delegate.delegate, *operator, SoulboardError::InvalidParameters);
+    delegate.spent = delegate.spent.saturating_sub(amount);
+    delegate.try_serialize(&mut &mut data[..])
+}
+
+/// Returns a booking's escrow to its campaign's available budget and releases its slots
+/// with `freed_status`.
+pub fn refund_booking_escrow(
+    campaign: &mut Account<Campaign>,
+    schedule: &mut LocationSchedule,
+    booking: &Account<CampaignBooking>,
+    freed_status: SlotStatus,
+) -> Result<u64> {
+    let total_price = booking.total_price;
+    ensure_rent_exempt_after_withdraw(&booking.to_account_info(), total_price)?;
+
+    move_lamports(
+        &booking.to_account_info(),
+        &campaign.to_account_info(),
+        total_price,
+    )?;
+
+    campaign.reserved_budget = campaign
+        .reserved_budget
+        .checked_sub(total_price)
+        .ok_or(SoulboardError::ArithmeticUnderflow)?;
+    campaign.available_budget = campaign
+        .available_budget
+        .checked_add(total_price)
+        .ok_or(SoulboardError::ArithmeticOverflow)?;
+
+    for slot in schedule.slots.iter_mut() {
+        if slot.booking == booking.key() && slot.status == SlotStatus::Booked {
+            slot.status = freed_status.clone();
+            slot.booking = Pubkey::default();
+        }
+    }
+
+    Ok(total_price)
+}
+
+/// Earliest start and latest end among the schedule slots still held by `booking`.
+pub fn booked_range(schedule: &LocationSchedule, booking: &Pubkey) -> Option<(i64, i64)> {
+    schedule
+        .slots
+        .iter()
+        .filter(|slot| slot.booking == *booking && slot.status == SlotStatus::Booked)
+        .fold(None, |range, slot| match range {
+            None => Some((slot.start_ts, slot.end_ts)),
+            Some((start, end)) => Some((start.min(slot.start_ts), end.max(slot.end_ts))),
+        })
+}
+
 pub fn require_campaign_active(campaign: &Campaign) -> Result<()> {
     require!(
         campaign.status == CampaignStatus::Active,
@@ -29,6 +254,156 @@ pub fn require_campaign_active(campaign: &Campaign) -> Result<()> {
     Ok(())
 }
 
+/// Accepts active and paused campaigns; only ended campaigns are rejected.
+pub fn require_campaign_open(campaign: &Campaign) -> Result<()> {
+    require!(
+        campaign.status != CampaignStatus::Closed,
+        SoulboardError::CampaignNotActive
+    );
+    Ok(())
+}
+
+/// Returns `amount * bps / 10_000`, rounding down.
+pub fn bps_share(amount: u64, bps: u16) -> Result<u64> {
+    let share = amount
+        .checked_mul(bps as u64)
+        .ok_or(SoulboardError::ArithmeticOverflow)?
+        .checked_div(BPS_DENOMINATOR)
+        .ok_or(SoulboardError::ArithmeticUnderflow)?;
+    Ok(share)
+}
+
+fn load_fee_override(info: &AccountInfo) -> Result<Option<FeeOverride>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    let mut data_slice: &[u8] = &data;
+    Ok(Some(FeeOverride::try_deserialize(&mut data_slice)?))
+}
+
+/// Resolves the platform fee from the most specific active override: location, then
+/// provider, then the global config rate.
+pub fn resolve_fee_bps(
+    config: &SoulboardConfig,
+    provider_override: &AccountInfo,
+    location_override: &AccountInfo,
+    now: i64,
+) -> Result<u16> {
+    let overrides = [
+        load_fee_override(location_override)?,
+        load_fee_override(provider_override)?,
+    ];
+    Ok(overrides
+        .iter()
+        .flatten()
+        .find(|fee_override| fee_override.is_active(now))
+        .map_or(config.fee_bps, |fee_override| fee_override.fee_bps))
+}
+
+pub fn validate_referral(referral: &Referral, config: &SoulboardConfig) -> Result<()> {
+    require!(
+        referral.referrer != Pubkey::default() && referral.bps > 0,
+        SoulboardError::InvalidParameters
+    );
+    require!(
+        referral.bps <= config.max_referral_bps,
+        SoulboardError::InvalidParameters
+    );
+    Ok(())
+}
+
+/// Rejects a referrer who is the principal's `owner` or holds a delegate over it.
+/// `referrer_delegate` is the delegate PDA the referrer would hold and must not exist.
+pub fn require_independent_referrer(
+    referral: &Referral,
+    owner: &Pubkey,
+    principal: &Pubkey,
+    referrer_delegate: Option<&AccountInfo>,
+) -> Result<()> {
+    require_keys_neq!(referral.referrer, *owner, SoulboardError::InvalidReferrer);
+    let referrer_delegate = referrer_delegate.ok_or(SoulboardError::InvalidParameters)?;
+    let (expected, _) = Pubkey::find_program_address(
+        &[DELEGATE_KEY, principal.as_ref(), referral.referrer.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        referrer_delegate.key(),
+        expected,
+        SoulboardError::InvalidParameters
+    );
+    require!(
+        referrer_delegate.data_is_empty(),
+        SoulboardError::InvalidReferrer
+    );
+    Ok(())
+}
+
+/// Copies the campaign and location referrals for a new booking. Neither side may
+/// refer the other.
+pub fn snapshot_referrals(campaign: &Campaign, location: &Location) -> Result<BookingReferrals> {
+    if let Some(referral) = &campaign.referral {
+        require_keys_neq!(referral.referrer, location.authority, SoulboardError::InvalidReferrer);
+    }
+    if let Some(referral) = &location.referral {
+        require_keys_neq!(referral.referrer, campaign.authority, SoulboardError::InvalidReferrer);
+    }
+    Ok(BookingReferrals {
+        campaign: campaign.referral,
+        location: location.referral,
+    })
+}
+
+/// Referral payout on `gross`. The bps is re-capped so a lowered config cap applies to
+/// referrals set earlier.
+fn referral_share(gross: u64, referral: &Referral, config: &SoulboardConfig) -> Result<u64> {
+    bps_share(gross, referral.bps.min(config.max_referral_bps))
+}
+
+/// Deducts a referral payout on `gross` from `source`, never taking more than it holds.
+pub fn take_referral_share(
+    gross: u64,
+    referral: &Referral,
+    config: &SoulboardConfig,
+    source: &mut u64,
+) -> Result<u64> {
+    let share = referral_share(gross, referral, config)?.min(*source);
+    *source -= share;
+    Ok(share)
+}
+
+fn referrer_account<'a, 'info>(
+    referral: &Referral,
+    referrer: Option<&'a AccountInfo<'info>>,
+) -> Result<&'a AccountInfo<'info>> {
+    let referrer = referrer.ok_or(SoulboardError::InvalidParameters)?;
+    require_keys_eq!(
+        referrer.key(),
+        referral.referrer,
+        SoulboardError::InvalidAuthority
+    );
+    Ok(referrer)
+}
+
+/// Moves up to `amount` out of the campaign's available budget to `recipient`. A budget
+/// that cannot cover it in full pays what is left. Returns what was paid.
+fn charge_campaign_budget(
+    campaign: &mut Account<Campaign>,
+    recipient: &AccountInfo,
+    amount: u64,
+) -> Result<u64> {
+    let amount = amount.min(campaign.available_budget);
+    if amount > 0 {
+        ensure_rent_exempt_after_withdraw(&campaign.to_account_info(), amount)?;
+        move_lamports(&campaign.to_account_info(), recipient, amount)?;
+        campaign.available_budget = campaign
+            .available_budget
+            .checked_sub(amount)
+            .ok_or(SoulboardError::ArithmeticUnderflow)?;
+    }
+    Ok(amount)
+}
+
 pub fn ensure_rent_exempt_after_withdraw(account_info: &AccountInfo, amount: u64) -> Result<()> {
     let rent = Rent::get()?;
     let min_balance = rent.minimum_balance(account_info.data_len());
@@ -52,6 +427,125 @@ pub fn move_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Resul
     Ok(())
 }
 
+/// Recipients of a settled amount, shared by the booking and legacy campaign-location
+/// settlement paths so both apply the same fee, agency and referral splits.
+pub struct SettlementPayees<'a, 'info> {
+    pub config: &'a SoulboardConfig,
+    pub provider_fee_override: &'a AccountInfo<'info>,
+    pub location_fee_override: &'a AccountInfo<'info>,
+    pub earnings: &'a mut Account<'info, ProviderEarnings>,
+    pub treasury: &'a mut Account<'info, Treasury>,
+    pub agency: Option<&'a Account<'info, Agency>>,
+    pub agency_authority: Option<&'a AccountInfo<'info>>,
+    pub campaign_referrer: Option<&'a AccountInfo<'info>>,
+    pub location_referrer: Option<&'a AccountInfo<'info>>,
+}
+
+pub struct SettlementSplit {
+    pub fee_amount: u64,
+    pub agency_commission: u64,
+    pub referral_amount: u64,
+}
+
+/// Splits `gross` between the platform fee, any referrers and the provider's earnings,
+/// and moves each share out of `escrow`. Charges the advertiser owes on top of `gross`,
+/// the agency commission and a campaign referral in `OnTopOfFee` mode, come out of the
+/// campaign's available budget, so the caller credits any refund to it first.
+pub fn pay_out_settlement(
+    escrow: &AccountInfo,
+    campaign: &mut Account<Campaign>,
+    referrals: &BookingReferrals,
+    payees: SettlementPayees,
+    gross: u64,
+    now: i64,
+) -> Result<SettlementSplit> {
+    let config = payees.config;
+    let fee_bps = resolve_fee_bps(
+        config,
+        payees.provider_fee_override,
+        payees.location_fee_override,
+        now,
+    )?;
+    let mut fee_amount = bps_share(gross, fee_bps)?;
+    let mut net_amount = gross
+        .checked_sub(fee_amount)
+        .ok_or(SoulboardError::ArithmeticUnderflow)?;
+    let on_top = config.referral_fee_mode == ReferralFeeMode::OnTopOfFee;
+
+    // Each referral is charged to the party that set it: the location's to the
+    // provider's net, the campaign's to the advertiser's budget.
+    let mut escrow_referrals: Vec<(&AccountInfo, u64)> = Vec::with_capacity(2);
+    if let Some(referral) = &referrals.location {
+        let referrer = referrer_account(referral, payees.location_referrer)?;
+        let source = if on_top { &mut net_amount } else { &mut fee_amount };
+        let share = take_referral_share(gross, referral, config, source)?;
+        escrow_referrals.push((referrer, share));
+    }
+    let mut budget_referral = None;
+    if let Some(referral) = &referrals.campaign {
+        let referrer = referrer_account(referral, payees.campaign_referrer)?;
+        if on_top {
+            budget_referral = Some((referrer, referral_share(gross, referral, config)?));
+        } else {
+            let share = take_referral_share(gross, referral, config, &mut fee_amount)?;
+            escrow_referrals.push((referrer, share));
+        }
+    }
+
+    let agency_authority = match campaign.agency {
+        Some(agency_key) => {
+            let agency = payees.agency.ok_or(SoulboardError::InvalidAgency)?;
+            let agency_authority = payees
+                .agency_authority
+                .ok_or(SoulboardError::InvalidAgency)?;
+            require_keys_eq!(agency.key(), agency_key, SoulboardError::InvalidAgency);
+            require_keys_eq!(
+                agency_authority.key(),
+                agency.authority,
+                SoulboardError::InvalidAuthority
+            );
+            Some(agency_authority)
+        }
+        None => None,
+    };
+
+    move_lamports(escrow, &payees.earnings.to_account_info(), net_amount)?;
+    payees.earnings.credit(net_amount)?;
+    if fee_amount > 0 {
+        move_lamports(escrow, &payees.treasury.to_account_info(), fee_amount)?;
+        payees.treasury.record_fee(fee_amount, now)?;
+    }
+    let mut referral_amount = 0u64;
+    for (referrer, share) in escrow_referrals {
+        if share > 0 {
+            move_lamports(escrow, referrer, share)?;
+        }
+        referral_amount = referral_amount
+            .checked_add(share)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+    }
+    if let Some((referrer, share)) = budget_referral {
+        let paid = charge_campaign_budget(campaign, referrer, share)?;
+        referral_amount = referral_amount
+            .checked_add(paid)
+            .ok_or(SoulboardError::ArithmeticOverflow)?;
+    }
+    let agency_commission = match agency_authority {
+        Some(agency_authority) => charge_campaign_budget(
+            campaign,
+            agency_authority,
+            bps_share(gross, campaign.agency_commission_bps)?,
+        )?,
+        None => 0,
+    };
+
+    Ok(SettlementSplit {
+        fee_amount,
+        agency_commission,
+        referral_amount,
+    })
+}
+
 pub fn transfer_from_signer<'a>(
     from: &AccountInfo<'a>,
     to: &AccountInfo<'a>,
@@ -62,3 +556,139 @@ pub fn transfer_from_signer<'a>(
     invoke(&ix, &[from.clone(), to.clone(), system_program.clone()])?;
     Ok(())
 }
+
+/// Reads an account still in its pre-upgrade layout `L`. Legacy accounts already carry the
+/// discriminator of their current type `T` and are told apart by their shorter length.
+pub fn read_legacy_account<T: Discriminator, L: AnchorDeserialize + Space>(info: &AccountInfo) -> Result<L> {
+    require_keys_eq!(*info.owner, crate::ID, SoulboardError::InvalidParameters);
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() == ANCHOR_DISCRIMINATOR_SIZE + L::INIT_SPACE,
+        SoulboardError::AccountAlreadyMigrated
+    );
+    require!(
+        data[..ANCHOR_DISCRIMINATOR_SIZE] == *T::DISCRIMINATOR,
+        SoulboardError::InvalidParameters
+    );
+    L::deserialize(&mut &data[ANCHOR_DISCRIMINATOR_SIZE..]).map_err(|_| error!(SoulboardError::InvalidParameters))
+}
+
+/// Grows a legacy account to the current layout of `T`, funding the extra rent from
+/// `payer`, and writes `migrated` over it.
+pub fn write_migrated_account<'a, T: AccountSerialize + Space>(
+    info: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    migrated: &T,
+) -> Result<()> {
+    let space = ANCHOR_DISCRIMINATOR_SIZE + T::INIT_SPACE;
+    let top_up = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+    if top_up > 0 {
+        transfer_from_signer(payer, info, system_program, top_up)?;
+    }
+    info.realloc(space, false)?;
+
+    let mut data = info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    migrated.try_serialize(&mut writer)
+}
+
+pub fn lock_oracle_device<'a>(
+    oracle_program: &AccountInfo<'a>,
+    device: &AccountInfo<'a>,
+    device_authority: &AccountInfo<'a>,
+    booking_authority: &AccountInfo<'a>,
+    booking_authority_bump: u8,
+    device_idx: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[BOOKING_AUTHORITY_KEY, &[booking_authority_bump]]];
+    soul_board_oracle::cpi::lock_device_booking(
+        CpiContext::new_with_signer(
+            oracle_program.clone(),
+            soul_board_oracle::cpi::accounts::UpdateDeviceBookings {
+                device: device.clone(),
+                device_authority: device_authority.clone(),
+                booking_authority: booking_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        device_idx,
+    )
+}
+
+pub fn release_oracle_device<'a>(
+    oracle_program: &AccountInfo<'a>,
+    device: &AccountInfo<'a>,
+    device_authority: &AccountInfo<'a>,
+    booking_authority: &AccountInfo<'a>,
+    booking_authority_bump: u8,
+    device_idx: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[BOOKING_AUTHORITY_KEY, &[booking_authority_bump]]];
+    soul_board_oracle::cpi::release_device_booking(
+        CpiContext::new_with_signer(
+            oracle_program.clone(),
+            soul_board_oracle::cpi::accounts::UpdateDeviceBookings {
+                device: device.clone(),
+                device_authority: device_authority.clone(),
+                booking_authority: booking_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        device_idx,
+    )
+}
+
+/// Deserializes an active oracle device after checking its owner, PDA and authority.
+pub fn load_oracle_device(
+    device_info: &AccountInfo,
+    device_authority: &AccountInfo,
+    device_idx: u64,
+) -> Result<OracleDevice> {
+    let device = read_oracle_device(device_info, device_authority, device_idx)?;
+    require!(
+        device.status == OracleDeviceStatus::Active,
+        SoulboardError::OracleDeviceInactive
+    );
+
+    Ok(device)
+}
+
+/// Same as `load_oracle_device` but accepts a device in any status, for paths that
+/// must still wind down bookings on a deactivated device.
+pub fn read_oracle_device(
+    device_info: &AccountInfo,
+    device_authority: &AccountInfo,
+    device_idx: u64,
+) -> Result<OracleDevice> {
+    require_keys_eq!(
+        *device_info.owner,
+        soul_board_oracle::ID,
+        SoulboardError::InvalidOracleDevice
+    );
+
+    let (expected, _) = Pubkey::find_program_address(
+        &[
+            ORACLE_DEVICE_KEY,
+            device_authority.key().as_ref(),
+            &device_idx.to_le_bytes(),
+        ],
+        &soul_board_oracle::ID,
+    );
+    require_keys_eq!(expected, device_info.key(), SoulboardError::InvalidOracleDevice);
+
+    let mut data: &[u8] = &device_info.data.borrow();
+    let device = OracleDevice::try_deserialize(&mut data)
+        .map_err(|_| SoulboardError::InvalidOracleDevice)?;
+    require_keys_eq!(
+        device.authority,
+        device_authority.key(),
+        SoulboardError::InvalidOracleDevice
+    );
+    require!(
+        device.device_idx == device_idx,
+        SoulboardError::InvalidOracleDevice
+    );
+
+    Ok(device)
+}
@@ -106,7 +106,21 @@ export class CampaignService {
     );
   }
 
-  async close(
+  async pause(
+    campaignIdx: BN | number | bigint,
+    authority?: PublicKey
+  ): Promise<void> {
+    await this.setStatus("pauseCampaign", campaignIdx, authority);
+  }
+
+  async resume(
+    campaignIdx: BN | number | bigint,
+    authority?: PublicKey
+  ): Promise<void> {
+    await this.setStatus("resumeCampaign", campaignIdx, authority);
+  }
+
+  async end(
     campaignIdx: BN | number | bigint,
     authority?: PublicKey
   ): Promise<void> {
@@ -118,9 +132,9 @@ export class CampaignService {
       this.context.programId
     );
 
-    await this.context.executor.run("closeCampaign", () =>
+    await this.context.executor.run("endCampaign", () =>
       this.context.program.methods
-        .closeCampaign(toBN(campaignIdx))
+        .endCampaign(toBN(campaignIdx))
         .accounts({
           advertiser,
           authority: signer,
@@ -130,6 +144,50 @@ export class CampaignService {
     );
   }
 
+  async archive(
+    campaignIdx: BN | number | bigint,
+    authority?: PublicKey
+  ): Promise<void> {
+    const signer = resolveAuthority(this.context, authority);
+    const [campaign] = findCampaignPda(
+      signer,
+      campaignIdx,
+      this.context.programId
+    );
+
+    await this.context.executor.run("archiveCampaign", () =>
+      this.context.program.methods
+        .archiveCampaign(toBN(campaignIdx))
+        .accounts({
+          authority: signer,
+          campaign,
+        })
+        .rpc()
+    );
+  }
+
+  private async setStatus(
+    method: "pauseCampaign" | "resumeCampaign",
+    campaignIdx: BN | number | bigint,
+    authority?: PublicKey
+  ): Promise<void> {
+    const signer = resolveAuthority(this.context, authority);
+    const [campaign] = findCampaignPda(
+      signer,
+      campaignIdx,
+      this.context.programId
+    );
+
+    await this.context.executor.run(method, () =>
+      this.context.program.methods[method](toBN(campaignIdx))
+        .accounts({
+          authority: signer,
+          campaign,
+        })
+        .rpc()
+    );
+  }
+
   async update(
     campaignIdx: BN | number | bigint,
     updates: Partial<CampaignMetadata>,
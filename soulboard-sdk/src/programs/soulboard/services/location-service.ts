@@ -245,7 +245,9 @@ export class LocationService {
   async addCampaignLocation(
     campaignIdx: BN | number | bigint,
     locationIdx: BN | number | bigint,
+    deviceIdx: BN | number | bigint,
     providerAuthority: PublicKey,
+    deviceAuthority: PublicKey,
     campaignAuthority?: PublicKey
   ): Promise<AccountWithAddress<CampaignLocationAccount>> {
     const signer = resolveAuthority(this.context, campaignAuthority);
@@ -269,15 +271,23 @@ export class LocationService {
       this.context.programId
     );
 
+    const [oracleDevice] = findDevicePda(deviceAuthority, deviceIdx);
+
     await this.context.executor.run("addCampaignLocation", () =>
       this.context.program.methods
-        .addCampaignLocation(toBN(campaignIdx), toBN(locationIdx))
-        .accounts({
+        .addCampaignLocation(
+          toBN(campaignIdx),
+          toBN(locationIdx),
+          toBN(deviceIdx)
+        )
+        .accountsPartial({
           authority: signer,
           provider,
           campaign,
           location,
           campaignLocation,
+          oracleDevice,
+          deviceAuthority,
           systemProgram: SystemProgram.programId,
         })
         .rpc()
@@ -391,12 +401,7 @@ export class LocationService {
 
     await this.context.executor.run("cancelLocationBooking", () =>
       this.context.program.methods
-        .cancelLocationBooking(
-          toBN(campaignIdx),
-          toBN(locationIdx),
-          toBN(rangeStartTs),
-          toBN(rangeEndTs)
-        )
+        .cancelLocationBooking(toBN(campaignIdx), toBN(locationIdx))
         .accounts({
           authority: signer,
           campaign,
@@ -463,8 +468,6 @@ export class LocationService {
         .settleLocationBooking(
           toBN(campaignIdx),
           toBN(locationIdx),
-          toBN(rangeStartTs),
-          toBN(rangeEndTs),
           campaignAuthority,
           providerAuthority
         )
@@ -512,15 +515,19 @@ export class LocationService {
       this.context.programId
     );
 
+    const booking = await this.fetchCampaignLocationByAddress(campaignLocation);
+
     await this.context.executor.run("removeCampaignLocation", () =>
       this.context.program.methods
         .removeCampaignLocation(toBN(campaignIdx), toBN(locationIdx))
-        .accounts({
+        .accountsPartial({
           authority: signer,
           campaign,
           provider,
           location,
           campaignLocation,
+          oracleDevice: booking.device,
+          deviceAuthority: booking.deviceAuthority,
         })
         .rpc()
     );
@@ -535,13 +542,10 @@ export class LocationService {
     settlementAmount: BN | number | bigint,
     providerAuthority: PublicKey,
     campaignAuthority: PublicKey,
-    oracleAuthority?: PublicKey,
-    locationAuthority?: PublicKey
+    oracleAuthority?: PublicKey
   ): Promise<AccountWithAddress<CampaignLocationAccount>> {
     const oracleSigner = resolveAuthority(this.context, oracleAuthority);
-    const recipient = locationAuthority ?? providerAuthority;
 
-    // Derive PDAs for reference
     const [campaign] = findCampaignPda(
       campaignAuthority,
       campaignIdx,
@@ -557,18 +561,34 @@ export class LocationService {
       location,
       this.context.programId
     );
+    const [config] = findSoulboardConfigPda(this.context.programId);
+    const booking = await this.fetchCampaignLocationByAddress(campaignLocation);
+    const [oracleDevice] = findDevicePda(
+      booking.deviceAuthority,
+      booking.deviceIdx
+    );
+    const configData = await fetchAccountOrThrow<SoulboardConfigAccount>(
+      "fetchSoulboardConfig",
+      config,
+      () => this.context.program.account.soulboardConfig.fetch(config)
+    );
 
     await this.context.executor.run("settleCampaignLocation", () =>
       this.context.program.methods
         .settleCampaignLocation(
           toBN(campaignIdx),
           toBN(locationIdx),
-          campaignAuthority,
-          providerAuthority,
-          toBN(settlementAmount)
+          toBN(settlementAmount),
+          booking.deviceIdx
         )
-        .accounts({
-          locationAuthority: recipient,
+        .accountsPartial({
+          campaign,
+          location,
+          campaignLocation,
+          config,
+          oracleDevice,
+          deviceAuthority: booking.deviceAuthority,
+          treasury: configData.treasury,
           oracleAuthority: oracleSigner,
         })
         .rpc()
@@ -594,7 +614,6 @@ export class LocationService {
     providerAuthority: PublicKey,
     campaignAuthority: PublicKey,
     oracleAuthority?: PublicKey,
-    locationAuthority?: PublicKey,
     options?: SettlementQuoteOptions
   ): Promise<{
     account: AccountWithAddress<CampaignLocationAccount>;
@@ -623,8 +642,7 @@ export class LocationService {
       quote.grossLamports,
       providerAuthority,
       campaignAuthority,
-      oracleAuthority,
-      locationAuthority
+      oracleAuthority
     );
 
     return { account, quote };
@@ -633,13 +651,17 @@ export class LocationService {
   async book(
     campaignIdx: BN | number | bigint,
     locationIdx: BN | number | bigint,
+    deviceIdx: BN | number | bigint,
     providerAuthority: PublicKey,
+    deviceAuthority: PublicKey,
     campaignAuthority?: PublicKey
   ): Promise<AccountWithAddress<CampaignLocationAccount>> {
     return this.addCampaignLocation(
       campaignIdx,
       locationIdx,
+      deviceIdx,
       providerAuthority,
+      deviceAuthority,
       campaignAuthority
     );
   }
@@ -13,6 +13,183 @@ export type SoulBoardOracle = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "closeDevice",
+      "discriminator": [
+        156,
+        69,
+        71,
+        242,
+        206,
+        207,
+        38,
+        134
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "device",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "arg",
+                "path": "deviceIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101,
+                  95,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "device"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry",
+            "device"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeRegistry",
+      "discriminator": [
+        76,
+        32,
+        154,
+        180,
+        51,
+        159,
+        218,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "registry"
+          ]
+        }
+      ],
+      "args": []
+    },
     {
       "name": "createDeviceRegistry",
       "discriminator": [
@@ -32,7 +209,234 @@ export type SoulBoardOracle = {
           "signer": true
         },
         {
-          "name": "registry",
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "lockDeviceBooking",
+      "discriminator": [
+        153,
+        37,
+        61,
+        45,
+        195,
+        137,
+        8,
+        243
+      ],
+      "accounts": [
+        {
+          "name": "device",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "deviceAuthority"
+              },
+              {
+                "kind": "arg",
+                "path": "deviceIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
+          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                118,
+                227,
+                20,
+                253,
+                185,
+                118,
+                244,
+                200,
+                16,
+                193,
+                23,
+                151,
+                28,
+                129,
+                91,
+                111,
+                51,
+                177,
+                138,
+                53,
+                24,
+                51,
+                50,
+                149,
+                89,
+                157,
+                33,
+                8,
+                246,
+                111,
+                102,
+                96
+              ]
+            }
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateDevice",
+      "discriminator": [
+        198,
+        211,
+        107,
+        143,
+        97,
+        49,
+        220,
+        206
+      ],
+      "accounts": [
+        {
+          "name": "device",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "arg",
+                "path": "deviceIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "recordDeviceHeartbeat",
+      "discriminator": [
+        250,
+        34,
+        70,
+        217,
+        170,
+        97,
+        80,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "device",
           "writable": true,
           "pda": {
             "seeds": [
@@ -44,31 +448,34 @@ export type SoulBoardOracle = {
                   118,
                   105,
                   99,
-                  101,
-                  95,
-                  114,
-                  101,
-                  103,
-                  105,
-                  115,
-                  116,
-                  114,
-                  121
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "deviceAuthority"
+              },
+              {
+                "kind": "arg",
+                "path": "deviceIdx"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "oracleAuthority",
+          "signer": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        }
+      ]
     },
     {
       "name": "registerDevice",
@@ -167,6 +574,124 @@ export type SoulBoardOracle = {
         }
       ]
     },
+    {
+      "name": "releaseDeviceBooking",
+      "discriminator": [
+        240,
+        207,
+        149,
+        86,
+        49,
+        217,
+        25,
+        51
+      ],
+      "accounts": [
+        {
+          "name": "device",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "deviceAuthority"
+              },
+              {
+                "kind": "arg",
+                "path": "deviceIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
+          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                118,
+                227,
+                20,
+                253,
+                185,
+                118,
+                244,
+                200,
+                16,
+                193,
+                23,
+                151,
+                28,
+                129,
+                91,
+                111,
+                51,
+                177,
+                138,
+                53,
+                24,
+                51,
+                50,
+                149,
+                89,
+                157,
+                33,
+                8,
+                246,
+                111,
+                102,
+                96
+              ]
+            }
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        }
+      ]
+    },
     {
       "name": "reportDeviceMetrics",
       "discriminator": [
@@ -221,12 +746,170 @@ export type SoulBoardOracle = {
           "type": "u64"
         },
         {
-          "name": "views",
-          "type": "u64"
-        },
-        {
-          "name": "impressions",
-          "type": "u64"
+          "name": "views",
+          "type": "u64"
+        },
+        {
+          "name": "impressions",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setDeviceMetadata",
+      "discriminator": [
+        175,
+        61,
+        114,
+        129,
+        144,
+        110,
+        154,
+        209
+      ],
+      "accounts": [
+        {
+          "name": "device",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "arg",
+                "path": "deviceIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "metadata",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101,
+                  95,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "device"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "device"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        },
+        {
+          "name": "specs",
+          "type": {
+            "defined": {
+              "name": "deviceSpecs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDeviceStaleAfter",
+      "discriminator": [
+        251,
+        118,
+        165,
+        221,
+        12,
+        219,
+        185,
+        95
+      ],
+      "accounts": [
+        {
+          "name": "device",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  118,
+                  105,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "arg",
+                "path": "deviceIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "device"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        },
+        {
+          "name": "staleAfterSecs",
+          "type": "u32"
         }
       ]
     },
@@ -426,6 +1109,19 @@ export type SoulBoardOracle = {
         128
       ]
     },
+    {
+      "name": "deviceMetadata",
+      "discriminator": [
+        173,
+        42,
+        156,
+        151,
+        58,
+        8,
+        243,
+        155
+      ]
+    },
     {
       "name": "deviceRegistry",
       "discriminator": [
@@ -441,6 +1137,32 @@ export type SoulBoardOracle = {
     }
   ],
   "events": [
+    {
+      "name": "deviceClosed",
+      "discriminator": [
+        47,
+        29,
+        126,
+        132,
+        37,
+        146,
+        29,
+        62
+      ]
+    },
+    {
+      "name": "deviceHeartbeatRecorded",
+      "discriminator": [
+        222,
+        120,
+        110,
+        113,
+        231,
+        17,
+        78,
+        183
+      ]
+    },
     {
       "name": "deviceLocationUpdated",
       "discriminator": [
@@ -454,6 +1176,19 @@ export type SoulBoardOracle = {
         75
       ]
     },
+    {
+      "name": "deviceMetadataUpdated",
+      "discriminator": [
+        147,
+        248,
+        152,
+        174,
+        36,
+        145,
+        213,
+        162
+      ]
+    },
     {
       "name": "deviceMetricsReported",
       "discriminator": [
@@ -467,6 +1202,19 @@ export type SoulBoardOracle = {
         13
       ]
     },
+    {
+      "name": "deviceMigrated",
+      "discriminator": [
+        7,
+        60,
+        144,
+        41,
+        84,
+        30,
+        232,
+        179
+      ]
+    },
     {
       "name": "deviceOracleUpdated",
       "discriminator": [
@@ -493,6 +1241,19 @@ export type SoulBoardOracle = {
         181
       ]
     },
+    {
+      "name": "deviceRegistryClosed",
+      "discriminator": [
+        187,
+        114,
+        45,
+        25,
+        241,
+        143,
+        6,
+        32
+      ]
+    },
     {
       "name": "deviceRegistryCreated",
       "discriminator": [
@@ -506,6 +1267,19 @@ export type SoulBoardOracle = {
         68
       ]
     },
+    {
+      "name": "deviceStaleAfterUpdated",
+      "discriminator": [
+        139,
+        74,
+        218,
+        86,
+        238,
+        250,
+        66,
+        248
+      ]
+    },
     {
       "name": "deviceStatusUpdated",
       "discriminator": [
@@ -555,6 +1329,31 @@ export type SoulBoardOracle = {
       "code": 6006,
       "name": "arithmeticUnderflow",
       "msg": "Arithmetic underflow"
+    },
+    {
+      "code": 6007,
+      "name": "deviceHasActiveBookings",
+      "msg": "Device has active bookings"
+    },
+    {
+      "code": 6008,
+      "name": "registryHasDevices",
+      "msg": "Registry still has devices"
+    },
+    {
+      "code": 6009,
+      "name": "invalidStringLength",
+      "msg": "Invalid string length"
+    },
+    {
+      "code": 6010,
+      "name": "invalidCoordinates",
+      "msg": "Coordinates out of range"
+    },
+    {
+      "code": 6011,
+      "name": "accountAlreadyMigrated",
+      "msg": "Device is not in a pre-upgrade layout"
     }
   ],
   "types": [
@@ -594,6 +1393,86 @@ export type SoulBoardOracle = {
                 "name": "deviceMetrics"
               }
             }
+          },
+          {
+            "name": "activeBookings",
+            "type": "u32"
+          },
+          {
+            "name": "heartbeat",
+            "type": {
+              "defined": {
+                "name": "deviceHeartbeat"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "deviceClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "device",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "deviceIdx",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "deviceHeartbeat",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lastHeartbeatAt",
+            "type": "i64"
+          },
+          {
+            "name": "onlineSince",
+            "type": "i64"
+          },
+          {
+            "name": "totalUptimeSecs",
+            "type": "u64"
+          },
+          {
+            "name": "staleAfterSecs",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "deviceHeartbeatRecorded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "device",
+            "type": "pubkey"
+          },
+          {
+            "name": "onlineSince",
+            "type": "i64"
+          },
+          {
+            "name": "totalUptimeSecs",
+            "type": "u64"
+          },
+          {
+            "name": "reportedAt",
+            "type": "i64"
           }
         ]
       }
@@ -614,6 +1493,54 @@ export type SoulBoardOracle = {
         ]
       }
     },
+    {
+      "name": "deviceMetadata",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "device",
+            "type": "pubkey"
+          },
+          {
+            "name": "specs",
+            "type": {
+              "defined": {
+                "name": "deviceSpecs"
+              }
+            }
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "deviceMetadataUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "device",
+            "type": "pubkey"
+          },
+          {
+            "name": "metadata",
+            "type": "pubkey"
+          },
+          {
+            "name": "specs",
+            "type": {
+              "defined": {
+                "name": "deviceSpecs"
+              }
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "deviceMetrics",
       "type": {
@@ -666,6 +1593,22 @@ export type SoulBoardOracle = {
         ]
       }
     },
+    {
+      "name": "deviceMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "device",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
     {
       "name": "deviceOracleUpdated",
       "type": {
@@ -730,6 +1673,22 @@ export type SoulBoardOracle = {
         ]
       }
     },
+    {
+      "name": "deviceRegistryClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "registry",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
     {
       "name": "deviceRegistryCreated",
       "type": {
@@ -746,6 +1705,75 @@ export type SoulBoardOracle = {
         ]
       }
     },
+    {
+      "name": "deviceSpecs",
+      "docs": [
+        "Hardware and firmware description of a screen. Coordinates are degrees scaled by 1e7."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "resolutionWidth",
+            "type": "u16"
+          },
+          {
+            "name": "resolutionHeight",
+            "type": "u16"
+          },
+          {
+            "name": "orientation",
+            "type": {
+              "defined": {
+                "name": "screenOrientation"
+              }
+            }
+          },
+          {
+            "name": "widthMm",
+            "type": "u32"
+          },
+          {
+            "name": "heightMm",
+            "type": "u32"
+          },
+          {
+            "name": "supportedMimeTypes",
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "firmwareVersion",
+            "type": "string"
+          },
+          {
+            "name": "latitudeE7",
+            "type": "i32"
+          },
+          {
+            "name": "longitudeE7",
+            "type": "i32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "deviceStaleAfterUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "device",
+            "type": "pubkey"
+          },
+          {
+            "name": "staleAfterSecs",
+            "type": "u32"
+          }
+        ]
+      }
+    },
     {
       "name": "deviceStatus",
       "type": {
@@ -779,6 +1807,20 @@ export type SoulBoardOracle = {
           }
         ]
       }
+    },
+    {
+      "name": "screenOrientation",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "landscape"
+          },
+          {
+            "name": "portrait"
+          }
+        ]
+      }
     }
   ]
 };
@@ -13,6 +13,124 @@ export type Soulboard = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "acceptBooking",
+      "discriminator": [
+        97,
+        36,
+        19,
+        198,
+        37,
+        119,
+        48,
+        229
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          },
+          "relations": [
+            "booking"
+          ]
+        },
+        {
+          "name": "booking",
+          "writable": true
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        }
+      ]
+    },
     {
       "name": "addBudget",
       "discriminator": [
@@ -46,7 +164,8 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign.creator",
+                "account": "campaign"
               },
               {
                 "kind": "arg",
@@ -55,13 +174,40 @@ export type Soulboard = {
             ]
           }
         },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
         {
           "name": "authority",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "campaign"
-          ]
+          "signer": true
         },
         {
           "name": "systemProgram",
@@ -112,7 +258,8 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign.creator",
+                "account": "campaign"
               },
               {
                 "kind": "arg",
@@ -121,6 +268,37 @@ export type Soulboard = {
             ]
           }
         },
+        {
+          "name": "delegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
         {
           "name": "provider",
           "pda": {
@@ -140,11 +318,14 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "provider.authority",
+                "path": "provider.creator",
                 "account": "provider"
               }
             ]
-          }
+          },
+          "relations": [
+            "location"
+          ]
         },
         {
           "name": "location",
@@ -166,8 +347,8 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "provider.authority",
-                "account": "provider"
+                "path": "location.creator",
+                "account": "location"
               },
               {
                 "kind": "arg",
@@ -215,74 +396,26 @@ export type Soulboard = {
           }
         },
         {
-          "name": "authority",
+          "name": "locationSpend",
+          "docs": [
+            "Required when the campaign caps spend per location."
+          ],
           "writable": true,
-          "signer": true,
-          "relations": [
-            "campaign"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "campaignIdx",
-          "type": "u64"
-        },
-        {
-          "name": "locationIdx",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "addLocationSlot",
-      "discriminator": [
-        152,
-        207,
-        169,
-        246,
-        114,
-        240,
-        21,
-        43
-      ],
-      "accounts": [
-        {
-          "name": "provider",
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  99,
+                  97,
+                  109,
                   112,
-                  114,
-                  111,
-                  118,
+                  97,
                   105,
-                  100,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "authority"
-              }
-            ]
-          }
-        },
-        {
-          "name": "location",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
+                  103,
+                  110,
+                  95,
                   108,
                   111,
                   99,
@@ -290,23 +423,29 @@ export type Soulboard = {
                   116,
                   105,
                   111,
-                  110
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign"
               },
               {
-                "kind": "arg",
-                "path": "locationIdx"
+                "kind": "account",
+                "path": "location"
               }
             ]
           }
         },
         {
-          "name": "schedule",
-          "writable": true,
+          "name": "locationPolicy",
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -321,14 +460,12 @@ export type Soulboard = {
                   111,
                   110,
                   95,
-                  115,
-                  99,
-                  104,
-                  101,
-                  100,
-                  117,
+                  112,
+                  111,
                   108,
-                  101
+                  105,
+                  99,
+                  121
                 ]
               },
               {
@@ -338,75 +475,84 @@ export type Soulboard = {
             ]
           }
         },
+        {
+          "name": "oracleDevice",
+          "writable": true
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "authority",
-          "signer": true,
-          "relations": [
-            "provider"
-          ]
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "oracleProgram",
+          "address": "HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX"
         }
       ],
       "args": [
         {
-          "name": "locationIdx",
+          "name": "campaignIdx",
           "type": "u64"
         },
         {
-          "name": "startTs",
-          "type": "i64"
-        },
-        {
-          "name": "endTs",
-          "type": "i64"
+          "name": "locationIdx",
+          "type": "u64"
         },
         {
-          "name": "price",
+          "name": "deviceIdx",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "bookLocationRange",
+      "name": "addLocationSlot",
       "discriminator": [
-        97,
-        124,
-        5,
-        92,
-        243,
-        92,
-        78,
-        178
+        152,
+        207,
+        169,
+        246,
+        114,
+        240,
+        21,
+        43
       ],
       "accounts": [
-        {
-          "name": "campaign",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  97,
-                  109,
-                  112,
-                  97,
-                  105,
-                  103,
-                  110
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "authority"
-              },
-              {
-                "kind": "arg",
-                "path": "campaignIdx"
-              }
-            ]
-          }
-        },
         {
           "name": "provider",
           "pda": {
@@ -426,11 +572,14 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "provider.authority",
+                "path": "provider.creator",
                 "account": "provider"
               }
             ]
-          }
+          },
+          "relations": [
+            "location"
+          ]
         },
         {
           "name": "location",
@@ -452,8 +601,8 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "provider.authority",
-                "account": "provider"
+                "path": "location.creator",
+                "account": "location"
               },
               {
                 "kind": "arg",
@@ -462,6 +611,35 @@ export type Soulboard = {
             ]
           }
         },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
         {
           "name": "schedule",
           "writable": true,
@@ -497,7 +675,48 @@ export type Soulboard = {
           }
         },
         {
-          "name": "booking",
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "startTs",
+          "type": "i64"
+        },
+        {
+          "name": "endTs",
+          "type": "i64"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "allowOutsideHours",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "archiveCampaign",
+      "discriminator": [
+        19,
+        218,
+        54,
+        199,
+        114,
+        68,
+        46,
+        212
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
           "writable": true,
           "pda": {
             "seeds": [
@@ -511,42 +730,21 @@ export type Soulboard = {
                   97,
                   105,
                   103,
-                  110,
-                  95,
-                  98,
-                  111,
-                  111,
-                  107,
-                  105,
-                  110,
-                  103
+                  110
                 ]
               },
               {
                 "kind": "account",
-                "path": "campaign"
-              },
-              {
-                "kind": "account",
-                "path": "location"
-              },
-              {
-                "kind": "arg",
-                "path": "rangeStartTs"
+                "path": "campaign.creator",
+                "account": "campaign"
               },
               {
                 "kind": "arg",
-                "path": "rangeEndTs"
+                "path": "campaignIdx"
               }
             ]
           }
         },
-        {
-          "name": "oracleDevice"
-        },
-        {
-          "name": "deviceAuthority"
-        },
         {
           "name": "authority",
           "writable": true,
@@ -554,54 +752,26 @@ export type Soulboard = {
           "relations": [
             "campaign"
           ]
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
           "name": "campaignIdx",
           "type": "u64"
-        },
-        {
-          "name": "locationIdx",
-          "type": "u64"
-        },
-        {
-          "name": "rangeStartTs",
-          "type": "i64"
-        },
-        {
-          "name": "rangeEndTs",
-          "type": "i64"
-        },
-        {
-          "name": "deviceIdx",
-          "type": "u64"
-        },
-        {
-          "name": "pricingModel",
-          "type": {
-            "defined": {
-              "name": "pricingModel"
-            }
-          }
         }
       ]
     },
     {
-      "name": "cancelLocationBooking",
+      "name": "bookLocationRange",
       "discriminator": [
-        215,
-        200,
-        17,
-        35,
-        30,
-        63,
-        130,
-        21
+        97,
+        124,
+        5,
+        92,
+        243,
+        92,
+        78,
+        178
       ],
       "accounts": [
         {
@@ -624,7 +794,8 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign.creator",
+                "account": "campaign"
               },
               {
                 "kind": "arg",
@@ -633,6 +804,37 @@ export type Soulboard = {
             ]
           }
         },
+        {
+          "name": "delegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
         {
           "name": "provider",
           "pda": {
@@ -652,11 +854,14 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "provider.authority",
+                "path": "provider.creator",
                 "account": "provider"
               }
             ]
-          }
+          },
+          "relations": [
+            "location"
+          ]
         },
         {
           "name": "location",
@@ -678,8 +883,8 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "provider.authority",
-                "account": "provider"
+                "path": "location.creator",
+                "account": "location"
               },
               {
                 "kind": "arg",
@@ -768,76 +973,44 @@ export type Soulboard = {
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "campaign"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "campaignIdx",
-          "type": "u64"
-        },
-        {
-          "name": "locationIdx",
-          "type": "u64"
-        },
-        {
-          "name": "rangeStartTs",
-          "type": "i64"
-        },
-        {
-          "name": "rangeEndTs",
-          "type": "i64"
-        }
-      ]
-    },
-    {
-      "name": "closeCampaign",
-      "discriminator": [
-        65,
-        49,
-        110,
-        7,
-        63,
-        238,
-        206,
-        77
-      ],
-      "accounts": [
-        {
-          "name": "advertiser",
-          "writable": true,
+          "name": "locationPolicy",
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  108,
+                  111,
+                  99,
                   97,
-                  100,
-                  118,
-                  101,
-                  114,
                   116,
                   105,
-                  115,
-                  101,
-                  114
+                  111,
+                  110,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "location"
               }
             ]
           }
         },
         {
-          "name": "campaign",
+          "name": "locationSpend",
+          "docs": [
+            "Required when the campaign has a per-location pacing cap."
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -850,204 +1023,227 @@ export type Soulboard = {
                   97,
                   105,
                   103,
-                  110
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign"
               },
               {
-                "kind": "arg",
-                "path": "campaignIdx"
+                "kind": "account",
+                "path": "location"
               }
             ]
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "advertiser",
-            "campaign"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "campaignIdx",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "createAdvertiser",
-      "discriminator": [
-        249,
-        170,
-        60,
-        235,
-        33,
-        219,
-        7,
-        83
-      ],
-      "accounts": [
+          "name": "oracleDevice",
+          "writable": true
+        },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
+          "name": "deviceAuthority"
         },
         {
-          "name": "advertiser",
-          "writable": true,
+          "name": "bookingAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
                   97,
-                  100,
-                  118,
-                  101,
-                  114,
+                  117,
                   116,
+                  104,
+                  111,
+                  114,
                   105,
-                  115,
-                  101,
-                  114
+                  116,
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "authority"
               }
             ]
           }
         },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "oracleProgram",
+          "address": "HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "rangeStartTs",
+          "type": "i64"
+        },
+        {
+          "name": "rangeEndTs",
+          "type": "i64"
+        },
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        },
+        {
+          "name": "pricingModel",
+          "type": {
+            "defined": {
+              "name": "pricingModel"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "createCampaign",
+      "name": "bookOrderSlots",
       "discriminator": [
-        111,
-        131,
-        187,
-        98,
-        160,
-        193,
-        114,
-        244
+        146,
+        218,
+        54,
+        174,
+        141,
+        174,
+        38,
+        38
       ],
       "accounts": [
         {
-          "name": "advertiser",
+          "name": "campaign",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  99,
+                  97,
+                  109,
+                  112,
                   97,
-                  100,
-                  118,
-                  101,
-                  114,
-                  116,
                   105,
-                  115,
-                  101,
-                  114
+                  103,
+                  110
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
               }
             ]
-          }
-        },
-        {
-          "name": "campaign",
+          },
+          "relations": [
+            "order"
+          ]
+        },
+        {
+          "name": "delegate",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  97,
-                  109,
-                  112,
-                  97,
-                  105,
+                  100,
+                  101,
+                  108,
+                  101,
                   103,
-                  110
+                  97,
+                  116,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign.advertiser",
+                "account": "campaign"
               },
               {
                 "kind": "account",
-                "path": "advertiser.last_campaign_id",
-                "account": "advertiser"
+                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "authority",
+          "name": "order",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "advertiser"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "campaignName",
-          "type": "string"
-        },
-        {
-          "name": "campaignDescription",
-          "type": "string"
-        },
-        {
-          "name": "campaignImageUrl",
-          "type": "string"
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "orderId"
+              }
+            ]
+          }
         },
-        {
-          "name": "budget",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "createLocationSchedule",
-      "discriminator": [
-        238,
-        232,
-        88,
-        169,
-        115,
-        51,
-        198,
-        4
-      ],
-      "accounts": [
         {
           "name": "provider",
           "pda": {
@@ -1067,14 +1263,17 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "provider.creator",
+                "account": "provider"
               }
             ]
-          }
+          },
+          "relations": [
+            "location"
+          ]
         },
         {
           "name": "location",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1092,7 +1291,8 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "location.creator",
+                "account": "location"
               },
               {
                 "kind": "arg",
@@ -1136,214 +1336,167 @@ export type Soulboard = {
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "provider"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "locationIdx",
-          "type": "u64"
-        },
-        {
-          "name": "maxSlots",
-          "type": "u32"
-        }
-      ]
-    },
-    {
-      "name": "createProvider",
-      "discriminator": [
-        74,
-        53,
-        211,
-        174,
-        38,
-        168,
-        227,
-        177
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "provider",
+          "name": "booking",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  99,
+                  97,
+                  109,
                   112,
-                  114,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  98,
                   111,
-                  118,
+                  111,
+                  107,
                   105,
-                  100,
-                  101,
-                  114
+                  110,
+                  103
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "order"
+              },
+              {
+                "kind": "account",
+                "path": "location"
               }
             ]
           }
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "initializeConfig",
-      "discriminator": [
-        208,
-        127,
-        21,
-        1,
-        194,
-        190,
-        196,
-        70
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
         },
         {
-          "name": "config",
-          "writable": true,
+          "name": "locationPolicy",
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  115,
-                  111,
-                  117,
                   108,
-                  98,
                   111,
-                  97,
-                  114,
-                  100,
-                  95,
                   99,
+                  97,
+                  116,
+                  105,
                   111,
                   110,
-                  102,
+                  95,
+                  112,
+                  111,
+                  108,
                   105,
-                  103
+                  99,
+                  121
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "treasury",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "registerLocation",
-      "discriminator": [
-        6,
-        192,
-        107,
-        95,
-        99,
-        19,
-        126,
-        64
-      ],
-      "accounts": [
-        {
-          "name": "provider",
+          "name": "locationSpend",
+          "docs": [
+            "Required when the campaign has a per-location pacing cap."
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  99,
+                  97,
+                  109,
                   112,
-                  114,
-                  111,
-                  118,
+                  97,
                   105,
-                  100,
-                  101,
-                  114
-                ]
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
+                ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
               }
             ]
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "provider"
-          ]
+          "name": "oracleDevice",
+          "writable": true
         },
         {
-          "name": "location",
-          "writable": true,
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
+                  98,
                   111,
-                  99,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
                   97,
+                  117,
                   116,
-                  105,
+                  104,
                   111,
-                  110
+                  114,
+                  105,
+                  116,
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "authority"
-              },
-              {
-                "kind": "account",
-                "path": "provider.last_location_id",
-                "account": "provider"
               }
             ]
           }
         },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "oracleProgram",
+          "address": "HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -1351,34 +1504,48 @@ export type Soulboard = {
       ],
       "args": [
         {
-          "name": "locationName",
-          "type": "string"
+          "name": "campaignIdx",
+          "type": "u64"
         },
         {
-          "name": "locationDescription",
-          "type": "string"
+          "name": "orderId",
+          "type": "u64"
         },
         {
-          "name": "price",
+          "name": "locationIdx",
           "type": "u64"
         },
         {
-          "name": "oracleAuthority",
-          "type": "pubkey"
+          "name": "slotIndexes",
+          "type": {
+            "vec": "u32"
+          }
+        },
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        },
+        {
+          "name": "pricingModel",
+          "type": {
+            "defined": {
+              "name": "pricingModel"
+            }
+          }
         }
       ]
     },
     {
-      "name": "removeCampaignLocation",
+      "name": "cancelBookingSlots",
       "discriminator": [
-        11,
+        84,
+        139,
+        64,
         168,
-        157,
-        230,
-        206,
-        75,
-        95,
-        212
+        50,
+        92,
+        47,
+        176
       ],
       "accounts": [
         {
@@ -1401,13 +1568,48 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign.creator",
+                "account": "campaign"
               },
               {
                 "kind": "arg",
                 "path": "campaignIdx"
               }
             ]
+          },
+          "relations": [
+            "booking",
+            "order"
+          ]
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
           }
         },
         {
@@ -1429,15 +1631,17 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "provider.authority",
+                "path": "provider.creator",
                 "account": "provider"
               }
             ]
-          }
+          },
+          "relations": [
+            "location"
+          ]
         },
         {
           "name": "location",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1455,33 +1659,27 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "provider.authority",
-                "account": "provider"
+                "path": "location.creator",
+                "account": "location"
               },
               {
                 "kind": "arg",
                 "path": "locationIdx"
               }
             ]
-          }
+          },
+          "relations": [
+            "booking"
+          ]
         },
         {
-          "name": "campaignLocation",
+          "name": "schedule",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  97,
-                  109,
-                  112,
-                  97,
-                  105,
-                  103,
-                  110,
-                  95,
                   108,
                   111,
                   99,
@@ -1489,13 +1687,18 @@ export type Soulboard = {
                   116,
                   105,
                   111,
-                  110
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
                 ]
               },
-              {
-                "kind": "account",
-                "path": "campaign"
-              },
               {
                 "kind": "account",
                 "path": "location"
@@ -1504,125 +1707,130 @@ export type Soulboard = {
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "campaign"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "campaignIdx",
-          "type": "u64"
+          "name": "booking",
+          "writable": true
         },
         {
-          "name": "locationIdx",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "setLocationStatus",
-      "discriminator": [
-        221,
-        161,
-        199,
-        131,
-        241,
-        177,
-        93,
-        5
-      ],
-      "accounts": [
+          "name": "order",
+          "docs": [
+            "Required when the booking belongs to a `BookingOrder`."
+          ],
+          "writable": true,
+          "optional": true
+        },
         {
-          "name": "provider",
+          "name": "locationSpend",
+          "docs": [
+            "Required when the booking charged the per-location spend counter."
+          ],
+          "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  99,
+                  97,
+                  109,
                   112,
-                  114,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
                   111,
-                  118,
+                  99,
+                  97,
+                  116,
                   105,
-                  100,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
                   101,
-                  114
+                  110,
+                  100
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
               }
             ]
           }
         },
         {
-          "name": "location",
+          "name": "spendDelegate",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  100,
+                  101,
                   108,
-                  111,
-                  99,
+                  101,
+                  103,
                   97,
                   116,
-                  105,
-                  111,
-                  110
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign.advertiser",
+                "account": "campaign"
               },
               {
-                "kind": "arg",
-                "path": "locationIdx"
+                "kind": "account",
+                "path": "booking.charges.delegate",
+                "account": "campaignBooking"
               }
             ]
           }
         },
         {
           "name": "authority",
-          "signer": true,
-          "relations": [
-            "provider"
-          ]
+          "signer": true
         }
       ],
       "args": [
         {
-          "name": "locationIdx",
+          "name": "campaignIdx",
           "type": "u64"
         },
         {
-          "name": "status",
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "slotIndexes",
           "type": {
-            "defined": {
-              "name": "locationStatus"
-            }
+            "vec": "u32"
           }
         }
       ]
     },
     {
-      "name": "settleCampaignLocation",
+      "name": "cancelLocationBooking",
       "discriminator": [
-        92,
-        155,
-        20,
-        165,
-        60,
-        151,
-        251,
-        131
+        215,
+        200,
+        17,
+        35,
+        30,
+        63,
+        130,
+        21
       ],
       "accounts": [
         {
@@ -1644,14 +1852,49 @@ export type Soulboard = {
                 ]
               },
               {
-                "kind": "arg",
-                "path": "campaignAuthority"
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
               },
               {
                 "kind": "arg",
                 "path": "campaignIdx"
               }
             ]
+          },
+          "relations": [
+            "booking",
+            "order"
+          ]
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
           }
         },
         {
@@ -1672,11 +1915,15 @@ export type Soulboard = {
                 ]
               },
               {
-                "kind": "arg",
-                "path": "providerAuthority"
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
               }
             ]
-          }
+          },
+          "relations": [
+            "location"
+          ]
         },
         {
           "name": "location",
@@ -1697,19 +1944,73 @@ export type Soulboard = {
                 ]
               },
               {
-                "kind": "arg",
-                "path": "providerAuthority"
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
               },
               {
                 "kind": "arg",
                 "path": "locationIdx"
               }
             ]
+          },
+          "relations": [
+            "booking"
+          ]
+        },
+        {
+          "name": "schedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
           }
         },
         {
-          "name": "campaignLocation",
+          "name": "booking",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "docs": [
+            "Required when the booking belongs to a `BookingOrder`."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "locationSpend",
+          "docs": [
+            "Required when the booking charged the per-location spend counter."
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -1731,7 +2032,13 @@ export type Soulboard = {
                   116,
                   105,
                   111,
-                  110
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
                 ]
               },
               {
@@ -1746,12 +2053,81 @@ export type Soulboard = {
           }
         },
         {
-          "name": "locationAuthority",
+          "name": "spendDelegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "booking.charges.delegate",
+                "account": "campaignBooking"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleDevice",
           "writable": true
         },
         {
-          "name": "oracleAuthority",
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
           "signer": true
+        },
+        {
+          "name": "oracleProgram",
+          "address": "HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX"
         }
       ],
       "args": [
@@ -1762,32 +2138,20 @@ export type Soulboard = {
         {
           "name": "locationIdx",
           "type": "u64"
-        },
-        {
-          "name": "campaignAuthority",
-          "type": "pubkey"
-        },
-        {
-          "name": "providerAuthority",
-          "type": "pubkey"
-        },
-        {
-          "name": "settlementAmount",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "settleLocationBooking",
+      "name": "closeBookingOrder",
       "discriminator": [
-        237,
-        63,
-        48,
-        237,
-        136,
-        119,
-        54,
-        165
+        224,
+        0,
+        60,
+        171,
+        111,
+        201,
+        255,
+        156
       ],
       "accounts": [
         {
@@ -1809,105 +2173,143 @@ export type Soulboard = {
                 ]
               },
               {
-                "kind": "arg",
-                "path": "campaignAuthority"
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
               },
               {
                 "kind": "arg",
                 "path": "campaignIdx"
               }
             ]
-          }
+          },
+          "relations": [
+            "order"
+          ]
         },
         {
-          "name": "provider",
+          "name": "delegate",
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  118,
-                  105,
                   100,
                   101,
-                  114
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
                 ]
               },
               {
-                "kind": "arg",
-                "path": "providerAuthority"
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "location",
+          "name": "order",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
+                  98,
                   111,
-                  99,
-                  97,
-                  116,
+                  111,
+                  107,
                   105,
+                  110,
+                  103,
+                  95,
                   111,
-                  110
+                  114,
+                  100,
+                  101,
+                  114
                 ]
               },
               {
-                "kind": "arg",
-                "path": "providerAuthority"
+                "kind": "account",
+                "path": "campaign"
               },
               {
                 "kind": "arg",
-                "path": "locationIdx"
+                "path": "orderId"
               }
             ]
           }
         },
         {
-          "name": "schedule",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  108,
-                  111,
-                  99,
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "orderId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeCampaign",
+      "discriminator": [
+        65,
+        49,
+        110,
+        7,
+        63,
+        238,
+        206,
+        77
+      ],
+      "accounts": [
+        {
+          "name": "advertiser",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
                   97,
+                  100,
+                  118,
+                  101,
+                  114,
                   116,
                   105,
-                  111,
-                  110,
-                  95,
                   115,
-                  99,
-                  104,
                   101,
-                  100,
-                  117,
-                  108,
-                  101
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "location"
+                "path": "advertiser.creator",
+                "account": "advertiser"
               }
             ]
           }
         },
         {
-          "name": "booking",
+          "name": "campaign",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1921,122 +2323,154 @@ export type Soulboard = {
                   97,
                   105,
                   103,
-                  110,
-                  95,
-                  98,
-                  111,
-                  111,
-                  107,
-                  105,
-                  110,
-                  103
+                  110
                 ]
               },
               {
                 "kind": "account",
-                "path": "campaign"
-              },
-              {
-                "kind": "account",
-                "path": "location"
-              },
-              {
-                "kind": "arg",
-                "path": "rangeStartTs"
+                "path": "campaign.creator",
+                "account": "campaign"
               },
               {
                 "kind": "arg",
-                "path": "rangeEndTs"
+                "path": "campaignIdx"
               }
             ]
           }
         },
         {
-          "name": "config",
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "advertiser",
+            "campaign"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createAdvertiser",
+      "discriminator": [
+        249,
+        170,
+        60,
+        235,
+        33,
+        219,
+        7,
+        83
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "advertiser",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  115,
-                  111,
-                  117,
-                  108,
-                  98,
-                  111,
                   97,
-                  114,
                   100,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
+                  118,
+                  101,
+                  114,
+                  116,
                   105,
-                  103
+                  115,
+                  101,
+                  114
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "oracleDevice"
-        },
-        {
-          "name": "deviceAuthority"
-        },
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createAgency",
+      "discriminator": [
+        206,
+        154,
+        210,
+        225,
+        54,
+        41,
+        81,
+        131
+      ],
+      "accounts": [
         {
-          "name": "locationAuthority",
-          "writable": true
+          "name": "agency",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
         },
         {
-          "name": "treasury",
-          "writable": true
+          "name": "authority",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "oracleAuthority",
-          "signer": true
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "campaignIdx",
-          "type": "u64"
-        },
-        {
-          "name": "locationIdx",
-          "type": "u64"
-        },
-        {
-          "name": "rangeStartTs",
-          "type": "i64"
-        },
-        {
-          "name": "rangeEndTs",
-          "type": "i64"
-        },
-        {
-          "name": "campaignAuthority",
-          "type": "pubkey"
-        },
-        {
-          "name": "providerAuthority",
-          "type": "pubkey"
+          "name": "commissionBps",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "updateCampaign",
+      "name": "createBookingOrder",
       "discriminator": [
-        235,
-        31,
-        39,
-        49,
-        121,
-        173,
-        19,
-        92
+        41,
+        120,
+        198,
+        201,
+        107,
+        104,
+        58,
+        68
       ],
       "accounts": [
         {
@@ -2059,7 +2493,8 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "campaign.creator",
+                "account": "campaign"
               },
               {
                 "kind": "arg",
@@ -2069,142 +2504,223 @@ export type Soulboard = {
           }
         },
         {
-          "name": "authority",
-          "signer": true,
-          "relations": [
-            "campaign"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "campaignIdx",
-          "type": "u64"
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
         },
         {
-          "name": "campaignName",
-          "type": {
-            "option": "string"
+          "name": "order",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.next_order_id",
+                "account": "campaign"
+              }
+            ]
           }
         },
         {
-          "name": "campaignDescription",
-          "type": {
-            "option": "string"
-          }
+          "name": "authority",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "campaignImageUrl",
-          "type": {
-            "option": "string"
-          }
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "updateLocationDetails",
+      "name": "createCampaign",
       "discriminator": [
-        255,
-        68,
-        138,
-        178,
-        133,
-        15,
-        42,
-        246
+        111,
+        131,
+        187,
+        98,
+        160,
+        193,
+        114,
+        244
       ],
       "accounts": [
         {
-          "name": "provider",
+          "name": "advertiser",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
+                  97,
+                  100,
                   118,
+                  101,
+                  114,
+                  116,
                   105,
-                  100,
+                  115,
                   101,
                   114
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "advertiser.creator",
+                "account": "advertiser"
               }
             ]
           }
         },
         {
-          "name": "location",
+          "name": "agency",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agency.creator",
+                "account": "agency"
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaign",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  108,
-                  111,
                   99,
                   97,
-                  116,
+                  109,
+                  112,
+                  97,
                   105,
-                  111,
+                  103,
                   110
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "advertiser.creator",
+                "account": "advertiser"
               },
               {
-                "kind": "arg",
-                "path": "locationIdx"
+                "kind": "account",
+                "path": "advertiser.last_campaign_id",
+                "account": "advertiser"
               }
             ]
           }
         },
         {
           "name": "authority",
-          "signer": true,
-          "relations": [
-            "provider"
-          ]
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "locationIdx",
-          "type": "u64"
+          "name": "campaignName",
+          "type": "string"
         },
         {
-          "name": "locationName",
-          "type": {
-            "option": "string"
-          }
+          "name": "campaignDescription",
+          "type": "string"
         },
         {
-          "name": "locationDescription",
-          "type": {
-            "option": "string"
-          }
+          "name": "campaignImageUrl",
+          "type": "string"
+        },
+        {
+          "name": "budget",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "updateLocationPrice",
+      "name": "createLocationSchedule",
       "discriminator": [
-        116,
-        230,
-        163,
-        205,
-        126,
-        234,
-        98,
-        124
+        238,
+        232,
+        88,
+        169,
+        115,
+        51,
+        198,
+        4
       ],
       "accounts": [
         {
@@ -2226,10 +2742,14 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "provider.creator",
+                "account": "provider"
               }
             ]
-          }
+          },
+          "relations": [
+            "location"
+          ]
         },
         {
           "name": "location",
@@ -2251,7 +2771,8 @@ export type Soulboard = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "location.creator",
+                "account": "location"
               },
               {
                 "kind": "arg",
@@ -2261,62 +2782,64 @@ export type Soulboard = {
           }
         },
         {
-          "name": "authority",
-          "signer": true,
-          "relations": [
-            "provider"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "locationIdx",
-          "type": "u64"
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
         },
         {
-          "name": "price",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "withdrawBudget",
-      "discriminator": [
-        26,
-        141,
-        71,
-        90,
-        120,
-        39,
-        231,
-        91
-      ],
-      "accounts": [
-        {
-          "name": "campaign",
+          "name": "schedule",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  108,
+                  111,
                   99,
                   97,
-                  109,
-                  112,
-                  97,
+                  116,
                   105,
-                  103,
-                  110
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
-              },
-              {
-                "kind": "arg",
-                "path": "campaignIdx"
+                "path": "location"
               }
             ]
           }
@@ -2324,480 +2847,10611 @@ export type Soulboard = {
         {
           "name": "authority",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "campaign"
-          ]
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "campaignIdx",
+          "name": "locationIdx",
           "type": "u64"
         },
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "maxSlots",
+          "type": "u32"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "advertiser",
-      "discriminator": [
-        224,
-        189,
-        134,
-        74,
-        86,
-        113,
-        216,
-        184
-      ]
-    },
-    {
-      "name": "campaign",
-      "discriminator": [
-        50,
-        40,
-        49,
-        11,
-        157,
-        220,
-        229,
-        192
-      ]
-    },
-    {
-      "name": "campaignBooking",
-      "discriminator": [
-        123,
-        35,
-        142,
-        240,
-        224,
-        160,
-        21,
-        136
-      ]
     },
     {
-      "name": "campaignLocation",
+      "name": "createProvider",
       "discriminator": [
-        140,
-        91,
-        221,
-        49,
-        222,
+        74,
+        53,
+        211,
+        174,
         38,
-        117,
-        125
-      ]
-    },
-    {
-      "name": "location",
-      "discriminator": [
-        73,
-        140,
-        105,
-        78,
-        215,
-        159,
-        92,
-        234
-      ]
-    },
-    {
-      "name": "locationSchedule",
-      "discriminator": [
-        2,
-        61,
-        136,
-        21,
-        217,
-        191,
-        136,
-        245
-      ]
+        168,
+        227,
+        177
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "provider",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "earnings",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  97,
+                  114,
+                  110,
+                  105,
+                  110,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
     },
     {
-      "name": "provider",
+      "name": "createProviderEarnings",
       "discriminator": [
-        164,
-        180,
-        71,
-        17,
-        75,
-        216,
-        80,
-        195
-      ]
+        233,
+        227,
+        169,
+        52,
+        24,
+        132,
+        53,
+        85
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "earnings",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  97,
+                  114,
+                  110,
+                  105,
+                  110,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
     },
     {
-      "name": "soulboardConfig",
-      "discriminator": [
-        42,
-        100,
-        60,
-        171,
-        110,
-        84,
-        31,
-        70
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "budgetAdded",
-      "discriminator": [
-        227,
-        8,
-        82,
-        112,
-        239,
-        227,
-        92,
-        169
-      ]
-    },
-    {
-      "name": "budgetWithdrawn",
+      "name": "endCampaign",
       "discriminator": [
         6,
-        32,
-        94,
-        74,
-        79,
-        3,
-        32,
-        122
-      ]
-    },
-    {
-      "name": "campaignBookingCancelled",
-      "discriminator": [
-        73,
-        78,
-        112,
-        142,
-        142,
-        226,
-        209,
-        230
-      ]
-    },
-    {
-      "name": "campaignBookingCreated",
-      "discriminator": [
-        149,
-        15,
-        230,
-        191,
-        125,
-        86,
-        148,
-        209
-      ]
-    },
-    {
-      "name": "campaignBookingSettled",
-      "discriminator": [
-        110,
-        93,
-        193,
-        12,
-        45,
-        249,
-        68,
-        29
-      ]
-    },
-    {
-      "name": "campaignClosed",
-      "discriminator": [
-        158,
-        143,
-        128,
-        251,
-        84,
-        131,
-        2,
+        152,
+        36,
+        161,
+        147,
+        29,
+        30,
         90
+      ],
+      "accounts": [
+        {
+          "name": "advertiser",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "advertiser.creator",
+                "account": "advertiser"
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "advertiser",
+            "campaign"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "campaignCreated",
+      "name": "expireCampaign",
       "discriminator": [
-        9,
-        98,
-        69,
-        61,
-        53,
-        131,
-        64,
-        152
+        229,
+        143,
+        4,
+        1,
+        143,
+        221,
+        0,
+        6
+      ],
+      "accounts": [
+        {
+          "name": "advertiser",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "advertiser.creator",
+                "account": "advertiser"
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "campaignLocationBooked",
+      "name": "expirePendingBooking",
       "discriminator": [
-        54,
-        9,
+        161,
+        46,
+        105,
         124,
-        21,
-        230,
-        113,
-        25,
-        177
-      ]
-    },
-    {
-      "name": "campaignLocationCancelled",
-      "discriminator": [
-        219,
-        62,
-        139,
-        226,
-        168,
-        58,
         77,
-        112
+        38,
+        159,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "relations": [
+            "booking",
+            "order"
+          ]
+        },
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          },
+          "relations": [
+            "booking"
+          ]
+        },
+        {
+          "name": "schedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "booking",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "docs": [
+            "Required when the booking belongs to a `BookingOrder`."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "locationSpend",
+          "docs": [
+            "Required when the booking charged the per-location spend counter."
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spendDelegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "booking.charges.delegate",
+                "account": "campaignBooking"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleDevice",
+          "writable": true
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "oracleProgram",
+          "address": "HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX"
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "campaignLocationSettled",
+      "name": "extendBooking",
       "discriminator": [
-        202,
-        207,
-        42,
-        195,
-        146,
-        203,
-        139,
-        3
-      ]
+        142,
+        67,
+        174,
+        233,
+        165,
+        248,
+        44,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          },
+          "relations": [
+            "booking",
+            "order"
+          ]
+        },
+        {
+          "name": "delegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          },
+          "relations": [
+            "booking"
+          ]
+        },
+        {
+          "name": "schedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "booking",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "docs": [
+            "Required when the booking belongs to a `BookingOrder`."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "locationPolicy",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleDevice"
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "locationSpend",
+          "docs": [
+            "Required when the campaign has a per-location pacing cap and the change costs more."
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spendDelegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "booking.charges.delegate",
+                "account": "campaignBooking"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "slotIndexes",
+          "type": {
+            "vec": "u32"
+          }
+        }
+      ]
+    },
+    {
+      "name": "holdLocationSlots",
+      "discriminator": [
+        32,
+        14,
+        141,
+        190,
+        228,
+        16,
+        75,
+        208
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "schedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "locationPolicy",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleDevice"
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "rangeStartTs",
+          "type": "i64"
+        },
+        {
+          "name": "rangeEndTs",
+          "type": "i64"
+        },
+        {
+          "name": "holdSecs",
+          "type": "u32"
+        },
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeConfig",
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "linkAgency",
+      "discriminator": [
+        207,
+        115,
+        128,
+        26,
+        224,
+        117,
+        91,
+        35
+      ],
+      "accounts": [
+        {
+          "name": "advertiser",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "advertiser.creator",
+                "account": "advertiser"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agency",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agency.creator",
+                "account": "agency"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegateAccount",
+          "docs": [
+            "Must not exist yet: a grant the advertiser already gave the operator is left alone."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "advertiser"
+              },
+              {
+                "kind": "account",
+                "path": "agency.authority",
+                "account": "agency"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "advertiser"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateAdvertiser",
+      "discriminator": [
+        193,
+        209,
+        131,
+        189,
+        219,
+        61,
+        205,
+        60
+      ],
+      "accounts": [
+        {
+          "name": "advertiser",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateCampaign",
+      "discriminator": [
+        38,
+        211,
+        205,
+        215,
+        172,
+        252,
+        62,
+        227
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateConfig",
+      "discriminator": [
+        92,
+        131,
+        58,
+        105,
+        210,
+        154,
+        224,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateLocation",
+      "discriminator": [
+        206,
+        173,
+        140,
+        105,
+        197,
+        118,
+        162,
+        196
+      ],
+      "accounts": [
+        {
+          "name": "location",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateProvider",
+      "discriminator": [
+        0,
+        124,
+        128,
+        196,
+        112,
+        147,
+        25,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pauseCampaign",
+      "discriminator": [
+        62,
+        247,
+        54,
+        192,
+        240,
+        158,
+        8,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "providerCancelBooking",
+      "discriminator": [
+        98,
+        183,
+        140,
+        203,
+        40,
+        129,
+        254,
+        179
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "relations": [
+            "booking",
+            "order"
+          ]
+        },
+        {
+          "name": "provider",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location",
+            "earnings"
+          ]
+        },
+        {
+          "name": "location",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          },
+          "relations": [
+            "schedule",
+            "booking"
+          ]
+        },
+        {
+          "name": "schedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "booking",
+          "writable": true
+        },
+        {
+          "name": "earnings",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  97,
+                  114,
+                  110,
+                  105,
+                  110,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "order",
+          "docs": [
+            "Required when the booking belongs to a `BookingOrder`."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "locationSpend",
+          "docs": [
+            "Required when the booking charged the per-location spend counter."
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spendDelegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "booking.charges.delegate",
+                "account": "campaignBooking"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleDevice",
+          "writable": true
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "oracleProgram",
+          "address": "HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX"
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "providerCancelReason"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerLocation",
+      "discriminator": [
+        6,
+        192,
+        107,
+        95,
+        99,
+        19,
+        126,
+        64
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "provider"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "provider.last_location_id",
+                "account": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "locationName",
+          "type": "string"
+        },
+        {
+          "name": "locationDescription",
+          "type": "string"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        },
+        {
+          "name": "oracleAuthority",
+          "type": "pubkey"
+        },
+        {
+          "name": "profile",
+          "type": {
+            "defined": {
+              "name": "locationProfile"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "rejectBooking",
+      "discriminator": [
+        209,
+        137,
+        96,
+        96,
+        217,
+        145,
+        70,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "relations": [
+            "booking",
+            "order"
+          ]
+        },
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          },
+          "relations": [
+            "booking"
+          ]
+        },
+        {
+          "name": "schedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "booking",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "docs": [
+            "Required when the booking belongs to a `BookingOrder`."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "locationSpend",
+          "docs": [
+            "Required when the booking charged the per-location spend counter."
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spendDelegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "booking.charges.delegate",
+                "account": "campaignBooking"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleDevice",
+          "writable": true
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "oracleProgram",
+          "address": "HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX"
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "releaseSlotHolds",
+      "discriminator": [
+        250,
+        42,
+        160,
+        20,
+        83,
+        65,
+        159,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "schedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeCampaignLocation",
+      "discriminator": [
+        11,
+        168,
+        157,
+        230,
+        206,
+        75,
+        95,
+        212
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaignLocation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "locationSpend",
+          "docs": [
+            "Required when the booking charged the per-location spend counter."
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spendDelegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign_location.charges.delegate",
+                "account": "campaignLocation"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleDevice",
+          "writable": true
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "oracleProgram",
+          "address": "HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX"
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeFeeOverride",
+      "discriminator": [
+        237,
+        246,
+        238,
+        80,
+        226,
+        26,
+        85,
+        244
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeOverride",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "target"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "target",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "removeLocationPolicy",
+      "discriminator": [
+        254,
+        137,
+        0,
+        41,
+        215,
+        90,
+        226,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "locationPolicy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "rescheduleBooking",
+      "discriminator": [
+        66,
+        36,
+        49,
+        78,
+        17,
+        77,
+        93,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          },
+          "relations": [
+            "booking",
+            "order"
+          ]
+        },
+        {
+          "name": "delegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          },
+          "relations": [
+            "booking"
+          ]
+        },
+        {
+          "name": "schedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "booking",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "docs": [
+            "Required when the booking belongs to a `BookingOrder`."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "locationPolicy",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleDevice"
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "locationSpend",
+          "docs": [
+            "Required when the campaign has a per-location pacing cap and the change costs more."
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spendDelegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "booking.charges.delegate",
+                "account": "campaignBooking"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "releaseIndexes",
+          "type": {
+            "vec": "u32"
+          }
+        },
+        {
+          "name": "bookIndexes",
+          "type": {
+            "vec": "u32"
+          }
+        }
+      ]
+    },
+    {
+      "name": "resumeCampaign",
+      "discriminator": [
+        84,
+        91,
+        163,
+        83,
+        28,
+        24,
+        54,
+        2
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "revokeDelegate",
+      "discriminator": [
+        142,
+        66,
+        98,
+        126,
+        102,
+        60,
+        92,
+        163
+      ],
+      "accounts": [
+        {
+          "name": "principal"
+        },
+        {
+          "name": "delegateAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "principal"
+              },
+              {
+                "kind": "arg",
+                "path": "delegate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setAgencyCommission",
+      "discriminator": [
+        142,
+        24,
+        198,
+        25,
+        186,
+        9,
+        8,
+        124
+      ],
+      "accounts": [
+        {
+          "name": "agency",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agency.creator",
+                "account": "agency"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "agency"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "commissionBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setCampaignContent",
+      "discriminator": [
+        17,
+        113,
+        37,
+        153,
+        100,
+        155,
+        21,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "contentCategories",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setCampaignPacing",
+      "discriminator": [
+        21,
+        85,
+        164,
+        88,
+        86,
+        176,
+        25,
+        196
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "pacing",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "campaignPacing"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCampaignReferral",
+      "discriminator": [
+        140,
+        70,
+        116,
+        192,
+        161,
+        148,
+        212,
+        231
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerDelegate",
+          "optional": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "referral",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "referral"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCampaignTargeting",
+      "discriminator": [
+        96,
+        35,
+        207,
+        228,
+        126,
+        187,
+        125,
+        16
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "campaign"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "targeting",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "campaignTargeting"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCampaignWindow",
+      "discriminator": [
+        105,
+        234,
+        207,
+        1,
+        39,
+        233,
+        170,
+        93
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "startsAt",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "endsAt",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDelegate",
+      "discriminator": [
+        242,
+        30,
+        46,
+        76,
+        108,
+        235,
+        128,
+        181
+      ],
+      "accounts": [
+        {
+          "name": "principal"
+        },
+        {
+          "name": "delegateAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "principal"
+              },
+              {
+                "kind": "arg",
+                "path": "delegate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "pubkey"
+        },
+        {
+          "name": "permissions",
+          "type": "u16"
+        },
+        {
+          "name": "spendLimit",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFeeOverride",
+      "discriminator": [
+        238,
+        6,
+        44,
+        194,
+        50,
+        78,
+        93,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeOverride",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "target"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "target",
+          "type": "pubkey"
+        },
+        {
+          "name": "scope",
+          "type": {
+            "defined": {
+              "name": "feeOverrideScope"
+            }
+          }
+        },
+        {
+          "name": "feeBps",
+          "type": "u16"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLocationApproval",
+      "discriminator": [
+        117,
+        70,
+        176,
+        90,
+        6,
+        162,
+        89,
+        79
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "requiresApproval",
+          "type": "bool"
+        },
+        {
+          "name": "approvalWindowSecs",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setLocationCancellation",
+      "discriminator": [
+        160,
+        241,
+        142,
+        71,
+        107,
+        168,
+        158,
+        10
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "cancellation",
+          "type": {
+            "defined": {
+              "name": "cancellationPolicy"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLocationModifications",
+      "discriminator": [
+        35,
+        149,
+        255,
+        234,
+        192,
+        107,
+        179,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "allowModifications",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setLocationOperatingHours",
+      "discriminator": [
+        10,
+        92,
+        56,
+        225,
+        14,
+        247,
+        251,
+        243
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "operatingHours",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "operatingHours"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLocationPolicy",
+      "discriminator": [
+        108,
+        241,
+        192,
+        8,
+        59,
+        126,
+        138,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "locationPolicy",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  112,
+                  111,
+                  108,
+                  105,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "allowedAdvertisers",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "deniedAdvertisers",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "forbiddenCategories",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setLocationReferral",
+      "discriminator": [
+        218,
+        244,
+        125,
+        113,
+        84,
+        207,
+        91,
+        48
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerDelegate",
+          "optional": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "referral",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "referral"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setLocationStatus",
+      "discriminator": [
+        221,
+        161,
+        199,
+        131,
+        241,
+        177,
+        93,
+        5
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": {
+              "name": "locationStatus"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setProviderCancelCompensation",
+      "discriminator": [
+        94,
+        70,
+        25,
+        10,
+        237,
+        189,
+        105,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "compensationBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setSettlementFallback",
+      "discriminator": [
+        156,
+        228,
+        110,
+        119,
+        164,
+        173,
+        64,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "settlementGraceSecs",
+          "type": "u32"
+        },
+        {
+          "name": "fallbackSettlement",
+          "type": {
+            "defined": {
+              "name": "fallbackSettlement"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "settleCampaignLocation",
+      "discriminator": [
+        92,
+        155,
+        20,
+        165,
+        60,
+        151,
+        251,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaignLocation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "locationSpend",
+          "docs": [
+            "Required when the booking charged the per-location spend counter."
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spendDelegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign_location.charges.delegate",
+                "account": "campaignLocation"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "providerFeeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "locationFeeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleDevice",
+          "writable": true
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "earnings",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  97,
+                  114,
+                  110,
+                  105,
+                  110,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "agency",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agency.creator",
+                "account": "agency"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agencyAuthority",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "campaignReferrer",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "locationReferrer",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "oracleAuthority",
+          "signer": true
+        },
+        {
+          "name": "oracleProgram",
+          "address": "HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX"
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "settlementAmount",
+          "type": "u64"
+        },
+        {
+          "name": "deviceIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleLocationBooking",
+      "discriminator": [
+        237,
+        63,
+        48,
+        237,
+        136,
+        119,
+        54,
+        165
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          },
+          "relations": [
+            "booking",
+            "order"
+          ]
+        },
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          },
+          "relations": [
+            "booking"
+          ]
+        },
+        {
+          "name": "schedule",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "booking",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "docs": [
+            "Required when the booking belongs to a `BookingOrder`."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "locationSpend",
+          "docs": [
+            "Required when the booking charged the per-location spend counter."
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  112,
+                  101,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spendDelegate",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "booking.charges.delegate",
+                "account": "campaignBooking"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "providerFeeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "locationFeeOverride",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  111,
+                  118,
+                  101,
+                  114,
+                  114,
+                  105,
+                  100,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleDevice",
+          "writable": true
+        },
+        {
+          "name": "deviceAuthority"
+        },
+        {
+          "name": "bookingAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  111,
+                  107,
+                  105,
+                  110,
+                  103,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "earnings",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  97,
+                  114,
+                  110,
+                  105,
+                  110,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "agency",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agency.creator",
+                "account": "agency"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agencyAuthority",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "campaignReferrer",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "locationReferrer",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "oracleAuthority",
+          "docs": [
+            "Omit to settle permissionlessly once the config grace period has passed."
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "oracleProgram",
+          "address": "HbjHJmYYCSjfyiJWCRvaYWo1vKsgRurFDkrxNnNusVFX"
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "campaignAuthority",
+          "type": "pubkey"
+        },
+        {
+          "name": "providerAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "syncCampaignAuthority",
+      "discriminator": [
+        214,
+        171,
+        152,
+        217,
+        86,
+        244,
+        141,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "advertiser",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "syncLocationAuthority",
+      "discriminator": [
+        74,
+        165,
+        243,
+        114,
+        215,
+        190,
+        170,
+        69
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "schedule",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "transferAdvertiser",
+      "discriminator": [
+        32,
+        53,
+        100,
+        48,
+        81,
+        183,
+        253,
+        216
+      ],
+      "accounts": [
+        {
+          "name": "advertiser",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "advertiser.creator",
+                "account": "advertiser"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "advertiser"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "transferAgency",
+      "discriminator": [
+        117,
+        50,
+        156,
+        47,
+        144,
+        74,
+        89,
+        200
+      ],
+      "accounts": [
+        {
+          "name": "agency",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agency.creator",
+                "account": "agency"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "agency"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "transferConfigAuthority",
+      "discriminator": [
+        105,
+        153,
+        105,
+        110,
+        203,
+        141,
+        163,
+        235
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "transferLocation",
+      "discriminator": [
+        82,
+        219,
+        14,
+        10,
+        21,
+        155,
+        132,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "newProvider",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "new_provider.creator",
+                "account": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "schedule",
+          "docs": [
+            "it; deserialized in the handler when it has been created."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  115,
+                  99,
+                  104,
+                  101,
+                  100,
+                  117,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "provider"
+          ]
+        },
+        {
+          "name": "newAuthority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "transferProvider",
+      "discriminator": [
+        201,
+        149,
+        137,
+        230,
+        173,
+        244,
+        200,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "provider"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "unlinkAgency",
+      "discriminator": [
+        28,
+        137,
+        188,
+        190,
+        82,
+        118,
+        239,
+        169
+      ],
+      "accounts": [
+        {
+          "name": "advertiser",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "advertiser.creator",
+                "account": "advertiser"
+              }
+            ]
+          }
+        },
+        {
+          "name": "agency",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "agency.creator",
+                "account": "agency"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegateAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "advertiser"
+              },
+              {
+                "kind": "account",
+                "path": "agency.authority",
+                "account": "agency"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "advertiser"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateCampaign",
+      "discriminator": [
+        235,
+        31,
+        39,
+        49,
+        121,
+        173,
+        19,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "campaignName",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "campaignDescription",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "campaignImageUrl",
+          "type": {
+            "option": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "maxReferralBps",
+          "type": "u16"
+        },
+        {
+          "name": "referralFeeMode",
+          "type": {
+            "defined": {
+              "name": "referralFeeMode"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateLocationDetails",
+      "discriminator": [
+        255,
+        68,
+        138,
+        178,
+        133,
+        15,
+        42,
+        246
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "locationName",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "locationDescription",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "profile",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "locationProfile"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateLocationPrice",
+      "discriminator": [
+        116,
+        230,
+        163,
+        205,
+        126,
+        234,
+        98,
+        124
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "location"
+          ]
+        },
+        {
+          "name": "location",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  111,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "location.creator",
+                "account": "location"
+              },
+              {
+                "kind": "arg",
+                "path": "locationIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "locationIdx",
+          "type": "u64"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawBudget",
+      "discriminator": [
+        26,
+        141,
+        71,
+        90,
+        120,
+        39,
+        231,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "arg",
+                "path": "campaignIdx"
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegate",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.advertiser",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "campaignIdx",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawEarnings",
+      "discriminator": [
+        6,
+        132,
+        233,
+        254,
+        241,
+        87,
+        247,
+        185
+      ],
+      "accounts": [
+        {
+          "name": "provider",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider.creator",
+                "account": "provider"
+              }
+            ]
+          },
+          "relations": [
+            "earnings"
+          ]
+        },
+        {
+          "name": "earnings",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  118,
+                  105,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  97,
+                  114,
+                  110,
+                  105,
+                  110,
+                  103,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "provider"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payout",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "provider"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawTreasury",
+      "discriminator": [
+        40,
+        63,
+        122,
+        158,
+        144,
+        216,
+        83,
+        96
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  117,
+                  108,
+                  98,
+                  111,
+                  97,
+                  114,
+                  100,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "recipient",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "splits",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "treasurySplit"
+              }
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "advertiser",
+      "discriminator": [
+        224,
+        189,
+        134,
+        74,
+        86,
+        113,
+        216,
+        184
+      ]
+    },
+    {
+      "name": "agency",
+      "discriminator": [
+        190,
+        253,
+        179,
+        184,
+        231,
+        128,
+        71,
+        112
+      ]
+    },
+    {
+      "name": "bookingOrder",
+      "discriminator": [
+        205,
+        120,
+        99,
+        210,
+        112,
+        18,
+        146,
+        57
+      ]
+    },
+    {
+      "name": "campaign",
+      "discriminator": [
+        50,
+        40,
+        49,
+        11,
+        157,
+        220,
+        229,
+        192
+      ]
+    },
+    {
+      "name": "campaignBooking",
+      "discriminator": [
+        123,
+        35,
+        142,
+        240,
+        224,
+        160,
+        21,
+        136
+      ]
+    },
+    {
+      "name": "campaignLocation",
+      "discriminator": [
+        140,
+        91,
+        221,
+        49,
+        222,
+        38,
+        117,
+        125
+      ]
+    },
+    {
+      "name": "campaignLocationSpend",
+      "discriminator": [
+        136,
+        196,
+        172,
+        180,
+        92,
+        215,
+        2,
+        69
+      ]
+    },
+    {
+      "name": "delegate",
+      "discriminator": [
+        92,
+        145,
+        166,
+        111,
+        11,
+        38,
+        38,
+        247
+      ]
+    },
+    {
+      "name": "feeOverride",
+      "discriminator": [
+        45,
+        33,
+        41,
+        248,
+        253,
+        236,
+        239,
+        85
+      ]
+    },
+    {
+      "name": "location",
+      "discriminator": [
+        73,
+        140,
+        105,
+        78,
+        215,
+        159,
+        92,
+        234
+      ]
+    },
+    {
+      "name": "locationPolicy",
+      "discriminator": [
+        33,
+        15,
+        56,
+        228,
+        67,
+        183,
+        56,
+        244
+      ]
+    },
+    {
+      "name": "locationSchedule",
+      "discriminator": [
+        2,
+        61,
+        136,
+        21,
+        217,
+        191,
+        136,
+        245
+      ]
+    },
+    {
+      "name": "provider",
+      "discriminator": [
+        164,
+        180,
+        71,
+        17,
+        75,
+        216,
+        80,
+        195
+      ]
+    },
+    {
+      "name": "providerEarnings",
+      "discriminator": [
+        103,
+        154,
+        140,
+        107,
+        40,
+        229,
+        1,
+        152
+      ]
+    },
+    {
+      "name": "soulboardConfig",
+      "discriminator": [
+        42,
+        100,
+        60,
+        171,
+        110,
+        84,
+        31,
+        70
+      ]
+    },
+    {
+      "name": "treasury",
+      "discriminator": [
+        238,
+        239,
+        123,
+        238,
+        89,
+        1,
+        168,
+        253
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "accountMigrated",
+      "discriminator": [
+        153,
+        121,
+        252,
+        128,
+        30,
+        241,
+        166,
+        101
+      ]
+    },
+    {
+      "name": "advertiserAgencyLinked",
+      "discriminator": [
+        52,
+        31,
+        106,
+        35,
+        35,
+        214,
+        117,
+        39
+      ]
+    },
+    {
+      "name": "advertiserAgencyUnlinked",
+      "discriminator": [
+        216,
+        26,
+        237,
+        143,
+        221,
+        27,
+        42,
+        129
+      ]
+    },
+    {
+      "name": "advertiserTransferred",
+      "discriminator": [
+        148,
+        178,
+        136,
+        199,
+        102,
+        101,
+        3,
+        163
+      ]
+    },
+    {
+      "name": "agencyUpdated",
+      "discriminator": [
+        221,
+        195,
+        43,
+        115,
+        206,
+        85,
+        0,
+        8
+      ]
+    },
+    {
+      "name": "bookingOrderClosed",
+      "discriminator": [
+        200,
+        119,
+        235,
+        15,
+        201,
+        128,
+        98,
+        31
+      ]
+    },
+    {
+      "name": "bookingOrderCreated",
+      "discriminator": [
+        26,
+        165,
+        66,
+        35,
+        44,
+        203,
+        96,
+        214
+      ]
+    },
+    {
+      "name": "budgetAdded",
+      "discriminator": [
+        227,
+        8,
+        82,
+        112,
+        239,
+        227,
+        92,
+        169
+      ]
+    },
+    {
+      "name": "budgetWithdrawn",
+      "discriminator": [
+        6,
+        32,
+        94,
+        74,
+        79,
+        3,
+        32,
+        122
+      ]
+    },
+    {
+      "name": "campaignArchived",
+      "discriminator": [
+        197,
+        249,
+        96,
+        74,
+        114,
+        70,
+        223,
+        79
+      ]
+    },
+    {
+      "name": "campaignBookingAccepted",
+      "discriminator": [
+        232,
+        109,
+        63,
+        174,
+        159,
+        132,
+        89,
+        15
+      ]
+    },
+    {
+      "name": "campaignBookingCancelled",
+      "discriminator": [
+        73,
+        78,
+        112,
+        142,
+        142,
+        226,
+        209,
+        230
+      ]
+    },
+    {
+      "name": "campaignBookingCancelledByProvider",
+      "discriminator": [
+        39,
+        226,
+        163,
+        4,
+        58,
+        144,
+        221,
+        159
+      ]
+    },
+    {
+      "name": "campaignBookingCreated",
+      "discriminator": [
+        149,
+        15,
+        230,
+        191,
+        125,
+        86,
+        148,
+        209
+      ]
+    },
+    {
+      "name": "campaignBookingExtended",
+      "discriminator": [
+        34,
+        196,
+        59,
+        105,
+        179,
+        136,
+        247,
+        169
+      ]
+    },
+    {
+      "name": "campaignBookingRejected",
+      "discriminator": [
+        63,
+        223,
+        233,
+        249,
+        134,
+        225,
+        233,
+        112
+      ]
+    },
+    {
+      "name": "campaignBookingRescheduled",
+      "discriminator": [
+        128,
+        111,
+        162,
+        212,
+        241,
+        194,
+        66,
+        169
+      ]
+    },
+    {
+      "name": "campaignBookingSettled",
+      "discriminator": [
+        110,
+        93,
+        193,
+        12,
+        45,
+        249,
+        68,
+        29
+      ]
+    },
+    {
+      "name": "campaignBookingSlotsCancelled",
+      "discriminator": [
+        33,
+        120,
+        162,
+        188,
+        123,
+        166,
+        86,
+        117
+      ]
+    },
+    {
+      "name": "campaignClosed",
+      "discriminator": [
+        158,
+        143,
+        128,
+        251,
+        84,
+        131,
+        2,
+        90
+      ]
+    },
+    {
+      "name": "campaignContentUpdated",
+      "discriminator": [
+        134,
+        183,
+        166,
+        163,
+        239,
+        243,
+        36,
+        9
+      ]
+    },
+    {
+      "name": "campaignCreated",
+      "discriminator": [
+        9,
+        98,
+        69,
+        61,
+        53,
+        131,
+        64,
+        152
+      ]
+    },
+    {
+      "name": "campaignExpired",
+      "discriminator": [
+        187,
+        3,
+        100,
+        113,
+        223,
+        198,
+        73,
+        68
+      ]
+    },
+    {
+      "name": "campaignLocationBooked",
+      "discriminator": [
+        54,
+        9,
+        124,
+        21,
+        230,
+        113,
+        25,
+        177
+      ]
+    },
+    {
+      "name": "campaignLocationCancelled",
+      "discriminator": [
+        219,
+        62,
+        139,
+        226,
+        168,
+        58,
+        77,
+        112
+      ]
+    },
+    {
+      "name": "campaignLocationSettled",
+      "discriminator": [
+        202,
+        207,
+        42,
+        195,
+        146,
+        203,
+        139,
+        3
+      ]
+    },
+    {
+      "name": "campaignPacingUpdated",
+      "discriminator": [
+        175,
+        166,
+        209,
+        179,
+        85,
+        55,
+        100,
+        67
+      ]
+    },
+    {
+      "name": "campaignStatusChanged",
+      "discriminator": [
+        29,
+        231,
+        184,
+        247,
+        102,
+        189,
+        130,
+        98
+      ]
+    },
+    {
+      "name": "campaignTargetingUpdated",
+      "discriminator": [
+        165,
+        132,
+        50,
+        237,
+        85,
+        63,
+        216,
+        238
+      ]
+    },
+    {
+      "name": "campaignUpdated",
+      "discriminator": [
+        110,
+        209,
+        206,
+        190,
+        205,
+        2,
+        234,
+        81
+      ]
+    },
+    {
+      "name": "campaignWindowUpdated",
+      "discriminator": [
+        116,
+        11,
+        239,
+        189,
+        184,
+        73,
+        141,
+        239
+      ]
+    },
+    {
+      "name": "configAuthorityTransferred",
+      "discriminator": [
+        116,
+        152,
+        24,
+        23,
+        117,
+        176,
+        34,
+        143
+      ]
+    },
+    {
+      "name": "delegateRevoked",
+      "discriminator": [
+        10,
+        200,
+        133,
+        29,
+        238,
+        207,
+        193,
+        124
+      ]
+    },
+    {
+      "name": "delegateUpdated",
+      "discriminator": [
+        103,
+        221,
+        114,
+        118,
+        109,
+        141,
+        48,
+        134
+      ]
+    },
+    {
+      "name": "earningsWithdrawn",
+      "discriminator": [
+        2,
+        155,
+        160,
+        28,
+        85,
+        112,
+        127,
+        79
+      ]
+    },
+    {
+      "name": "feeOverrideRemoved",
+      "discriminator": [
+        4,
+        59,
+        160,
+        233,
+        13,
+        11,
+        21,
+        137
+      ]
+    },
+    {
+      "name": "feeOverrideSet",
+      "discriminator": [
+        93,
+        117,
+        225,
+        31,
+        184,
+        115,
+        83,
+        4
+      ]
+    },
+    {
+      "name": "locationApprovalUpdated",
+      "discriminator": [
+        24,
+        58,
+        112,
+        135,
+        109,
+        128,
+        160,
+        3
+      ]
+    },
+    {
+      "name": "locationCancellationUpdated",
+      "discriminator": [
+        199,
+        33,
+        10,
+        165,
+        48,
+        148,
+        25,
+        1
+      ]
+    },
+    {
+      "name": "locationModificationsUpdated",
+      "discriminator": [
+        196,
+        52,
+        179,
+        117,
+        161,
+        170,
+        197,
+        76
+      ]
+    },
+    {
+      "name": "locationPolicyRemoved",
+      "discriminator": [
+        247,
+        32,
+        121,
+        107,
+        215,
+        126,
+        246,
+        26
+      ]
+    },
+    {
+      "name": "locationPolicyUpdated",
+      "discriminator": [
+        57,
+        30,
+        173,
+        234,
+        143,
+        135,
+        158,
+        155
+      ]
+    },
+    {
+      "name": "locationRegistered",
+      "discriminator": [
+        43,
+        158,
+        200,
+        223,
+        220,
+        110,
+        239,
+        162
+      ]
+    },
+    {
+      "name": "locationScheduleCreated",
+      "discriminator": [
+        65,
+        246,
+        199,
+        100,
+        199,
+        250,
+        46,
+        15
+      ]
+    },
+    {
+      "name": "locationSlotAdded",
+      "discriminator": [
+        1,
+        157,
+        169,
+        220,
+        176,
+        31,
+        39,
+        191
+      ]
+    },
+    {
+      "name": "locationSlotHoldsReleased",
+      "discriminator": [
+        204,
+        113,
+        141,
+        219,
+        173,
+        2,
+        195,
+        227
+      ]
+    },
+    {
+      "name": "locationSlotsHeld",
+      "discriminator": [
+        144,
+        69,
+        194,
+        82,
+        58,
+        89,
+        73,
+        153
+      ]
+    },
+    {
+      "name": "locationTransferred",
+      "discriminator": [
+        89,
+        140,
+        168,
+        105,
+        0,
+        27,
+        121,
+        207
+      ]
+    },
+    {
+      "name": "locationUpdated",
+      "discriminator": [
+        161,
+        48,
+        186,
+        7,
+        3,
+        198,
+        31,
+        67
+      ]
+    },
+    {
+      "name": "providerCancelCompensationUpdated",
+      "discriminator": [
+        212,
+        15,
+        141,
+        95,
+        16,
+        206,
+        251,
+        48
+      ]
+    },
+    {
+      "name": "providerTransferred",
+      "discriminator": [
+        79,
+        204,
+        141,
+        17,
+        108,
+        169,
+        178,
+        108
+      ]
+    },
+    {
+      "name": "referralUpdated",
+      "discriminator": [
+        44,
+        206,
+        190,
+        2,
+        81,
+        151,
+        155,
+        214
+      ]
+    },
+    {
+      "name": "settlementFallbackUpdated",
+      "discriminator": [
+        4,
+        196,
+        24,
+        137,
+        236,
+        203,
+        161,
+        103
+      ]
+    },
+    {
+      "name": "soulboardConfigInitialized",
+      "discriminator": [
+        196,
+        254,
+        15,
+        131,
+        243,
+        166,
+        87,
+        33
+      ]
+    },
+    {
+      "name": "soulboardConfigUpdated",
+      "discriminator": [
+        248,
+        124,
+        173,
+        72,
+        62,
+        119,
+        19,
+        173
+      ]
+    },
+    {
+      "name": "treasuryWithdrawn",
+      "discriminator": [
+        143,
+        181,
+        157,
+        169,
+        87,
+        155,
+        170,
+        46
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidAuthority",
+      "msg": "Invalid authority"
+    },
+    {
+      "code": 6001,
+      "name": "oracleNotConfigured",
+      "msg": "Oracle authority not configured"
+    },
+    {
+      "code": 6002,
+      "name": "invalidOracleAuthority",
+      "msg": "Invalid oracle authority"
+    },
+    {
+      "code": 6003,
+      "name": "unauthorized",
+      "msg": "Unauthorized operation"
+    },
+    {
+      "code": 6004,
+      "name": "campaignNotActive",
+      "msg": "Campaign is not active"
+    },
+    {
+      "code": 6005,
+      "name": "campaignHasActiveBookings",
+      "msg": "Campaign has active bookings"
+    },
+    {
+      "code": 6006,
+      "name": "insufficientBudget",
+      "msg": "Insufficient campaign budget"
+    },
+    {
+      "code": 6007,
+      "name": "locationUnavailable",
+      "msg": "Location is unavailable"
+    },
+    {
+      "code": 6008,
+      "name": "locationInactive",
+      "msg": "Location is inactive"
+    },
+    {
+      "code": 6009,
+      "name": "locationAlreadyBooked",
+      "msg": "Location already booked"
+    },
+    {
+      "code": 6010,
+      "name": "invalidTimeRange",
+      "msg": "Invalid time range"
+    },
+    {
+      "code": 6011,
+      "name": "slotOverlap",
+      "msg": "Slot overlaps with existing slot"
+    },
+    {
+      "code": 6012,
+      "name": "slotUnavailable",
+      "msg": "Slot is unavailable"
+    },
+    {
+      "code": 6013,
+      "name": "slotNotFound",
+      "msg": "No slots found in range"
+    },
+    {
+      "code": 6014,
+      "name": "slotInPast",
+      "msg": "Slot time is in the past"
+    },
+    {
+      "code": 6015,
+      "name": "scheduleFull",
+      "msg": "Schedule has reached maximum slots"
+    },
+    {
+      "code": 6016,
+      "name": "invalidOracleDevice",
+      "msg": "Invalid oracle device"
+    },
+    {
+      "code": 6017,
+      "name": "oracleDeviceInactive",
+      "msg": "Oracle device inactive"
+    },
+    {
+      "code": 6018,
+      "name": "bookingAlreadyExists",
+      "msg": "Booking already exists"
+    },
+    {
+      "code": 6019,
+      "name": "bookingNotActive",
+      "msg": "Booking not active"
+    },
+    {
+      "code": 6020,
+      "name": "settlementTooHigh",
+      "msg": "Settlement amount exceeds escrow"
+    },
+    {
+      "code": 6021,
+      "name": "invalidParameters",
+      "msg": "Invalid parameters"
+    },
+    {
+      "code": 6022,
+      "name": "invalidStringLength",
+      "msg": "Invalid string length"
+    },
+    {
+      "code": 6023,
+      "name": "insufficientRent",
+      "msg": "Insufficient rent-exempt balance"
+    },
+    {
+      "code": 6024,
+      "name": "insufficientEarnings",
+      "msg": "Insufficient earnings"
+    },
+    {
+      "code": 6025,
+      "name": "arithmeticOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6026,
+      "name": "arithmeticUnderflow",
+      "msg": "Arithmetic underflow"
+    },
+    {
+      "code": 6027,
+      "name": "oracleDeviceOffline",
+      "msg": "Oracle device is offline"
+    },
+    {
+      "code": 6028,
+      "name": "invalidCoordinates",
+      "msg": "Coordinates out of range"
+    },
+    {
+      "code": 6029,
+      "name": "slotOutsideOperatingHours",
+      "msg": "Slot is outside location operating hours"
+    },
+    {
+      "code": 6030,
+      "name": "delegateSpendLimitExceeded",
+      "msg": "Delegate spend limit exceeded"
+    },
+    {
+      "code": 6031,
+      "name": "locationHasBookings",
+      "msg": "Location has live bookings"
+    },
+    {
+      "code": 6032,
+      "name": "invalidAgency",
+      "msg": "Agency does not manage this advertiser"
+    },
+    {
+      "code": 6033,
+      "name": "agencyHasAdvertisers",
+      "msg": "Agency still manages advertisers"
+    },
+    {
+      "code": 6034,
+      "name": "invalidReferrer",
+      "msg": "Referrer is a party to the campaign or location"
+    },
+    {
+      "code": 6035,
+      "name": "campaignNotPaused",
+      "msg": "Campaign is not paused"
+    },
+    {
+      "code": 6036,
+      "name": "campaignNotClosed",
+      "msg": "Campaign has not ended"
+    },
+    {
+      "code": 6037,
+      "name": "outsideCampaignWindow",
+      "msg": "Booking range is outside the campaign window"
+    },
+    {
+      "code": 6038,
+      "name": "campaignNotExpired",
+      "msg": "Campaign has not reached its end time"
+    },
+    {
+      "code": 6039,
+      "name": "pacingLimitExceeded",
+      "msg": "Booking exceeds the campaign pacing limits"
+    },
+    {
+      "code": 6040,
+      "name": "targetingMismatch",
+      "msg": "Location does not match the campaign targeting"
+    },
+    {
+      "code": 6041,
+      "name": "rejectedByLocationPolicy",
+      "msg": "Location policy does not accept this campaign"
+    },
+    {
+      "code": 6042,
+      "name": "bookingNotPending",
+      "msg": "Booking is not awaiting approval"
+    },
+    {
+      "code": 6043,
+      "name": "approvalDeadlinePassed",
+      "msg": "Booking approval deadline has passed"
+    },
+    {
+      "code": 6044,
+      "name": "approvalDeadlineNotReached",
+      "msg": "Booking approval deadline has not passed"
+    },
+    {
+      "code": 6045,
+      "name": "slotAlreadyHeld",
+      "msg": "Slot is already held by this campaign"
+    },
+    {
+      "code": 6046,
+      "name": "orderHasBookings",
+      "msg": "Order still has live bookings"
+    },
+    {
+      "code": 6047,
+      "name": "partialCancelNotAllowed",
+      "msg": "Location does not allow partial cancellation"
+    },
+    {
+      "code": 6048,
+      "name": "cancellationCutoffPassed",
+      "msg": "Slot is inside the cancellation cutoff"
+    },
+    {
+      "code": 6049,
+      "name": "slotNotInBooking",
+      "msg": "Slot does not belong to this booking"
+    },
+    {
+      "code": 6050,
+      "name": "modificationsNotAllowed",
+      "msg": "Location does not allow booking modifications"
+    },
+    {
+      "code": 6051,
+      "name": "slotsNotAdjacent",
+      "msg": "Slots are not adjacent to the booking"
+    },
+    {
+      "code": 6052,
+      "name": "settlementGracePending",
+      "msg": "Settlement grace period has not elapsed"
+    },
+    {
+      "code": 6053,
+      "name": "accountAlreadyMigrated",
+      "msg": "Account is not in a pre-upgrade layout"
+    }
+  ],
+  "types": [
+    {
+      "name": "accountMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "account",
+            "type": "pubkey"
+          },
+          {
+            "name": "payer",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "advertiser",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Original authority; PDA seeds stay bound to it across ownership transfers."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "lastCampaignId",
+            "type": "u64"
+          },
+          {
+            "name": "campaignCount",
+            "type": "u64"
+          },
+          {
+            "name": "agency",
+            "docs": [
+              "Agency the advertiser has opted in to be managed by."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "advertiserAgencyLinked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "advertiser",
+            "type": "pubkey"
+          },
+          {
+            "name": "agency",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "advertiserAgencyUnlinked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "advertiser",
+            "type": "pubkey"
+          },
+          {
+            "name": "agency",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "advertiserTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "advertiser",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAuthority",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "agency",
+      "docs": [
+        "Buyer operating campaigns for linked advertisers in exchange for a commission."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "name": "commissionBps",
+            "type": "u16"
+          },
+          {
+            "name": "advertiserCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "agencyUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "agency",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "commissionBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bookingCharges",
+      "docs": [
+        "Spend counters a booking has charged and not yet given back, so refunds can credit",
+        "the same counters."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "delegate",
+            "docs": [
+              "Delegate signer whose `Delegate::spent` the booking charged, if any."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "delegateAmount",
+            "type": "u64"
+          },
+          {
+            "name": "locationAmount",
+            "type": "u64"
+          },
+          {
+            "name": "pacingWindowStart",
+            "docs": [
+              "Pacing window the booking was last charged in; earlier windows are not credited."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "pacingAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bookingOrder",
+      "docs": [
+        "Groups bookings made from explicit slot lists, one child booking per location."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "orderId",
+            "type": "u64"
+          },
+          {
+            "name": "bookingCount",
+            "docs": [
+              "Child bookings not yet cancelled or settled."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "totalPrice",
+            "docs": [
+              "Escrow currently reserved across the live child bookings."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "settledAmount",
+            "docs": [
+              "Gross paid out by settled child bookings."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bookingOrderClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "order",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "orderId",
+            "type": "u64"
+          },
+          {
+            "name": "settledAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bookingOrderCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "order",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "orderId",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bookingReferrals",
+      "docs": [
+        "Referrals copied onto a booking when it is made; settlement pays these rather than",
+        "whatever the campaign and location carry by then."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "referral"
+                }
+              }
+            }
+          },
+          {
+            "name": "location",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "referral"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bookingStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
+          },
+          {
+            "name": "cancelled"
+          },
+          {
+            "name": "settled"
+          },
+          {
+            "name": "pending"
+          }
+        ]
+      }
+    },
+    {
+      "name": "budgetAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "availableBudget",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "budgetWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "availableBudget",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaign",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator of the owning advertiser account, used for PDA seeds."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "advertiser",
+            "docs": [
+              "Owning advertiser account; campaign delegates are scoped to it."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "campaignIdx",
+            "type": "u64"
+          },
+          {
+            "name": "campaignName",
+            "type": "string"
+          },
+          {
+            "name": "campaignDescription",
+            "type": "string"
+          },
+          {
+            "name": "campaignImageUrl",
+            "type": "string"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "campaignStatus"
+              }
+            }
+          },
+          {
+            "name": "availableBudget",
+            "type": "u64"
+          },
+          {
+            "name": "reservedBudget",
+            "type": "u64"
+          },
+          {
+            "name": "agency",
+            "docs": [
+              "Managing agency and its commission, fixed when the campaign is created."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "agencyCommissionBps",
+            "type": "u16"
+          },
+          {
+            "name": "referral",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "referral"
+                }
+              }
+            }
+          },
+          {
+            "name": "startsAt",
+            "docs": [
+              "Optional booking window; booked ranges must fall inside it."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "endsAt",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "pacing",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "campaignPacing"
+                }
+              }
+            }
+          },
+          {
+            "name": "pacingWindowStart",
+            "docs": [
+              "Rolling pacing window: restarts once `pacing.period` has elapsed since it opened."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "pacingWindowSpent",
+            "type": "u64"
+          },
+          {
+            "name": "targeting",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "campaignTargeting"
+                }
+              }
+            }
+          },
+          {
+            "name": "contentCategories",
+            "docs": [
+              "Bitmask of `ContentCategory::mask` values the campaign's creatives contain."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "nextOrderId",
+            "docs": [
+              "Id assigned to the next `BookingOrder` opened for this campaign."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaignArchived",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "refundedBudget",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaignBooking",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "advertiser",
+            "type": "pubkey"
+          },
+          {
+            "name": "provider",
+            "type": "pubkey"
+          },
+          {
+            "name": "oracleAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "device",
+            "type": "pubkey"
+          },
+          {
+            "name": "deviceAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "deviceIdx",
+            "type": "u64"
+          },
+          {
+            "name": "rangeStartTs",
+            "type": "i64"
+          },
+          {
+            "name": "rangeEndTs",
+            "type": "i64"
+          },
+          {
+            "name": "slotCount",
+            "type": "u32"
+          },
+          {
+            "name": "totalPrice",
+            "type": "u64"
+          },
+          {
+            "name": "pricingModel",
+            "type": {
+              "defined": {
+                "name": "pricingModel"
+              }
+            }
+          },
+          {
+            "name": "startImpressions",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "bookingStatus"
+              }
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "referrals",
+            "type": {
+              "defined": {
+                "name": "bookingReferrals"
+              }
+            }
+          },
+          {
+            "name": "approvalDeadline",
+            "docs": [
+              "Set on `Pending` bookings: the provider must accept before this time."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "order",
+            "docs": [
+              "Parent `BookingOrder` for bookings made from an explicit slot list."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "charges",
+            "type": {
+              "defined": {
+                "name": "bookingCharges"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaignBookingAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "booking",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaignBookingCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "booking",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "refundedAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaignBookingCancelledByProvider",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "booking",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "providerCancelReason"
+              }
+            }
+          },
+          {
+            "name": "refundedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "compensation",
+            "type": "u64"
+          },
+          {
+            "name": "cancellationCount",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaignBookingCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "booking",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "slotCount",
+            "type": "u32"
+          },
+          {
+            "name": "totalPrice",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "bookingStatus"
+              }
+            }
+          },
+          {
+            "name": "order",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaignBookingExtended",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "booking",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "addedSlots",
+            "type": "u32"
+          },
+          {
+            "name": "chargedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "rangeStartTs",
+            "type": "i64"
+          },
+          {
+            "name": "rangeEndTs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaignBookingRejected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "booking",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "refundedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "expired",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaignBookingRescheduled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "booking",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "releasedSlots",
+            "type": "u32"
+          },
+          {
+            "name": "addedSlots",
+            "type": "u32"
+          },
+          {
+            "name": "refundedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "chargedAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "campaignBookingSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "booking",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "impressions",
+            "type": "u64"
+          },
+          {
+            "name": "settledAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "agency",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "agencyCommission",
+            "type": "u64"
+          },
+          {
+            "name": "referralAmount",
+            "type": "u64"
+          },
+          {
+            "name": "refundedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "permissionless",
+            "docs": [
+              "Settled by the grace-period crank rather than the oracle."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "name": "campaignUpdated",
-      "discriminator": [
-        110,
-        209,
-        206,
-        190,
-        205,
-        2,
-        234,
-        81
-      ]
+      "name": "campaignBookingSlotsCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "booking",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledSlots",
+            "type": "u32"
+          },
+          {
+            "name": "remainingSlots",
+            "type": "u32"
+          },
+          {
+            "name": "refundedAmount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "locationRegistered",
-      "discriminator": [
-        43,
-        158,
-        200,
-        223,
-        220,
-        110,
-        239,
-        162
-      ]
+      "name": "campaignClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "name": "locationScheduleCreated",
-      "discriminator": [
-        65,
-        246,
-        199,
-        100,
-        199,
-        250,
-        46,
-        15
-      ]
+      "name": "campaignContentUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "contentCategories",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "name": "locationSlotAdded",
-      "discriminator": [
-        1,
-        157,
-        169,
-        220,
-        176,
-        31,
-        39,
-        191
-      ]
+      "name": "campaignCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "campaignIdx",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "locationUpdated",
-      "discriminator": [
-        161,
-        48,
-        186,
-        7,
-        3,
-        198,
-        31,
-        67
-      ]
+      "name": "campaignExpired",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "releasedBudget",
+            "type": "u64"
+          },
+          {
+            "name": "reservedBudget",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "soulboardConfigInitialized",
-      "discriminator": [
-        196,
-        254,
-        15,
-        131,
-        243,
-        166,
-        87,
-        33
-      ]
-    }
-  ],
-  "errors": [
+      "name": "campaignLocation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "advertiser",
+            "type": "pubkey"
+          },
+          {
+            "name": "provider",
+            "type": "pubkey"
+          },
+          {
+            "name": "oracleAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "device",
+            "docs": [
+              "Oracle device locked for the booking's lifetime."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "deviceAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "deviceIdx",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "campaignLocationStatus"
+              }
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "referrals",
+            "type": {
+              "defined": {
+                "name": "bookingReferrals"
+              }
+            }
+          },
+          {
+            "name": "charges",
+            "type": {
+              "defined": {
+                "name": "bookingCharges"
+              }
+            }
+          }
+        ]
+      }
+    },
     {
-      "code": 6000,
-      "name": "invalidAuthority",
-      "msg": "Invalid authority"
+      "name": "campaignLocationBooked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "oracleNotConfigured",
-      "msg": "Oracle authority not configured"
+      "name": "campaignLocationCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "invalidOracleAuthority",
-      "msg": "Invalid oracle authority"
+      "name": "campaignLocationSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "device",
+            "type": "pubkey"
+          },
+          {
+            "name": "settledAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "agency",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "agencyCommission",
+            "type": "u64"
+          },
+          {
+            "name": "referralAmount",
+            "type": "u64"
+          },
+          {
+            "name": "refundedAmount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "unauthorized",
-      "msg": "Unauthorized operation"
+      "name": "campaignLocationSpend",
+      "docs": [
+        "Running total booked by a campaign at one location, for the per-location pacing cap."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "location",
+            "type": "pubkey"
+          },
+          {
+            "name": "spent",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "campaignNotActive",
-      "msg": "Campaign is not active"
+      "name": "campaignLocationStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
+          },
+          {
+            "name": "cancelled"
+          },
+          {
+            "name": "settled"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "campaignHasActiveBookings",
-      "msg": "Campaign has active bookings"
+      "name": "campaignPacing",
+      "docs": [
+        "Advertiser-set spend limits checked when a range is booked. `None` disables a limit."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "period",
+            "type": {
+              "defined": {
+                "name": "pacingPeriod"
+              }
+            }
+          },
+          {
+            "name": "maxPerPeriod",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxPerBooking",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxPerLocation",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "insufficientBudget",
-      "msg": "Insufficient campaign budget"
+      "name": "campaignPacingUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "pacing",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "campaignPacing"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "locationUnavailable",
-      "msg": "Location is unavailable"
+      "name": "campaignStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
+          },
+          {
+            "name": "closed"
+          },
+          {
+            "name": "paused"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "locationInactive",
-      "msg": "Location is inactive"
+      "name": "campaignStatusChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "campaignStatus"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "locationAlreadyBooked",
-      "msg": "Location already booked"
+      "name": "campaignTargeting",
+      "docs": [
+        "Booking constraints on the locations a campaign may use. Empty or `None` rules match",
+        "everything; `allowed_categories` is a bitmask of `VenueCategory::mask` values."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowedCategories",
+            "type": "u16"
+          },
+          {
+            "name": "geo",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "geoBounds"
+                }
+              }
+            }
+          },
+          {
+            "name": "maxPricePerSlot",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "allowedProviders",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "deniedProviders",
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "invalidTimeRange",
-      "msg": "Invalid time range"
+      "name": "campaignTargetingUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "targeting",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "campaignTargeting"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "slotOverlap",
-      "msg": "Slot overlaps with existing slot"
+      "name": "campaignUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "slotUnavailable",
-      "msg": "Slot is unavailable"
+      "name": "campaignWindowUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "name": "startsAt",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "endsAt",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "slotNotFound",
-      "msg": "No slots found in range"
+      "name": "cancellationPolicy",
+      "docs": [
+        "How advertisers may drop individual slots from a booking at this location."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowPartial",
+            "type": "bool"
+          },
+          {
+            "name": "cutoffSecs",
+            "docs": [
+              "Slots starting within this many seconds can no longer be dropped."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "slotInPast",
-      "msg": "Slot time is in the past"
+      "name": "configAuthorityTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAuthority",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "scheduleFull",
-      "msg": "Schedule has reached maximum slots"
+      "name": "dayHours",
+      "docs": [
+        "Minutes since local midnight; a day with `open_minute == close_minute` is closed, and",
+        "one with `close_minute < open_minute` stays open past midnight into the next day."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "openMinute",
+            "type": "u16"
+          },
+          {
+            "name": "closeMinute",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "invalidOracleDevice",
-      "msg": "Invalid oracle device"
+      "name": "delegate",
+      "docs": [
+        "Scoped operator acting on behalf of an advertiser or provider authority."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "principal",
+            "docs": [
+              "`Advertiser` or `Provider` account the grant is scoped to."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Authority that made the grant; the grant lapses once the principal changes hands."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          },
+          {
+            "name": "permissions",
+            "type": "u16"
+          },
+          {
+            "name": "spendLimit",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "spent",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "oracleDeviceInactive",
-      "msg": "Oracle device inactive"
+      "name": "delegateRevoked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "delegateAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "bookingAlreadyExists",
-      "msg": "Booking already exists"
+      "name": "delegateUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "delegateAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          },
+          {
+            "name": "permissions",
+            "type": "u16"
+          },
+          {
+            "name": "spendLimit",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "bookingNotActive",
-      "msg": "Booking not active"
+      "name": "earningsWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "provider",
+            "type": "pubkey"
+          },
+          {
+            "name": "earnings",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "payout",
+            "type": "pubkey"
+          },
+          {
+            "name": "pending",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawn",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "settlementTooHigh",
-      "msg": "Settlement amount exceeds escrow"
+      "name": "fallbackSettlement",
+      "docs": [
+        "How permissionless settlement prices impression-based bookings."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "useMetrics"
+          },
+          {
+            "name": "refund"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "invalidParameters",
-      "msg": "Invalid parameters"
+      "name": "feeOverride",
+      "docs": [
+        "Admin-negotiated platform fee for one provider or location, optionally time-limited."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "target",
+            "type": "pubkey"
+          },
+          {
+            "name": "scope",
+            "type": {
+              "defined": {
+                "name": "feeOverrideScope"
+              }
+            }
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "invalidStringLength",
-      "msg": "Invalid string length"
+      "name": "feeOverrideRemoved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeOverride",
+            "type": "pubkey"
+          },
+          {
+            "name": "target",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "insufficientRent",
-      "msg": "Insufficient rent-exempt balance"
+      "name": "feeOverrideScope",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "provider"
+          },
+          {
+            "name": "location"
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "insufficientEarnings",
-      "msg": "Insufficient earnings"
+      "name": "feeOverrideSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeOverride",
+            "type": "pubkey"
+          },
+          {
+            "name": "target",
+            "type": "pubkey"
+          },
+          {
+            "name": "scope",
+            "type": {
+              "defined": {
+                "name": "feeOverrideScope"
+              }
+            }
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "expiresAt",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "arithmeticOverflow",
-      "msg": "Arithmetic overflow"
+      "name": "geoBounds",
+      "docs": [
+        "Inclusive latitude/longitude box, degrees scaled by 1e7."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minLatitudeE7",
+            "type": "i32"
+          },
+          {
+            "name": "maxLatitudeE7",
+            "type": "i32"
+          },
+          {
+            "name": "minLongitudeE7",
+            "type": "i32"
+          },
+          {
+            "name": "maxLongitudeE7",
+            "type": "i32"
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "arithmeticUnderflow",
-      "msg": "Arithmetic underflow"
-    }
-  ],
-  "types": [
-    {
-      "name": "advertiser",
+      "name": "location",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2806,244 +13460,446 @@ export type Soulboard = {
             "type": "pubkey"
           },
           {
-            "name": "lastCampaignId",
+            "name": "creator",
+            "docs": [
+              "Creator of the registering provider account, used for PDA seeds."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "provider",
+            "docs": [
+              "Provider account currently owning the location."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "locationIdx",
             "type": "u64"
           },
           {
-            "name": "campaignCount",
+            "name": "price",
             "type": "u64"
+          },
+          {
+            "name": "oracleAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "locationName",
+            "type": "string"
+          },
+          {
+            "name": "locationDescription",
+            "type": "string"
+          },
+          {
+            "name": "locationStatus",
+            "type": {
+              "defined": {
+                "name": "locationStatus"
+              }
+            }
+          },
+          {
+            "name": "profile",
+            "type": {
+              "defined": {
+                "name": "locationProfile"
+              }
+            }
+          },
+          {
+            "name": "operatingHours",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "operatingHours"
+                }
+              }
+            }
+          },
+          {
+            "name": "referral",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "referral"
+                }
+              }
+            }
+          },
+          {
+            "name": "hasPolicy",
+            "docs": [
+              "Set while a `LocationPolicy` exists; bookings must then supply it."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "requiresApproval",
+            "docs": [
+              "New bookings start `Pending` and must be accepted within `approval_window_secs`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "approvalWindowSecs",
+            "type": "u32"
+          },
+          {
+            "name": "cancellation",
+            "type": {
+              "defined": {
+                "name": "cancellationPolicy"
+              }
+            }
+          },
+          {
+            "name": "allowModifications",
+            "docs": [
+              "Lets advertisers extend or reschedule existing bookings."
+            ],
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "bookingStatus",
+      "name": "locationApprovalUpdated",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "active"
+            "name": "location",
+            "type": "pubkey"
           },
           {
-            "name": "cancelled"
+            "name": "requiresApproval",
+            "type": "bool"
           },
           {
-            "name": "settled"
+            "name": "approvalWindowSecs",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "budgetAdded",
+      "name": "locationCancellationUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "campaign",
+            "name": "location",
             "type": "pubkey"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "cancellation",
+            "type": {
+              "defined": {
+                "name": "cancellationPolicy"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "locationModificationsUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "location",
+            "type": "pubkey"
           },
           {
-            "name": "availableBudget",
-            "type": "u64"
+            "name": "allowModifications",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "budgetWithdrawn",
+      "name": "locationPolicy",
+      "docs": [
+        "Provider rules on who may book a location. Advertisers are identified by their",
+        "advertiser account address; an empty allow list admits everyone not denied."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "campaign",
+            "name": "location",
             "type": "pubkey"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "allowedAdvertisers",
+            "type": {
+              "vec": "pubkey"
+            }
           },
           {
-            "name": "availableBudget",
-            "type": "u64"
+            "name": "deniedAdvertisers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "forbiddenCategories",
+            "docs": [
+              "Bitmask of `ContentCategory::mask` values the venue refuses."
+            ],
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "campaign",
+      "name": "locationPolicyRemoved",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "location",
             "type": "pubkey"
           },
           {
-            "name": "campaignIdx",
-            "type": "u64"
+            "name": "policy",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "locationPolicyUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "location",
+            "type": "pubkey"
           },
           {
-            "name": "campaignName",
-            "type": "string"
+            "name": "policy",
+            "type": "pubkey"
           },
           {
-            "name": "campaignDescription",
-            "type": "string"
+            "name": "allowedAdvertisers",
+            "type": {
+              "vec": "pubkey"
+            }
           },
           {
-            "name": "campaignImageUrl",
-            "type": "string"
+            "name": "deniedAdvertisers",
+            "type": {
+              "vec": "pubkey"
+            }
           },
           {
-            "name": "status",
+            "name": "forbiddenCategories",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "locationProfile",
+      "docs": [
+        "Structured venue data for map search. Coordinates are degrees scaled by 1e7."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "latitudeE7",
+            "type": "i32"
+          },
+          {
+            "name": "longitudeE7",
+            "type": "i32"
+          },
+          {
+            "name": "venueCategory",
             "type": {
               "defined": {
-                "name": "campaignStatus"
+                "name": "venueCategory"
               }
             }
           },
           {
-            "name": "availableBudget",
-            "type": "u64"
-          },
-          {
-            "name": "reservedBudget",
-            "type": "u64"
+            "name": "dailyAudienceEstimate",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "campaignBooking",
+      "name": "locationRegistered",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "campaign",
+            "name": "location",
             "type": "pubkey"
           },
           {
-            "name": "location",
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "advertiser",
-            "type": "pubkey"
+            "name": "locationIdx",
+            "type": "u64"
           },
           {
-            "name": "provider",
+            "name": "profile",
+            "type": {
+              "defined": {
+                "name": "locationProfile"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "locationSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "location",
             "type": "pubkey"
           },
           {
-            "name": "oracleAuthority",
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "device",
-            "type": "pubkey"
+            "name": "maxSlots",
+            "type": "u32"
           },
           {
-            "name": "deviceAuthority",
+            "name": "slotCount",
+            "type": "u32"
+          },
+          {
+            "name": "slots",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "locationSlot"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "locationScheduleCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "schedule",
             "type": "pubkey"
           },
           {
-            "name": "deviceIdx",
-            "type": "u64"
+            "name": "location",
+            "type": "pubkey"
           },
           {
-            "name": "rangeStartTs",
-            "type": "i64"
+            "name": "authority",
+            "type": "pubkey"
           },
           {
-            "name": "rangeEndTs",
+            "name": "maxSlots",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "locationSlot",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startTs",
             "type": "i64"
           },
           {
-            "name": "slotCount",
-            "type": "u32"
+            "name": "endTs",
+            "type": "i64"
           },
           {
-            "name": "totalPrice",
+            "name": "price",
             "type": "u64"
           },
           {
-            "name": "pricingModel",
+            "name": "status",
             "type": {
               "defined": {
-                "name": "pricingModel"
+                "name": "slotStatus"
               }
             }
           },
           {
-            "name": "startImpressions",
-            "type": "u64"
-          },
+            "name": "booking",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "locationSlotAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "status",
-            "type": {
-              "defined": {
-                "name": "bookingStatus"
-              }
-            }
+            "name": "schedule",
+            "type": "pubkey"
           },
           {
-            "name": "createdAt",
+            "name": "startTs",
             "type": "i64"
           },
           {
-            "name": "updatedAt",
+            "name": "endTs",
             "type": "i64"
           },
           {
-            "name": "impressions",
-            "type": "u64"
-          },
-          {
-            "name": "settledAmount",
-            "type": "u64"
-          },
-          {
-            "name": "feeAmount",
+            "name": "price",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "campaignBookingCancelled",
+      "name": "locationSlotHoldsReleased",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "booking",
-            "type": "pubkey"
-          },
-          {
-            "name": "campaign",
-            "type": "pubkey"
-          },
-          {
-            "name": "location",
+            "name": "schedule",
             "type": "pubkey"
           },
           {
-            "name": "refundedAmount",
-            "type": "u64"
+            "name": "slotCount",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "campaignBookingCreated",
+      "name": "locationSlotsHeld",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "booking",
+            "name": "schedule",
             "type": "pubkey"
           },
           {
@@ -3051,285 +13907,350 @@ export type Soulboard = {
             "type": "pubkey"
           },
           {
-            "name": "location",
-            "type": "pubkey"
+            "name": "rangeStartTs",
+            "type": "i64"
+          },
+          {
+            "name": "rangeEndTs",
+            "type": "i64"
           },
           {
             "name": "slotCount",
             "type": "u32"
           },
           {
-            "name": "totalPrice",
-            "type": "u64"
+            "name": "expiresAt",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "campaignBookingSettled",
+      "name": "locationStatus",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "booking",
-            "type": "pubkey"
+            "name": "available"
           },
           {
-            "name": "campaign",
-            "type": "pubkey"
+            "name": "booked",
+            "fields": [
+              {
+                "name": "campaign",
+                "type": "pubkey"
+              }
+            ]
           },
+          {
+            "name": "inactive"
+          }
+        ]
+      }
+    },
+    {
+      "name": "locationTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "location",
             "type": "pubkey"
           },
           {
-            "name": "impressions",
-            "type": "u64"
-          },
-          {
-            "name": "settledAmount",
-            "type": "u64"
+            "name": "previousProvider",
+            "type": "pubkey"
           },
           {
-            "name": "feeAmount",
-            "type": "u64"
+            "name": "newProvider",
+            "type": "pubkey"
           },
           {
-            "name": "refundedAmount",
-            "type": "u64"
+            "name": "authority",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "campaignClosed",
+      "name": "locationUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "campaign",
-            "type": "pubkey"
-          },
-          {
-            "name": "authority",
+            "name": "location",
             "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "campaignCreated",
+      "name": "operatingHours",
+      "docs": [
+        "Weekly opening hours in venue-local time, Monday first."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "campaign",
-            "type": "pubkey"
+            "name": "utcOffsetMinutes",
+            "type": "i16"
           },
           {
-            "name": "authority",
-            "type": "pubkey"
+            "name": "days",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "dayHours"
+                  }
+                },
+                7
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pacingPeriod",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "daily"
           },
           {
-            "name": "campaignIdx",
-            "type": "u64"
+            "name": "weekly"
           }
         ]
       }
     },
     {
-      "name": "campaignLocation",
+      "name": "pricingModel",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "campaign",
-            "type": "pubkey"
+            "name": "timeSlot"
           },
           {
-            "name": "location",
-            "type": "pubkey"
+            "name": "perImpression",
+            "fields": [
+              {
+                "name": "price",
+                "type": "u64"
+              }
+            ]
           },
           {
-            "name": "advertiser",
-            "type": "pubkey"
-          },
+            "name": "cpm",
+            "fields": [
+              {
+                "name": "price",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "provider",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "provider",
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "oracleAuthority",
+            "name": "creator",
+            "docs": [
+              "Original authority; PDA seeds stay bound to it across ownership transfers."
+            ],
             "type": "pubkey"
           },
           {
-            "name": "price",
+            "name": "lastLocationId",
             "type": "u64"
           },
           {
-            "name": "status",
-            "type": {
-              "defined": {
-                "name": "campaignLocationStatus"
-              }
-            }
-          },
-          {
-            "name": "createdAt",
-            "type": "i64"
-          },
-          {
-            "name": "updatedAt",
-            "type": "i64"
+            "name": "locationCount",
+            "type": "u64"
           },
           {
-            "name": "settledAmount",
-            "type": "u64"
+            "name": "cancellationCount",
+            "docs": [
+              "Bookings the provider has cancelled; a reliability signal for advertisers."
+            ],
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "campaignLocationBooked",
+      "name": "providerCancelCompensationUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "campaign",
-            "type": "pubkey"
-          },
-          {
-            "name": "location",
+            "name": "config",
             "type": "pubkey"
           },
           {
-            "name": "price",
-            "type": "u64"
+            "name": "compensationBps",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "campaignLocationCancelled",
+      "name": "providerCancelReason",
+      "docs": [
+        "Why a provider backed out of a booking."
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "campaign",
-            "type": "pubkey"
+            "name": "screenFailure"
           },
           {
-            "name": "location",
-            "type": "pubkey"
+            "name": "venueClosed"
           },
           {
-            "name": "price",
-            "type": "u64"
+            "name": "maintenance"
+          },
+          {
+            "name": "other"
           }
         ]
       }
     },
     {
-      "name": "campaignLocationSettled",
+      "name": "providerEarnings",
+      "docs": [
+        "Program-owned vault holding a provider's settled earnings until they are withdrawn."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "campaign",
+            "name": "provider",
             "type": "pubkey"
           },
           {
-            "name": "location",
-            "type": "pubkey"
+            "name": "pending",
+            "type": "u64"
           },
           {
-            "name": "settledAmount",
+            "name": "withdrawn",
             "type": "u64"
           },
           {
-            "name": "refundedAmount",
+            "name": "lifetime",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "campaignLocationStatus",
+      "name": "providerTransferred",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "active"
+            "name": "provider",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
           },
           {
-            "name": "cancelled"
+            "name": "newAuthority",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referral",
+      "docs": [
+        "Affiliate paid a share of each settlement; `bps` is capped by `max_referral_bps`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "type": "pubkey"
           },
           {
-            "name": "settled"
+            "name": "bps",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "campaignStatus",
+      "name": "referralFeeMode",
+      "docs": [
+        "Whether referral payouts are carved out of the platform fee or charged in addition to it."
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "active"
+            "name": "fromPlatformFee"
           },
           {
-            "name": "closed"
+            "name": "onTopOfFee"
           }
         ]
       }
     },
     {
-      "name": "campaignUpdated",
+      "name": "referralUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "campaign",
+            "name": "target",
             "type": "pubkey"
+          },
+          {
+            "name": "referral",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "referral"
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "location",
+      "name": "settlementFallbackUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
-            "type": "pubkey"
-          },
-          {
-            "name": "locationIdx",
-            "type": "u64"
-          },
-          {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "oracleAuthority",
+            "name": "config",
             "type": "pubkey"
           },
           {
-            "name": "locationName",
-            "type": "string"
-          },
-          {
-            "name": "locationDescription",
-            "type": "string"
+            "name": "settlementGraceSecs",
+            "type": "u32"
           },
           {
-            "name": "locationStatus",
+            "name": "fallbackSettlement",
             "type": {
               "defined": {
-                "name": "locationStatus"
+                "name": "fallbackSettlement"
               }
             }
           }
@@ -3337,283 +14258,276 @@ export type Soulboard = {
       }
     },
     {
-      "name": "locationRegistered",
+      "name": "slotStatus",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "location",
-            "type": "pubkey"
+            "name": "available"
           },
           {
-            "name": "authority",
-            "type": "pubkey"
+            "name": "booked"
           },
           {
-            "name": "locationIdx",
-            "type": "u64"
+            "name": "cancelled"
+          },
+          {
+            "name": "settled"
+          },
+          {
+            "name": "held",
+            "fields": [
+              {
+                "name": "campaign",
+                "type": "pubkey"
+              },
+              {
+                "name": "expiresAt",
+                "type": "i64"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "locationSchedule",
+      "name": "soulboardConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "location",
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "authority",
+            "name": "treasury",
             "type": "pubkey"
           },
           {
-            "name": "maxSlots",
-            "type": "u32"
+            "name": "feeBps",
+            "type": "u16"
           },
           {
-            "name": "slotCount",
+            "name": "maxReferralBps",
+            "type": "u16"
+          },
+          {
+            "name": "referralFeeMode",
+            "type": {
+              "defined": {
+                "name": "referralFeeMode"
+              }
+            }
+          },
+          {
+            "name": "settlementGraceSecs",
+            "docs": [
+              "Anyone may settle a booking this long after `range_end_ts` without the oracle."
+            ],
             "type": "u32"
           },
           {
-            "name": "slots",
+            "name": "fallbackSettlement",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "locationSlot"
-                }
+              "defined": {
+                "name": "fallbackSettlement"
               }
             }
+          },
+          {
+            "name": "providerCancelCompensationBps",
+            "docs": [
+              "Share of a booking's price a provider pays the campaign when cancelling it."
+            ],
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "locationScheduleCreated",
+      "name": "soulboardConfigInitialized",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "schedule",
+            "name": "config",
             "type": "pubkey"
           },
           {
-            "name": "location",
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "authority",
+            "name": "treasury",
             "type": "pubkey"
           },
           {
-            "name": "maxSlots",
-            "type": "u32"
+            "name": "feeBps",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "locationSlot",
+      "name": "soulboardConfigUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "startTs",
-            "type": "i64"
-          },
-          {
-            "name": "endTs",
-            "type": "i64"
+            "name": "config",
+            "type": "pubkey"
           },
           {
-            "name": "price",
-            "type": "u64"
+            "name": "maxReferralBps",
+            "type": "u16"
           },
           {
-            "name": "status",
+            "name": "referralFeeMode",
             "type": {
               "defined": {
-                "name": "slotStatus"
+                "name": "referralFeeMode"
               }
             }
-          },
-          {
-            "name": "booking",
-            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "locationSlotAdded",
+      "name": "treasury",
+      "docs": [
+        "Program-owned fee vault. `periods` is a ring of the most recent fee periods."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "schedule",
-            "type": "pubkey"
-          },
-          {
-            "name": "startTs",
-            "type": "i64"
+            "name": "totalCollected",
+            "type": "u64"
           },
           {
-            "name": "endTs",
-            "type": "i64"
+            "name": "totalWithdrawn",
+            "type": "u64"
           },
           {
-            "name": "price",
-            "type": "u64"
+            "name": "periods",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "treasuryPeriod"
+                  }
+                },
+                12
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "locationStatus",
+      "name": "treasuryPeriod",
       "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "available"
-          },
+        "kind": "struct",
+        "fields": [
           {
-            "name": "booked",
-            "fields": [
-              {
-                "name": "campaign",
-                "type": "pubkey"
-              }
-            ]
+            "name": "period",
+            "type": "u64"
           },
           {
-            "name": "inactive"
+            "name": "collected",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "locationUpdated",
+      "name": "treasurySplit",
+      "docs": [
+        "Share of a treasury withdrawal paid to one beneficiary."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "location",
+            "name": "beneficiary",
             "type": "pubkey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "pricingModel",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "timeSlot"
-          },
-          {
-            "name": "perImpression",
-            "fields": [
-              {
-                "name": "price",
-                "type": "u64"
-              }
-            ]
           },
           {
-            "name": "cpm",
-            "fields": [
-              {
-                "name": "price",
-                "type": "u64"
-              }
-            ]
+            "name": "bps",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "provider",
+      "name": "treasuryWithdrawn",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "treasury",
             "type": "pubkey"
           },
           {
-            "name": "lastLocationId",
+            "name": "amount",
             "type": "u64"
           },
           {
-            "name": "locationCount",
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "recipientAmount",
+            "type": "u64"
+          },
+          {
+            "name": "splits",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "treasurySplit"
+                }
+              }
+            }
+          },
+          {
+            "name": "totalWithdrawn",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "slotStatus",
+      "name": "venueCategory",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "available"
+            "name": "retail"
           },
           {
-            "name": "booked"
+            "name": "transit"
           },
           {
-            "name": "cancelled"
+            "name": "restaurant"
           },
           {
-            "name": "settled"
-          }
-        ]
-      }
-    },
-    {
-      "name": "soulboardConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authority",
-            "type": "pubkey"
+            "name": "entertainment"
           },
           {
-            "name": "treasury",
-            "type": "pubkey"
+            "name": "office"
           },
           {
-            "name": "feeBps",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "soulboardConfigInitialized",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "config",
-            "type": "pubkey"
+            "name": "education"
           },
           {
-            "name": "authority",
-            "type": "pubkey"
+            "name": "healthcare"
           },
           {
-            "name": "treasury",
-            "type": "pubkey"
+            "name": "outdoor"
           },
           {
-            "name": "feeBps",
-            "type": "u16"
+            "name": "other"
           }
         ]
       }
@@ -9,6 +9,7 @@ import {
   LAMPORTS_PER_SOL,
 } from "@solana/web3.js";
 import BN from "bn.js";
+import * as fs from "fs";
 
 describe("soul_board_oracle", () => {
   const provider = anchor.AnchorProvider.env();
@@ -275,4 +276,235 @@ describe("soul_board_oracle", () => {
       "DeviceInactive"
     );
   });
+
+  it("tracks heartbeats and marks stale devices offline", async () => {
+    const { authority, oracleAuthority, deviceIdx, devicePda } =
+      await setupDevice();
+
+    let device = await program.account.device.fetch(devicePda);
+    expect(device.heartbeat.lastHeartbeatAt.toNumber()).to.equal(0);
+    expect(device.heartbeat.staleAfterSecs).to.equal(300);
+
+    await program.methods
+      .recordDeviceHeartbeat(deviceIdx)
+      .accounts({
+        device: devicePda,
+        deviceAuthority: authority.publicKey,
+        oracleAuthority: oracleAuthority.publicKey,
+      })
+      .signers([oracleAuthority])
+      .rpc();
+
+    device = await program.account.device.fetch(devicePda);
+    const firstBeat = device.heartbeat.lastHeartbeatAt.toNumber();
+    expect(firstBeat).to.be.greaterThan(0);
+    expect(device.heartbeat.onlineSince.toNumber()).to.equal(firstBeat);
+
+    await program.methods
+      .setDeviceStaleAfter(deviceIdx, 60)
+      .accounts({
+        device: devicePda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    await expectAnchorError(
+      program.methods
+        .setDeviceStaleAfter(deviceIdx, 0)
+        .accounts({
+          device: devicePda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc(),
+      "InvalidParameters"
+    );
+
+    await program.methods
+      .reportDeviceMetrics(deviceIdx, new BN(0), new BN(5))
+      .accounts({
+        device: devicePda,
+        deviceAuthority: authority.publicKey,
+        oracleAuthority: oracleAuthority.publicKey,
+      })
+      .signers([oracleAuthority])
+      .rpc();
+
+    device = await program.account.device.fetch(devicePda);
+    expect(device.heartbeat.staleAfterSecs).to.equal(60);
+    expect(device.heartbeat.onlineSince.toNumber()).to.equal(firstBeat);
+    expect(device.heartbeat.lastHeartbeatAt.toNumber()).to.be.at.least(
+      firstBeat
+    );
+  });
+
+  it("stores and validates device metadata", async () => {
+    const { authority, deviceIdx, devicePda } = await setupDevice();
+    const [metadataPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("device_metadata"), devicePda.toBuffer()],
+      program.programId
+    );
+
+    const specs = {
+      resolutionWidth: 1920,
+      resolutionHeight: 1080,
+      orientation: { landscape: {} },
+      widthMm: 1210,
+      heightMm: 680,
+      supportedMimeTypes: ["image/png", "video/mp4"],
+      firmwareVersion: "2.4.1",
+      latitudeE7: 407_580_000,
+      longitudeE7: -739_855_000,
+    };
+
+    await program.methods
+      .setDeviceMetadata(deviceIdx, specs)
+      .accounts({
+        authority: authority.publicKey,
+        device: devicePda,
+        metadata: metadataPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    await program.methods
+      .setDeviceMetadata(deviceIdx, { ...specs, firmwareVersion: "2.5.0" })
+      .accounts({
+        authority: authority.publicKey,
+        device: devicePda,
+        metadata: metadataPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    const metadata = await program.account.deviceMetadata.fetch(metadataPda);
+    expect(metadata.device.toBase58()).to.equal(devicePda.toBase58());
+    expect(metadata.specs.firmwareVersion).to.equal("2.5.0");
+    expect(metadata.specs.supportedMimeTypes).to.deep.equal([
+      "image/png",
+      "video/mp4",
+    ]);
+    expect(metadata.specs.orientation).to.have.property("landscape");
+
+    await expectAnchorError(
+      program.methods
+        .setDeviceMetadata(deviceIdx, { ...specs, latitudeE7: 950_000_000 })
+        .accounts({
+          authority: authority.publicKey,
+          device: devicePda,
+          metadata: metadataPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc(),
+      "InvalidCoordinates"
+    );
+  });
+
+  it("closes a device and its registry", async () => {
+    const { authority, registryPda, deviceIdx, devicePda } =
+      await setupDevice();
+    const [metadataPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("device_metadata"), devicePda.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .setDeviceMetadata(deviceIdx, {
+        resolutionWidth: 1080,
+        resolutionHeight: 1920,
+        orientation: { portrait: {} },
+        widthMm: 680,
+        heightMm: 1210,
+        supportedMimeTypes: ["image/png"],
+        firmwareVersion: "1.0.0",
+        latitudeE7: 0,
+        longitudeE7: 0,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        device: devicePda,
+        metadata: metadataPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    await expectAnchorError(
+      program.methods
+        .closeRegistry()
+        .accounts({
+          authority: authority.publicKey,
+          registry: registryPda,
+        })
+        .signers([authority])
+        .rpc(),
+      "RegistryHasDevices"
+    );
+
+    await program.methods
+      .closeDevice(deviceIdx)
+      .accounts({
+        authority: authority.publicKey,
+        registry: registryPda,
+        device: devicePda,
+        metadata: metadataPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const registry = await program.account.deviceRegistry.fetch(registryPda);
+    expect(registry.deviceCount.toNumber()).to.equal(0);
+    expect(registry.lastDeviceId.toNumber()).to.equal(1);
+    expect(await connection.getAccountInfo(devicePda)).to.be.null;
+    expect(await connection.getAccountInfo(metadataPda)).to.be.null;
+
+    await program.methods
+      .closeRegistry()
+      .accounts({
+        authority: authority.publicKey,
+        registry: registryPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    expect(await connection.getAccountInfo(registryPda)).to.be.null;
+  });
+
+  it("migrates a device registered before the upgrade", async () => {
+    // Loaded from tests/fixtures in its pre-upgrade layout.
+    const authority = Keypair.fromSecretKey(
+      Uint8Array.from(
+        JSON.parse(
+          fs.readFileSync("tests/fixtures/legacy-device-authority.json", "utf8")
+        )
+      )
+    );
+    await airdropTo(authority.publicKey);
+    const devicePda = deriveDevicePda(authority.publicKey, new BN(0));
+    const migrate = () =>
+      program.methods
+        .migrateDevice(new BN(0))
+        .accounts({
+          device: devicePda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+    await migrate();
+
+    const device = await program.account.device.fetch(devicePda);
+    expect(device.authority.toBase58()).to.equal(
+      authority.publicKey.toBase58()
+    );
+    expect(device.metrics.totalImpressions.toNumber()).to.equal(800);
+    expect(device.activeBookings).to.equal(0);
+    expect(device.heartbeat.lastHeartbeatAt.toNumber()).to.equal(0);
+
+    await expectAnchorError(migrate(), "AccountAlreadyMigrated");
+  });
 });
@@ -10,6 +10,7 @@ import {
   LAMPORTS_PER_SOL,
 } from "@solana/web3.js";
 import BN from "bn.js";
+import * as fs from "fs";
 
 describe("soulboard", () => {
   const provider = anchor.AnchorProvider.env();
@@ -85,6 +86,69 @@ describe("soulboard", () => {
       program.programId
     )[0];
 
+  const deriveDelegatePda = (principal: PublicKey, delegate: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("delegate"), principal.toBuffer(), delegate.toBuffer()],
+      program.programId
+    )[0];
+
+  const deriveAgencyPda = (authority: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("agency"), authority.toBuffer()],
+      program.programId
+    )[0];
+
+  const deriveFeeOverridePda = (target: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_override"), target.toBuffer()],
+      program.programId
+    )[0];
+
+  const deriveEarningsPda = (providerPda: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("provider_earnings"), providerPda.toBuffer()],
+      program.programId
+    )[0];
+
+  const fetchPendingEarnings = async (providerPda: PublicKey) =>
+    (
+      await program.account.providerEarnings.fetch(deriveEarningsPda(providerPda))
+    ).pending.toNumber();
+
+  const deriveLocationSpendPda = (campaign: PublicKey, location: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign_location_spend"),
+        campaign.toBuffer(),
+        location.toBuffer(),
+      ],
+      program.programId
+    )[0];
+
+  const deriveLocationPolicyPda = (location: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("location_policy"), location.toBuffer()],
+      program.programId
+    )[0];
+
+  const deriveBookingOrderPda = (campaign: PublicKey, orderId: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("booking_order"), campaign.toBuffer(), u64(orderId)],
+      program.programId
+    )[0];
+
+  const deriveOrderBookingPda = (order: PublicKey, location: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("campaign_booking"), order.toBuffer(), location.toBuffer()],
+      program.programId
+    )[0];
+
+  const deriveTreasuryPda = () =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury")],
+      program.programId
+    )[0];
+
   const deriveConfigPda = () =>
     PublicKey.findProgramAddressSync(
       [Buffer.from("soulboard_config")],
@@ -154,6 +218,7 @@ describe("soulboard", () => {
       .accounts({
         authority: providerKeypair.publicKey,
         provider: providerPda,
+        earnings: deriveEarningsPda(providerPda),
         systemProgram: SystemProgram.programId,
       })
       .signers([providerKeypair])
@@ -208,12 +273,20 @@ describe("soulboard", () => {
     return new BN(providerAccount.lastLocationId.toString());
   };
 
+  const defaultLocationProfile = {
+    latitudeE7: 407_580_000,
+    longitudeE7: -739_855_000,
+    venueCategory: { retail: {} },
+    dailyAudienceEstimate: 12_000,
+  };
+
   const registerLocation = async (
     provider: Keypair,
     providerPda: PublicKey,
     price: BN,
     oracleAuthority: PublicKey,
-    locationIdx?: BN
+    locationIdx?: BN,
+    profile = defaultLocationProfile
   ) => {
     const nextIdx = locationIdx ?? (await getNextLocationIdx(providerPda));
     const locationPda = deriveLocationPda(provider.publicKey, nextIdx);
@@ -223,7 +296,8 @@ describe("soulboard", () => {
         "Location A",
         "High traffic area",
         price,
-        oracleAuthority
+        oracleAuthority,
+        profile
       )
       .accounts({
         authority: provider.publicKey,
@@ -237,33 +311,57 @@ describe("soulboard", () => {
     return { locationIdx: nextIdx, locationPda };
   };
 
+  let configAuthority: Keypair | null = null;
+
+  // The validator preloads the config from tests/fixtures in its pre-upgrade layout, so
+  // the first caller migrates it; without the fixture it is initialized instead.
+  const legacyConfigAuthority = Keypair.fromSecretKey(
+    Uint8Array.from(
+      JSON.parse(
+        fs.readFileSync("tests/fixtures/legacy-config-authority.json", "utf8")
+      )
+    )
+  );
+
   const ensureConfig = async (authority: Keypair) => {
     const configPda = deriveConfigPda();
+    const treasury = deriveTreasuryPda();
     try {
-      const configAccount = await program.account.soulboardConfig.fetch(
-        configPda
-      );
-      await airdropTo(configAccount.treasury, 1);
-      return { configPda, treasury: configAccount.treasury };
+      await program.account.soulboardConfig.fetch(configPda);
     } catch (error) {
-      const treasury = Keypair.generate();
-      await airdropTo(treasury.publicKey, 1);
+      if (await connection.getAccountInfo(configPda)) {
+        await airdropTo(legacyConfigAuthority.publicKey);
+        await program.methods
+          .migrateConfig()
+          .accounts({
+            config: configPda,
+            treasury,
+            authority: legacyConfigAuthority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([legacyConfigAuthority])
+          .rpc();
+        configAuthority = legacyConfigAuthority;
+        return { configPda, treasury };
+      }
       await program.methods
-        .initializeConfig(treasury.publicKey)
+        .initializeConfig()
         .accounts({
           authority: authority.publicKey,
           config: configPda,
+          treasury,
           systemProgram: SystemProgram.programId,
         })
         .signers([authority])
         .rpc();
-      return { configPda, treasury: treasury.publicKey };
+      configAuthority = authority;
     }
+    return { configPda, treasury };
   };
 
   const createOracleDevice = async (
     authority: Keypair,
-    oracleAuthority: PublicKey,
+    oracle: Keypair,
     location: PublicKey
   ) => {
     const registryPda = deriveDeviceRegistryPda(authority.publicKey);
@@ -291,7 +389,7 @@ describe("soulboard", () => {
     const devicePda = deriveOracleDevicePda(authority.publicKey, deviceIdx);
 
     await oracleProgram.methods
-      .registerDevice(location, oracleAuthority)
+      .registerDevice(location, oracle.publicKey)
       .accounts({
         authority: authority.publicKey,
         registry: registryPda,
@@ -301,6 +399,16 @@ describe("soulboard", () => {
       .signers([authority])
       .rpc();
 
+    await oracleProgram.methods
+      .recordDeviceHeartbeat(deviceIdx)
+      .accounts({
+        device: devicePda,
+        deviceAuthority: authority.publicKey,
+        oracleAuthority: oracle.publicKey,
+      })
+      .signers([oracle])
+      .rpc();
+
     return { deviceIdx, devicePda, registryPda };
   };
 
@@ -323,6 +431,127 @@ describe("soulboard", () => {
       .rpc();
   };
 
+  const createScheduleWithSlots = async (
+    provider: Keypair,
+    providerPda: PublicKey,
+    locationIdx: BN,
+    locationPda: PublicKey,
+    slots: { start: BN; end: BN; price: BN }[],
+    maxSlots = 10
+  ) => {
+    const schedulePda = deriveLocationSchedulePda(locationPda);
+    await program.methods
+      .createLocationSchedule(locationIdx, maxSlots)
+      .accounts({
+        authority: provider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([provider])
+      .rpc();
+
+    for (const slot of slots) {
+      await program.methods
+        .addLocationSlot(locationIdx, slot.start, slot.end, slot.price, false)
+        .accounts({
+          authority: provider.publicKey,
+          provider: providerPda,
+          location: locationPda,
+          schedule: schedulePda,
+        })
+        .signers([provider])
+        .rpc();
+    }
+
+    return schedulePda;
+  };
+
+  const setupBookableLocation = async (slotPrice: BN, slotCount = 1) => {
+    const actors = await setupActors();
+    const { advertiser, advertiserPda, provider, providerPda, oracle } = actors;
+    const { campaignIdx, campaignPda } = await createCampaign(
+      advertiser,
+      advertiserPda,
+      new BN(1 * LAMPORTS_PER_SOL)
+    );
+    const { locationIdx, locationPda } = await registerLocation(
+      provider,
+      providerPda,
+      slotPrice,
+      oracle.publicKey
+    );
+    const now = Math.floor(Date.now() / 1000);
+    const slots = Array.from({ length: slotCount }, (_, i) => ({
+      start: new BN(now + 3600 + i * 1800),
+      end: new BN(now + 5400 + i * 1800),
+      price: slotPrice,
+    }));
+    const schedulePda = await createScheduleWithSlots(
+      provider,
+      providerPda,
+      locationIdx,
+      locationPda,
+      slots
+    );
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
+    return {
+      ...actors,
+      campaignIdx,
+      campaignPda,
+      locationIdx,
+      locationPda,
+      schedulePda,
+      deviceIdx,
+      devicePda,
+      slots,
+    };
+  };
+
+  const bookRange = async (
+    ctx: Awaited<ReturnType<typeof setupBookableLocation>>,
+    rangeStart: BN,
+    rangeEnd: BN,
+    pricing: any = { timeSlot: {} },
+    extraAccounts: Record<string, PublicKey | null> = {}
+  ) => {
+    const bookingPda = deriveCampaignBookingPda(
+      ctx.campaignPda,
+      ctx.locationPda,
+      rangeStart,
+      rangeEnd
+    );
+    await program.methods
+      .bookLocationRange(
+        ctx.campaignIdx,
+        ctx.locationIdx,
+        rangeStart,
+        rangeEnd,
+        ctx.deviceIdx,
+        pricing
+      )
+      .accounts({
+        authority: ctx.advertiser.publicKey,
+        campaign: ctx.campaignPda,
+        provider: ctx.providerPda,
+        location: ctx.locationPda,
+        schedule: ctx.schedulePda,
+        booking: bookingPda,
+        oracleDevice: ctx.devicePda,
+        deviceAuthority: ctx.provider.publicKey,
+        systemProgram: SystemProgram.programId,
+        ...extraAccounts,
+      })
+      .signers([ctx.advertiser])
+      .rpc();
+    return bookingPda;
+  };
+
   it("creates advertiser/provider and campaign metadata", async () => {
     const { advertiser, advertiserPda, provider, providerPda } =
       await setupActors();
@@ -387,6 +616,7 @@ describe("soulboard", () => {
       .accounts({
         authority: advertiser.publicKey,
         campaign: campaignPda,
+        owner: advertiser.publicKey,
       })
       .signers([advertiser])
       .rpc();
@@ -403,6 +633,7 @@ describe("soulboard", () => {
         .accounts({
           authority: advertiser.publicKey,
           campaign: campaignPda,
+          owner: advertiser.publicKey,
         })
         .signers([advertiser])
         .rpc(),
@@ -421,7 +652,10 @@ describe("soulboard", () => {
     );
 
     await program.methods
-      .updateLocationDetails(locationIdx, "Location B", null)
+      .updateLocationDetails(locationIdx, "Location B", null, {
+        ...defaultLocationProfile,
+        venueCategory: { transit: {} },
+      })
       .accounts({
         authority: provider.publicKey,
         provider: providerPda,
@@ -452,6 +686,10 @@ describe("soulboard", () => {
 
     const location = await program.account.location.fetch(locationPda);
     expect(location.locationName).to.equal("Location B");
+    expect(location.profile.latitudeE7).to.equal(
+      defaultLocationProfile.latitudeE7
+    );
+    expect(location.profile.venueCategory).to.have.property("transit");
     expect(location.price.toString()).to.equal("750000");
     expect(location.locationStatus).to.have.property("inactive");
   });
@@ -468,7 +706,8 @@ describe("soulboard", () => {
           "Location Missing Oracle",
           "No oracle configured",
           price,
-          ZERO_PUBKEY
+          ZERO_PUBKEY,
+          defaultLocationProfile
         )
         .accounts({
           authority: provider.publicKey,
@@ -482,6 +721,32 @@ describe("soulboard", () => {
     );
   });
 
+  it("rejects out-of-range location coordinates", async () => {
+    const { provider, providerPda, oracle } = await setupActors();
+    const locationIdx = await getNextLocationIdx(providerPda);
+    const locationPda = deriveLocationPda(provider.publicKey, locationIdx);
+
+    await expectAnchorError(
+      program.methods
+        .registerLocation(
+          "Location Off Map",
+          "Bad coordinates",
+          new BN(1),
+          oracle.publicKey,
+          { ...defaultLocationProfile, longitudeE7: 1_800_000_001 }
+        )
+        .accounts({
+          authority: provider.publicKey,
+          provider: providerPda,
+          location: locationPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([provider])
+        .rpc(),
+      "InvalidCoordinates"
+    );
+  });
+
   it("rejects booked status via setLocationStatus", async () => {
     const { advertiser, advertiserPda, provider, providerPda, oracle } =
       await setupActors();
@@ -529,6 +794,12 @@ describe("soulboard", () => {
       price,
       oracle.publicKey
     );
+    const { configPda, treasury } = await ensureConfig(provider);
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
     const campaignLocationPda = deriveCampaignLocationPda(
       campaignPda,
       locationPda
@@ -537,13 +808,15 @@ describe("soulboard", () => {
     const balanceBefore = await connection.getBalance(campaignPda);
 
     await program.methods
-      .addCampaignLocation(campaignIdx, locationIdx)
+      .addCampaignLocation(campaignIdx, locationIdx, deviceIdx)
       .accounts({
         authority: advertiser.publicKey,
         campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
         campaignLocation: campaignLocationPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([advertiser])
@@ -569,19 +842,27 @@ describe("soulboard", () => {
     );
     expect(locationAfterBooking.locationStatus).to.have.property("booked");
     expect(bookingAccount.status).to.have.property("active");
+    expect(
+      (await oracleProgram.account.device.fetch(devicePda)).activeBookings
+    ).to.equal(1);
 
     const settlementAmount = new BN(300_000);
+    const config = await program.account.soulboardConfig.fetch(configPda);
+    const treasuryBalanceBefore = await connection.getBalance(treasury);
     await program.methods
       .settleCampaignLocation(
         campaignIdx,
         locationIdx,
-        advertiser.publicKey,
-        provider.publicKey,
-        settlementAmount
+        settlementAmount,
+        deviceIdx
       )
       .accounts({
+        config: configPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+        treasury,
         oracleAuthority: oracle.publicKey,
-        locationAuthority: provider.publicKey,
+        earnings: deriveEarningsPda(providerPda),
         campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
@@ -612,6 +893,12 @@ describe("soulboard", () => {
       "available"
     );
     expect(bookingAfterSettlementError).to.be.ok;
+    expect((await connection.getBalance(treasury)) - treasuryBalanceBefore).to.equal(
+      settlementAmount.muln(config.feeBps).divn(10000).toNumber()
+    );
+    expect(
+      (await oracleProgram.account.device.fetch(devicePda)).activeBookings
+    ).to.equal(0);
   });
 
   it("rejects settlement above booked price", async () => {
@@ -631,19 +918,27 @@ describe("soulboard", () => {
       price,
       oracle.publicKey
     );
+    const { configPda, treasury } = await ensureConfig(provider);
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
     const campaignLocationPda = deriveCampaignLocationPda(
       campaignPda,
       locationPda
     );
 
     await program.methods
-      .addCampaignLocation(campaignIdx, locationIdx)
+      .addCampaignLocation(campaignIdx, locationIdx, deviceIdx)
       .accounts({
         authority: advertiser.publicKey,
         campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
         campaignLocation: campaignLocationPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([advertiser])
@@ -654,13 +949,16 @@ describe("soulboard", () => {
         .settleCampaignLocation(
           campaignIdx,
           locationIdx,
-          advertiser.publicKey,
-          provider.publicKey,
-          price.add(new BN(1))
+          price.add(new BN(1)),
+          deviceIdx
         )
         .accounts({
+          config: configPda,
+          oracleDevice: devicePda,
+          deviceAuthority: provider.publicKey,
+          treasury,
           oracleAuthority: oracle.publicKey,
-          locationAuthority: provider.publicKey,
+          earnings: deriveEarningsPda(providerPda),
           campaign: campaignPda,
           provider: providerPda,
           location: locationPda,
@@ -688,19 +986,27 @@ describe("soulboard", () => {
       price,
       oracle.publicKey
     );
+    const { configPda, treasury } = await ensureConfig(provider);
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
     const campaignLocationPda = deriveCampaignLocationPda(
       campaignPda,
       locationPda
     );
 
     await program.methods
-      .addCampaignLocation(campaignIdx, locationIdx)
+      .addCampaignLocation(campaignIdx, locationIdx, deviceIdx)
       .accounts({
         authority: advertiser.publicKey,
         campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
         campaignLocation: campaignLocationPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([advertiser])
@@ -714,13 +1020,16 @@ describe("soulboard", () => {
         .settleCampaignLocation(
           campaignIdx,
           locationIdx,
-          advertiser.publicKey,
-          provider.publicKey,
-          new BN(100_000)
+          new BN(100_000),
+          deviceIdx
         )
         .accounts({
+          config: configPda,
+          oracleDevice: devicePda,
+          deviceAuthority: provider.publicKey,
+          treasury,
           oracleAuthority: wrongOracle.publicKey,
-          locationAuthority: provider.publicKey,
+          earnings: deriveEarningsPda(providerPda),
           campaign: campaignPda,
           provider: providerPda,
           location: locationPda,
@@ -748,39 +1057,49 @@ describe("soulboard", () => {
       price,
       oracle.publicKey
     );
+    const { configPda, treasury } = await ensureConfig(provider);
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
     const campaignLocationPda = deriveCampaignLocationPda(
       campaignPda,
       locationPda
     );
 
     await program.methods
-      .addCampaignLocation(campaignIdx, locationIdx)
+      .addCampaignLocation(campaignIdx, locationIdx, deviceIdx)
       .accounts({
         authority: advertiser.publicKey,
         campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
         campaignLocation: campaignLocationPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([advertiser])
       .rpc();
 
-    const wrongRecipient = Keypair.generate();
-    await airdropTo(wrongRecipient.publicKey);
+    const { providerPda: otherProviderPda } = await setupActors();
 
     await expectAnchorError(
       program.methods
         .settleCampaignLocation(
           campaignIdx,
           locationIdx,
-          advertiser.publicKey,
-          provider.publicKey,
-          new BN(100_000)
+          new BN(100_000),
+          deviceIdx
         )
         .accounts({
+          config: configPda,
+          oracleDevice: devicePda,
+          deviceAuthority: provider.publicKey,
+          treasury,
           oracleAuthority: oracle.publicKey,
-          locationAuthority: wrongRecipient.publicKey,
+          earnings: deriveEarningsPda(otherProviderPda),
           campaign: campaignPda,
           provider: providerPda,
           location: locationPda,
@@ -788,7 +1107,7 @@ describe("soulboard", () => {
         })
         .signers([oracle])
         .rpc(),
-      "InvalidAuthority"
+      "ConstraintSeeds"
     );
   });
 
@@ -808,6 +1127,11 @@ describe("soulboard", () => {
       price,
       oracle.publicKey
     );
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
 
     await program.methods
       .setLocationStatus(locationIdx, { inactive: {} })
@@ -826,13 +1150,15 @@ describe("soulboard", () => {
 
     await expectAnchorError(
       program.methods
-        .addCampaignLocation(campaignIdx, locationIdx)
+        .addCampaignLocation(campaignIdx, locationIdx, deviceIdx)
         .accounts({
           authority: advertiser.publicKey,
           campaign: campaignPda,
           provider: providerPda,
           location: locationPda,
           campaignLocation: campaignLocationPda,
+          oracleDevice: devicePda,
+          deviceAuthority: provider.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([advertiser])
@@ -856,6 +1182,11 @@ describe("soulboard", () => {
       price,
       oracle.publicKey
     );
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
 
     const firstCampaignLocationPda = deriveCampaignLocationPda(
       firstCampaignPda,
@@ -863,13 +1194,15 @@ describe("soulboard", () => {
     );
 
     await program.methods
-      .addCampaignLocation(firstCampaignIdx, locationIdx)
+      .addCampaignLocation(firstCampaignIdx, locationIdx, deviceIdx)
       .accounts({
         authority: advertiser.publicKey,
         campaign: firstCampaignPda,
         provider: providerPda,
         location: locationPda,
         campaignLocation: firstCampaignLocationPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([advertiser])
@@ -882,13 +1215,15 @@ describe("soulboard", () => {
 
     await expectAnchorError(
       program.methods
-        .addCampaignLocation(secondCampaignIdx, locationIdx)
+        .addCampaignLocation(secondCampaignIdx, locationIdx, deviceIdx)
         .accounts({
           authority: advertiser.publicKey,
           campaign: secondCampaignPda,
           provider: providerPda,
           location: locationPda,
           campaignLocation: secondCampaignLocationPda,
+          oracleDevice: devicePda,
+          deviceAuthority: provider.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([advertiser])
@@ -913,19 +1248,26 @@ describe("soulboard", () => {
       price,
       oracle.publicKey
     );
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
     const campaignLocationPda = deriveCampaignLocationPda(
       campaignPda,
       locationPda
     );
 
     await program.methods
-      .addCampaignLocation(campaignIdx, locationIdx)
+      .addCampaignLocation(campaignIdx, locationIdx, deviceIdx)
       .accounts({
         authority: advertiser.publicKey,
         campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
         campaignLocation: campaignLocationPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([advertiser])
@@ -933,7 +1275,7 @@ describe("soulboard", () => {
 
     await expectAnchorError(
       program.methods
-        .closeCampaign(campaignIdx)
+        .endCampaign(campaignIdx)
         .accounts({
           authority: advertiser.publicKey,
           advertiser: advertiserPda,
@@ -961,19 +1303,27 @@ describe("soulboard", () => {
       price,
       oracle.publicKey
     );
+    const { configPda, treasury } = await ensureConfig(provider);
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
     const campaignLocationPda = deriveCampaignLocationPda(
       campaignPda,
       locationPda
     );
 
     await program.methods
-      .addCampaignLocation(campaignIdx, locationIdx)
+      .addCampaignLocation(campaignIdx, locationIdx, deviceIdx)
       .accounts({
         authority: advertiser.publicKey,
         campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
         campaignLocation: campaignLocationPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([advertiser])
@@ -983,13 +1333,16 @@ describe("soulboard", () => {
       .settleCampaignLocation(
         campaignIdx,
         locationIdx,
-        advertiser.publicKey,
-        provider.publicKey,
-        new BN(200_000)
+        new BN(200_000),
+        deviceIdx
       )
       .accounts({
+        config: configPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+        treasury,
         oracleAuthority: oracle.publicKey,
-        locationAuthority: provider.publicKey,
+        earnings: deriveEarningsPda(providerPda),
         campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
@@ -1007,6 +1360,8 @@ describe("soulboard", () => {
           provider: providerPda,
           location: locationPda,
           campaignLocation: campaignLocationPda,
+          oracleDevice: devicePda,
+          deviceAuthority: provider.publicKey,
         })
         .signers([advertiser])
         .rpc(),
@@ -1030,19 +1385,26 @@ describe("soulboard", () => {
       price,
       oracle.publicKey
     );
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
     const campaignLocationPda = deriveCampaignLocationPda(
       campaignPda,
       locationPda
     );
 
     await program.methods
-      .addCampaignLocation(campaignIdx, locationIdx)
+      .addCampaignLocation(campaignIdx, locationIdx, deviceIdx)
       .accounts({
         authority: advertiser.publicKey,
         campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
         campaignLocation: campaignLocationPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .signers([advertiser])
@@ -1056,6 +1418,8 @@ describe("soulboard", () => {
         provider: providerPda,
         location: locationPda,
         campaignLocation: campaignLocationPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
       })
       .signers([advertiser])
       .rpc();
@@ -1078,109 +1442,2493 @@ describe("soulboard", () => {
     expect(bookingAfterCancel.status).to.have.property("cancelled");
   });
 
-  it("closes a campaign with no active bookings", async () => {
-    const { advertiser, advertiserPda } = await setupActors();
-    const budget = new BN(500_000);
-    const { campaignIdx, campaignPda } = await createCampaign(
-      advertiser,
-      advertiserPda,
-      budget
-    );
+  it("pauses, ends and archives a campaign", async () => {
+    const ctx = await setupBookableLocation(new BN(100_000));
+    const { advertiser, advertiserPda, campaignIdx, campaignPda } = ctx;
+    const statusAccounts = {
+      campaign: campaignPda,
+      authority: advertiser.publicKey,
+    };
+    const lifecycleAccounts = {
+      advertiser: advertiserPda,
+      campaign: campaignPda,
+      authority: advertiser.publicKey,
+    };
 
     await program.methods
-      .closeCampaign(campaignIdx)
-      .accounts({
-        authority: advertiser.publicKey,
-        advertiser: advertiserPda,
-        campaign: campaignPda,
-      })
+      .pauseCampaign(campaignIdx)
+      .accounts(statusAccounts)
       .signers([advertiser])
       .rpc();
+    expect(
+      (await program.account.campaign.fetch(campaignPda)).status
+    ).to.have.property("paused");
 
-    const advertiserAccount = await program.account.advertiser.fetch(
-      advertiserPda
+    const [slot] = ctx.slots;
+    await expectAnchorError(
+      bookRange(ctx, slot.start, slot.end).then(() => ""),
+      "CampaignNotActive"
     );
-    expect(advertiserAccount.campaignCount.toNumber()).to.equal(0);
 
-    let fetchError: any = null;
-    try {
-      await program.account.campaign.fetch(campaignPda);
-    } catch (error) {
-      fetchError = error;
-    }
-    expect(fetchError).to.be.ok;
-  });
+    await program.methods
+      .resumeCampaign(campaignIdx)
+      .accounts(statusAccounts)
+      .signers([advertiser])
+      .rpc();
+    expect(
+      (await program.account.campaign.fetch(campaignPda)).status
+    ).to.have.property("active");
+
+    await expectAnchorError(
+      program.methods
+        .archiveCampaign(campaignIdx)
+        .accounts({ campaign: campaignPda, authority: advertiser.publicKey })
+        .signers([advertiser])
+        .rpc(),
+      "CampaignNotClosed"
+    );
+
+    await program.methods
+      .endCampaign(campaignIdx)
+      .accounts(lifecycleAccounts)
+      .signers([advertiser])
+      .rpc();
+
+    const advertiserAccount = await program.account.advertiser.fetch(
+      advertiserPda
+    );
+    expect(advertiserAccount.campaignCount.toNumber()).to.equal(0);
+    const ended = await program.account.campaign.fetch(campaignPda);
+    expect(ended.status).to.have.property("closed");
+
+    await expectAnchorError(
+      program.methods
+        .resumeCampaign(campaignIdx)
+        .accounts(statusAccounts)
+        .signers([advertiser])
+        .rpc(),
+      "CampaignNotPaused"
+    );
+
+    const campaignLamports = await connection.getBalance(campaignPda);
+    const authorityBefore = await connection.getBalance(advertiser.publicKey);
+    await program.methods
+      .archiveCampaign(campaignIdx)
+      .accounts({ campaign: campaignPda, authority: advertiser.publicKey })
+      .signers([advertiser])
+      .rpc();
+
+    expect(await program.account.campaign.fetchNullable(campaignPda)).to.be
+      .null;
+    expect(
+      (await connection.getBalance(advertiser.publicKey)) - authorityBefore
+    ).to.be.greaterThan(campaignLamports - 10_000);
+    expect(campaignLamports).to.be.greaterThan(ended.availableBudget.toNumber());
+  });
+
+  it("keeps close_campaign as a deprecated end-and-archive alias", async () => {
+    const { advertiser, advertiserPda } = await setupActors();
+    const { campaignIdx, campaignPda } = await createCampaign(
+      advertiser,
+      advertiserPda,
+      new BN(LAMPORTS_PER_SOL)
+    );
+    const countBefore = (await program.account.advertiser.fetch(advertiserPda))
+      .campaignCount;
+
+    await program.methods
+      .closeCampaign(campaignIdx)
+      .accounts({
+        advertiser: advertiserPda,
+        campaign: campaignPda,
+        authority: advertiser.publicKey,
+      })
+      .signers([advertiser])
+      .rpc();
+
+    expect(await program.account.campaign.fetchNullable(campaignPda)).to.be
+      .null;
+    const advertiserAccount = await program.account.advertiser.fetch(
+      advertiserPda
+    );
+    expect(advertiserAccount.campaignCount.toNumber()).to.equal(
+      countBefore.toNumber() - 1
+    );
+  });
+
+  it("enforces the campaign window and expires ended campaigns", async () => {
+    const ctx = await setupBookableLocation(new BN(100_000));
+    const { advertiser, advertiserPda, campaignIdx, campaignPda } = ctx;
+    const [slot] = ctx.slots;
+    const setWindow = (startsAt: BN | null, endsAt: BN | null) =>
+      program.methods
+        .setCampaignWindow(campaignIdx, startsAt, endsAt)
+        .accounts({ campaign: campaignPda, authority: advertiser.publicKey })
+        .signers([advertiser])
+        .rpc();
+
+    await setWindow(slot.start.addn(60), slot.end);
+    await expectAnchorError(
+      bookRange(ctx, slot.start, slot.end).then(() => ""),
+      "OutsideCampaignWindow"
+    );
+
+    // No signer is required; the provider wallet only pays the fee.
+    const expire = () =>
+      program.methods
+        .expireCampaign(campaignIdx)
+        .accounts({
+          advertiser: advertiserPda,
+          campaign: campaignPda,
+          owner: advertiser.publicKey,
+        })
+        .rpc();
+
+    await setWindow(null, slot.end);
+    await expectAnchorError(expire(), "CampaignNotExpired");
+
+    const now = Math.floor(Date.now() / 1000);
+    await setWindow(null, new BN(now - 60));
+    const before = await program.account.campaign.fetch(campaignPda);
+    const ownerBefore = await connection.getBalance(advertiser.publicKey);
+    await expire();
+
+    const after = await program.account.campaign.fetch(campaignPda);
+    expect(after.status).to.have.property("closed");
+    expect(after.availableBudget.toNumber()).to.equal(0);
+    expect(
+      (await connection.getBalance(advertiser.publicKey)) - ownerBefore
+    ).to.equal(before.availableBudget.toNumber());
+    const advertiserAccount = await program.account.advertiser.fetch(
+      advertiserPda
+    );
+    expect(advertiserAccount.campaignCount.toNumber()).to.equal(0);
+  });
+
+  it("enforces campaign pacing caps when booking", async () => {
+    const slotPrice = new BN(100_000);
+    const ctx = await setupBookableLocation(slotPrice, 3);
+    const { advertiser, campaignIdx, campaignPda, slots } = ctx;
+    const locationSpendPda = deriveLocationSpendPda(
+      campaignPda,
+      ctx.locationPda
+    );
+    const setPacing = (pacing: any) =>
+      program.methods
+        .setCampaignPacing(campaignIdx, pacing)
+        .accounts({ campaign: campaignPda, authority: advertiser.publicKey })
+        .signers([advertiser])
+        .rpc();
+    const book = (start: BN, end: BN, withSpend = true) =>
+      program.methods
+        .bookLocationRange(
+          campaignIdx,
+          ctx.locationIdx,
+          start,
+          end,
+          ctx.deviceIdx,
+          { timeSlot: {} }
+        )
+        .accounts({
+          authority: advertiser.publicKey,
+          campaign: campaignPda,
+          provider: ctx.providerPda,
+          location: ctx.locationPda,
+          schedule: ctx.schedulePda,
+          booking: deriveCampaignBookingPda(
+            campaignPda,
+            ctx.locationPda,
+            start,
+            end
+          ),
+          locationSpend: withSpend ? locationSpendPda : null,
+          oracleDevice: ctx.devicePda,
+          deviceAuthority: ctx.provider.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([advertiser])
+        .rpc();
+
+    await setPacing({
+      period: { daily: {} },
+      maxPerPeriod: new BN(250_000),
+      maxPerBooking: new BN(150_000),
+      maxPerLocation: new BN(150_000),
+    });
+
+    await expectAnchorError(
+      book(slots[0].start, slots[1].end),
+      "PacingLimitExceeded"
+    );
+    await expectAnchorError(
+      book(slots[0].start, slots[0].end, false),
+      "InvalidParameters"
+    );
+
+    await book(slots[0].start, slots[0].end);
+    const spend = await program.account.campaignLocationSpend.fetch(
+      locationSpendPda
+    );
+    expect(spend.spent.toString()).to.equal(slotPrice.toString());
+    await expectAnchorError(
+      book(slots[1].start, slots[1].end),
+      "PacingLimitExceeded"
+    );
+
+    await setPacing({
+      period: { daily: {} },
+      maxPerPeriod: new BN(250_000),
+      maxPerBooking: null,
+      maxPerLocation: null,
+    });
+    await book(slots[1].start, slots[1].end);
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.pacingWindowSpent.toString()).to.equal(
+      slotPrice.muln(2).toString()
+    );
+    await expectAnchorError(
+      book(slots[2].start, slots[2].end),
+      "PacingLimitExceeded"
+    );
+
+    // Cancelling hands the spend back to both the window and the location counter.
+    await program.methods
+      .cancelLocationBooking(campaignIdx, ctx.locationIdx)
+      .accounts({
+        authority: advertiser.publicKey,
+        campaign: campaignPda,
+        provider: ctx.providerPda,
+        location: ctx.locationPda,
+        schedule: ctx.schedulePda,
+        booking: deriveCampaignBookingPda(
+          campaignPda,
+          ctx.locationPda,
+          slots[1].start,
+          slots[1].end
+        ),
+        locationSpend: locationSpendPda,
+        oracleDevice: ctx.devicePda,
+        deviceAuthority: ctx.provider.publicKey,
+      })
+      .signers([advertiser])
+      .rpc();
+    const refunded = await program.account.campaign.fetch(campaignPda);
+    expect(refunded.pacingWindowSpent.toString()).to.equal(
+      slotPrice.toString()
+    );
+    const refundedSpend = await program.account.campaignLocationSpend.fetch(
+      locationSpendPda
+    );
+    expect(refundedSpend.spent.toString()).to.equal(slotPrice.toString());
+    await book(slots[2].start, slots[2].end);
+  });
+
+  it("charges campaign pacing on legacy campaign-location bookings", async () => {
+    const price = new BN(100_000);
+    const ctx = await setupBookableLocation(price);
+    const { advertiser, campaignIdx, campaignPda } = ctx;
+    const locationSpendPda = deriveLocationSpendPda(
+      campaignPda,
+      ctx.locationPda
+    );
+    const campaignLocationPda = deriveCampaignLocationPda(
+      campaignPda,
+      ctx.locationPda
+    );
+    const setPacing = (maxPerLocation: BN) =>
+      program.methods
+        .setCampaignPacing(campaignIdx, {
+          period: { daily: {} },
+          maxPerPeriod: new BN(250_000),
+          maxPerBooking: null,
+          maxPerLocation,
+        })
+        .accounts({ campaign: campaignPda, authority: advertiser.publicKey })
+        .signers([advertiser])
+        .rpc();
+    const add = () =>
+      program.methods
+        .addCampaignLocation(campaignIdx, ctx.locationIdx, ctx.deviceIdx)
+        .accounts({
+          authority: advertiser.publicKey,
+          campaign: campaignPda,
+          provider: ctx.providerPda,
+          location: ctx.locationPda,
+          campaignLocation: campaignLocationPda,
+          locationSpend: locationSpendPda,
+          oracleDevice: ctx.devicePda,
+          deviceAuthority: ctx.provider.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([advertiser])
+        .rpc();
+
+    await setPacing(new BN(50_000));
+    await expectAnchorError(add(), "PacingLimitExceeded");
+
+    await setPacing(new BN(150_000));
+    await add();
+    const booked = await program.account.campaign.fetch(campaignPda);
+    expect(booked.pacingWindowSpent.toString()).to.equal(price.toString());
+    expect(
+      (await program.account.campaignLocationSpend.fetch(locationSpendPda)).spent.toString()
+    ).to.equal(price.toString());
+
+    await program.methods
+      .removeCampaignLocation(campaignIdx, ctx.locationIdx)
+      .accounts({
+        authority: advertiser.publicKey,
+        campaign: campaignPda,
+        provider: ctx.providerPda,
+        location: ctx.locationPda,
+        campaignLocation: campaignLocationPda,
+        locationSpend: locationSpendPda,
+        oracleDevice: ctx.devicePda,
+        deviceAuthority: ctx.provider.publicKey,
+      })
+      .signers([advertiser])
+      .rpc();
+    const removed = await program.account.campaign.fetch(campaignPda);
+    expect(removed.pacingWindowSpent.toNumber()).to.equal(0);
+    expect(
+      (await program.account.campaignLocationSpend.fetch(locationSpendPda)).spent.toNumber()
+    ).to.equal(0);
+  });
+
+  it("books only locations matching the campaign targeting", async () => {
+    const slotPrice = new BN(100_000);
+    const ctx = await setupBookableLocation(slotPrice);
+    const { advertiser, campaignIdx, campaignPda, providerPda } = ctx;
+    const [slot] = ctx.slots;
+    const baseTargeting = {
+      allowedCategories: 0,
+      geo: null,
+      maxPricePerSlot: null,
+      allowedProviders: [],
+      deniedProviders: [],
+    };
+    const setTargeting = (targeting: any, signer = advertiser) =>
+      program.methods
+        .setCampaignTargeting(campaignIdx, targeting)
+        .accounts({ campaign: campaignPda, authority: signer.publicKey })
+        .signers([signer])
+        .rpc();
+
+    await expectAnchorError(
+      setTargeting(baseTargeting, ctx.provider),
+      "ConstraintHasOne"
+    );
+
+    const mismatches = [
+      { ...baseTargeting, allowedCategories: 1 << 1 },
+      {
+        ...baseTargeting,
+        geo: {
+          minLatitudeE7: 0,
+          maxLatitudeE7: 100_000_000,
+          minLongitudeE7: 0,
+          maxLongitudeE7: 100_000_000,
+        },
+      },
+      { ...baseTargeting, maxPricePerSlot: slotPrice.subn(1) },
+      { ...baseTargeting, deniedProviders: [providerPda] },
+      { ...baseTargeting, allowedProviders: [Keypair.generate().publicKey] },
+    ];
+    for (const targeting of mismatches) {
+      await setTargeting(targeting);
+      await expectAnchorError(
+        bookRange(ctx, slot.start, slot.end).then(() => ""),
+        "TargetingMismatch"
+      );
+    }
+
+    await setTargeting({
+      allowedCategories: 1 << 0,
+      geo: {
+        minLatitudeE7: 400_000_000,
+        maxLatitudeE7: 410_000_000,
+        minLongitudeE7: -745_000_000,
+        maxLongitudeE7: -735_000_000,
+      },
+      maxPricePerSlot: slotPrice,
+      allowedProviders: [providerPda],
+      deniedProviders: [],
+    });
+    const bookingPda = await bookRange(ctx, slot.start, slot.end);
+    const booking = await program.account.campaignBooking.fetch(bookingPda);
+    expect(booking.totalPrice.toString()).to.equal(slotPrice.toString());
+  });
+
+  it("applies location policies to advertisers and content", async () => {
+    const ctx = await setupBookableLocation(new BN(100_000));
+    const { advertiser, advertiserPda, provider, providerPda } = ctx;
+    const [slot] = ctx.slots;
+    const policyPda = deriveLocationPolicyPda(ctx.locationPda);
+    const gambling = 1 << 0;
+    const setPolicy = (denied: PublicKey[], forbidden: number) =>
+      program.methods
+        .setLocationPolicy(ctx.locationIdx, [], denied, forbidden)
+        .accounts({
+          provider: providerPda,
+          location: ctx.locationPda,
+          locationPolicy: policyPda,
+          authority: provider.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([provider])
+        .rpc();
+    const setContent = (categories: number) =>
+      program.methods
+        .setCampaignContent(ctx.campaignIdx, categories)
+        .accounts({ campaign: ctx.campaignPda, authority: advertiser.publicKey })
+        .signers([advertiser])
+        .rpc();
+    const book = (withPolicy = true) =>
+      bookRange(ctx, slot.start, slot.end, { timeSlot: {} }, {
+        locationPolicy: withPolicy ? policyPda : null,
+      }).then(() => "");
+
+    await setPolicy([advertiserPda], 0);
+    expect(
+      (await program.account.location.fetch(ctx.locationPda)).hasPolicy
+    ).to.equal(true);
+    await expectAnchorError(book(false), "InvalidParameters");
+    await expectAnchorError(book(), "RejectedByLocationPolicy");
+
+    await setPolicy([], gambling);
+    await setContent(gambling);
+    await expectAnchorError(book(), "RejectedByLocationPolicy");
+
+    await setContent(0);
+    await book();
+
+    // Policy changes need their own permission; location editors are refused.
+    const manager = Keypair.generate();
+    const managerDelegatePda = deriveDelegatePda(
+      providerPda,
+      manager.publicKey
+    );
+    const grant = (permissions: number) =>
+      program.methods
+        .setDelegate(manager.publicKey, permissions, null)
+        .accounts({
+          owner: provider.publicKey,
+          principal: providerPda,
+          delegateAccount: managerDelegatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([provider])
+        .rpc();
+    const removePolicy = () =>
+      program.methods
+        .removeLocationPolicy(ctx.locationIdx)
+        .accounts({
+          provider: providerPda,
+          location: ctx.locationPda,
+          locationPolicy: policyPda,
+          delegate: managerDelegatePda,
+          authority: manager.publicKey,
+          owner: provider.publicKey,
+        })
+        .signers([manager])
+        .rpc();
+    const PERMISSION_EDIT_POLICY = 1 << 5;
+    const PERMISSION_EDIT_LOCATION = 1 << 6;
+    await grant(PERMISSION_EDIT_LOCATION);
+    await expectAnchorError(removePolicy(), "Unauthorized");
+    await grant(PERMISSION_EDIT_POLICY);
+    await removePolicy();
+    expect(
+      (await program.account.location.fetch(ctx.locationPda)).hasPolicy
+    ).to.equal(false);
+    expect(await program.account.locationPolicy.fetchNullable(policyPda)).to.be
+      .null;
+  });
+
+  it("holds approval-mode bookings as pending until the provider decides", async () => {
+    const slotPrice = new BN(100_000);
+    const ctx = await setupBookableLocation(slotPrice, 3);
+    const { advertiser, provider, providerPda, campaignPda, slots } = ctx;
+    const setApproval = (windowSecs: number) =>
+      program.methods
+        .setLocationApproval(ctx.locationIdx, true, windowSecs)
+        .accounts({
+          provider: providerPda,
+          location: ctx.locationPda,
+          authority: provider.publicKey,
+        })
+        .signers([provider])
+        .rpc();
+    const accept = (bookingPda: PublicKey, signer: Keypair) =>
+      program.methods
+        .acceptBooking(ctx.locationIdx)
+        .accounts({
+          provider: providerPda,
+          location: ctx.locationPda,
+          booking: bookingPda,
+          authority: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+    const resolve = (
+      method: "rejectBooking" | "expirePendingBooking",
+      bookingPda: PublicKey,
+      signer: Keypair
+    ) =>
+      program.methods[method](ctx.locationIdx)
+        .accounts({
+          campaign: campaignPda,
+          provider: providerPda,
+          location: ctx.locationPda,
+          schedule: ctx.schedulePda,
+          booking: bookingPda,
+          oracleDevice: ctx.devicePda,
+          deviceAuthority: provider.publicKey,
+          authority: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+
+    await setApproval(3600);
+    const accepted = await bookRange(ctx, slots[0].start, slots[0].end);
+    const pending = await program.account.campaignBooking.fetch(accepted);
+    expect(pending.status).to.have.property("pending");
+    expect(pending.approvalDeadline).to.not.be.null;
+    await expectAnchorError(
+      resolve("expirePendingBooking", accepted, advertiser),
+      "ApprovalDeadlineNotReached"
+    );
+    await expectAnchorError(accept(accepted, advertiser), "Unauthorized");
+    await accept(accepted, provider);
+    expect(
+      (await program.account.campaignBooking.fetch(accepted)).status
+    ).to.have.property("active");
+
+    // The deadline is capped by the first booked slot, not the requested range start.
+    await setApproval(86_400);
+    const campaignBefore = await program.account.campaign.fetch(campaignPda);
+    const rejected = await bookRange(ctx, slots[1].start.subn(600), slots[1].end);
+    expect(
+      (await program.account.campaignBooking.fetch(rejected)).approvalDeadline.toString()
+    ).to.equal(slots[1].start.toString());
+    await resolve("rejectBooking", rejected, provider);
+    expect(await program.account.campaignBooking.fetchNullable(rejected)).to.be
+      .null;
+    const campaignAfter = await program.account.campaign.fetch(campaignPda);
+    expect(campaignAfter.availableBudget.toString()).to.equal(
+      campaignBefore.availableBudget.toString()
+    );
+    const schedule = await program.account.locationSchedule.fetch(
+      ctx.schedulePda
+    );
+    expect(schedule.slots[1].status).to.have.property("available");
+
+    await setApproval(1);
+    const expired = await bookRange(ctx, slots[2].start, slots[2].end);
+    await new Promise((resolve) => setTimeout(resolve, 2500));
+    await expectAnchorError(accept(expired, provider), "ApprovalDeadlinePassed");
+    const cranker = Keypair.generate();
+    await airdropTo(cranker.publicKey);
+    await resolve("expirePendingBooking", expired, cranker);
+    expect(await program.account.campaignBooking.fetchNullable(expired)).to.be
+      .null;
+  });
+
+  it("holds slots for a campaign until the hold expires", async () => {
+    const ctx = await setupBookableLocation(new BN(100_000), 2);
+    const { advertiser, advertiserPda, provider, providerPda, slots } = ctx;
+    const policyPda = deriveLocationPolicyPda(ctx.locationPda);
+    const other = await createCampaign(
+      advertiser,
+      advertiserPda,
+      new BN(1 * LAMPORTS_PER_SOL)
+    );
+    const otherCtx = { ...ctx, ...other };
+    const hold = (
+      slot: { start: BN; end: BN },
+      holdSecs: number,
+      locationPolicy: PublicKey | null = null
+    ) =>
+      program.methods
+        .holdLocationSlots(
+          ctx.campaignIdx,
+          ctx.locationIdx,
+          slot.start,
+          slot.end,
+          holdSecs,
+          ctx.deviceIdx
+        )
+        .accounts({
+          campaign: ctx.campaignPda,
+          provider: providerPda,
+          location: ctx.locationPda,
+          schedule: ctx.schedulePda,
+          locationPolicy,
+          oracleDevice: ctx.devicePda,
+          deviceAuthority: provider.publicKey,
+          authority: advertiser.publicKey,
+        })
+        .signers([advertiser])
+        .rpc();
+    const release = () =>
+      program.methods
+        .releaseSlotHolds(ctx.locationIdx)
+        .accounts({
+          provider: providerPda,
+          location: ctx.locationPda,
+          schedule: ctx.schedulePda,
+        })
+        .rpc();
+
+    // Holds go through the same admission checks as bookings.
+    await program.methods
+      .setLocationPolicy(ctx.locationIdx, [], [advertiserPda], 0)
+      .accounts({
+        provider: providerPda,
+        location: ctx.locationPda,
+        locationPolicy: policyPda,
+        authority: provider.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([provider])
+      .rpc();
+    await expectAnchorError(
+      hold(slots[0], 900, policyPda),
+      "RejectedByLocationPolicy"
+    );
+    await program.methods
+      .removeLocationPolicy(ctx.locationIdx)
+      .accounts({
+        provider: providerPda,
+        location: ctx.locationPda,
+        locationPolicy: policyPda,
+        authority: provider.publicKey,
+        owner: provider.publicKey,
+      })
+      .signers([provider])
+      .rpc();
+
+    await hold(slots[0], 900);
+    let schedule = await program.account.locationSchedule.fetch(
+      ctx.schedulePda
+    );
+    expect(schedule.slots[0].status.held.campaign.toBase58()).to.equal(
+      ctx.campaignPda.toBase58()
+    );
+    await expectAnchorError(hold(slots[0], 900), "SlotAlreadyHeld");
+    await expectAnchorError(
+      bookRange(otherCtx, slots[0].start, slots[0].end).then(() => ""),
+      "SlotUnavailable"
+    );
+    await bookRange(ctx, slots[0].start, slots[0].end);
+
+    await hold(slots[1], 1);
+    await expectAnchorError(release(), "SlotNotFound");
+    await new Promise((resolve) => setTimeout(resolve, 2500));
+    await release();
+    schedule = await program.account.locationSchedule.fetch(ctx.schedulePda);
+    expect(schedule.slots[0].status).to.have.property("booked");
+    expect(schedule.slots[1].status).to.have.property("available");
+    await bookRange(otherCtx, slots[1].start, slots[1].end);
+  });
+
+  it("extends and reschedules a booking when the location allows it", async () => {
+    const slotPrice = new BN(120_000);
+    const ctx = await setupBookableLocation(slotPrice, 4);
+    const { advertiser, provider, providerPda, slots } = ctx;
+    const bookingPda = await bookRange(ctx, slots[1].start, slots[1].end);
+    const modifyAccounts = {
+      campaign: ctx.campaignPda,
+      provider: providerPda,
+      location: ctx.locationPda,
+      schedule: ctx.schedulePda,
+      booking: bookingPda,
+      oracleDevice: ctx.devicePda,
+      deviceAuthority: provider.publicKey,
+      authority: advertiser.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+    const extend = (slotIndexes: number[]) =>
+      program.methods
+        .extendBooking(ctx.campaignIdx, ctx.locationIdx, slotIndexes)
+        .accounts(modifyAccounts)
+        .signers([advertiser])
+        .rpc();
+
+    await expectAnchorError(extend([2]), "ModificationsNotAllowed");
+    await program.methods
+      .setLocationModifications(ctx.locationIdx, true)
+      .accounts({
+        provider: providerPda,
+        location: ctx.locationPda,
+        authority: provider.publicKey,
+      })
+      .signers([provider])
+      .rpc();
+    await expectAnchorError(extend([3]), "SlotsNotAdjacent");
+
+    const before = await program.account.campaign.fetch(ctx.campaignPda);
+    await extend([0, 2]);
+    let booking = await program.account.campaignBooking.fetch(bookingPda);
+    expect(booking.slotCount).to.equal(3);
+    expect(booking.totalPrice.toString()).to.equal(
+      slotPrice.muln(3).toString()
+    );
+    expect(booking.rangeStartTs.toString()).to.equal(slots[0].start.toString());
+    expect(booking.rangeEndTs.toString()).to.equal(slots[2].end.toString());
+    const extended = await program.account.campaign.fetch(ctx.campaignPda);
+    expect(
+      before.availableBudget.sub(extended.availableBudget).toString()
+    ).to.equal(slotPrice.muln(2).toString());
+
+    await program.methods
+      .rescheduleBooking(ctx.campaignIdx, ctx.locationIdx, [0], [3])
+      .accounts(modifyAccounts)
+      .signers([advertiser])
+      .rpc();
+    booking = await program.account.campaignBooking.fetch(bookingPda);
+    expect(booking.slotCount).to.equal(3);
+    expect(booking.totalPrice.toString()).to.equal(
+      slotPrice.muln(3).toString()
+    );
+    expect(booking.rangeStartTs.toString()).to.equal(slots[1].start.toString());
+    expect(booking.rangeEndTs.toString()).to.equal(slots[3].end.toString());
+    const schedule = await program.account.locationSchedule.fetch(
+      ctx.schedulePda
+    );
+    expect(schedule.slots[0].status).to.have.property("available");
+    expect(schedule.slots[3].status).to.have.property("booked");
+    const rescheduled = await program.account.campaign.fetch(ctx.campaignPda);
+    expect(rescheduled.availableBudget.toString()).to.equal(
+      extended.availableBudget.toString()
+    );
+  });
+
+  it("lets providers cancel bookings with compensation", async () => {
+    const slotPrice = new BN(200_000);
+    const ctx = await setupBookableLocation(slotPrice, 4);
+    const { advertiser, provider, providerPda, oracle, slots } = ctx;
+    const { configPda, treasury } = await ensureConfig(provider);
+    await program.methods
+      .setProviderCancelCompensation(1_000)
+      .accounts({ config: configPda, authority: configAuthority.publicKey })
+      .signers([configAuthority])
+      .rpc();
+    const bookingPda = await bookRange(ctx, slots[0].start, slots[1].end);
+    const cancel = () =>
+      program.methods
+        .providerCancelBooking(ctx.locationIdx, { screenFailure: {} })
+        .accounts({
+          campaign: ctx.campaignPda,
+          provider: providerPda,
+          location: ctx.locationPda,
+          schedule: ctx.schedulePda,
+          booking: bookingPda,
+          earnings: deriveEarningsPda(providerPda),
+          oracleDevice: ctx.devicePda,
+          deviceAuthority: provider.publicKey,
+          authority: provider.publicKey,
+        })
+        .signers([provider])
+        .rpc();
+
+    // Compensation comes out of settled earnings, so the vault must cover it.
+    await expectAnchorError(cancel(), "InsufficientEarnings");
+
+    const earned = await bookRange(ctx, slots[3].start, slots[3].end);
+    await program.methods
+      .settleLocationBooking(
+        ctx.campaignIdx,
+        ctx.locationIdx,
+        advertiser.publicKey,
+        provider.publicKey
+      )
+      .accounts({
+        campaign: ctx.campaignPda,
+        provider: providerPda,
+        location: ctx.locationPda,
+        schedule: ctx.schedulePda,
+        booking: earned,
+        config: configPda,
+        oracleDevice: ctx.devicePda,
+        deviceAuthority: provider.publicKey,
+        earnings: deriveEarningsPda(providerPda),
+        treasury,
+        oracleAuthority: oracle.publicKey,
+      })
+      .signers([oracle])
+      .rpc();
+    const pendingBefore = await fetchPendingEarnings(providerPda);
+    const before = await program.account.campaign.fetch(ctx.campaignPda);
+
+    await cancel();
+
+    const escrow = slotPrice.muln(2);
+    const compensation = escrow.divn(10);
+    const after = await program.account.campaign.fetch(ctx.campaignPda);
+    expect(after.availableBudget.sub(before.availableBudget).toString()).to.equal(
+      escrow.add(compensation).toString()
+    );
+    expect(pendingBefore - (await fetchPendingEarnings(providerPda))).to.equal(
+      compensation.toNumber()
+    );
+    expect(after.reservedBudget.toNumber()).to.equal(
+      before.reservedBudget.sub(escrow).toNumber()
+    );
+    const schedule = await program.account.locationSchedule.fetch(
+      ctx.schedulePda
+    );
+    expect(schedule.slots[0].status).to.have.property("cancelled");
+    expect(schedule.slots[1].status).to.have.property("cancelled");
+    const providerAccount = await program.account.provider.fetch(providerPda);
+    expect(providerAccount.cancellationCount).to.equal(1);
+    const device = await oracleProgram.account.device.fetch(ctx.devicePda);
+    expect(device.activeBookings).to.equal(0);
+    expect(await connection.getAccountInfo(bookingPda)).to.equal(null);
+
+    // Ranges spanning the withdrawn slots still book whatever remains open.
+    const rebooked = await bookRange(ctx, slots[0].start, slots[2].end);
+    const booking = await program.account.campaignBooking.fetch(rebooked);
+    expect(booking.slotCount).to.equal(1);
+  });
+
+  it("cancels part of a booking subject to the location policy", async () => {
+    const slotPrice = new BN(150_000);
+    const ctx = await setupBookableLocation(slotPrice, 3);
+    const { advertiser, provider, providerPda, slots } = ctx;
+    const bookingPda = await bookRange(ctx, slots[0].start, slots[2].end);
+    const setCancellation = (allowPartial: boolean, cutoffSecs: number) =>
+      program.methods
+        .setLocationCancellation(ctx.locationIdx, {
+          allowPartial,
+          cutoffSecs,
+        })
+        .accounts({
+          provider: providerPda,
+          location: ctx.locationPda,
+          authority: provider.publicKey,
+        })
+        .signers([provider])
+        .rpc();
+    const cancelSlots = (slotIndexes: number[]) =>
+      program.methods
+        .cancelBookingSlots(ctx.campaignIdx, ctx.locationIdx, slotIndexes)
+        .accounts({
+          campaign: ctx.campaignPda,
+          provider: providerPda,
+          location: ctx.locationPda,
+          schedule: ctx.schedulePda,
+          booking: bookingPda,
+          authority: advertiser.publicKey,
+        })
+        .signers([advertiser])
+        .rpc();
+
+    await expectAnchorError(cancelSlots([2]), "PartialCancelNotAllowed");
+    await setCancellation(true, 4 * 3600);
+    await expectAnchorError(cancelSlots([2]), "CancellationCutoffPassed");
+    await setCancellation(true, 600);
+
+    const before = await program.account.campaign.fetch(ctx.campaignPda);
+    await cancelSlots([2]);
+    const booking = await program.account.campaignBooking.fetch(bookingPda);
+    expect(booking.slotCount).to.equal(2);
+    expect(booking.totalPrice.toString()).to.equal(
+      slotPrice.muln(2).toString()
+    );
+    expect(booking.rangeEndTs.toString()).to.equal(slots[1].end.toString());
+    const after = await program.account.campaign.fetch(ctx.campaignPda);
+    expect(after.availableBudget.sub(before.availableBudget).toString()).to.equal(
+      slotPrice.toString()
+    );
+    expect(before.reservedBudget.sub(after.reservedBudget).toString()).to.equal(
+      slotPrice.toString()
+    );
+    const schedule = await program.account.locationSchedule.fetch(
+      ctx.schedulePda
+    );
+    expect(schedule.slots[1].status).to.have.property("booked");
+    expect(schedule.slots[2].status).to.have.property("available");
+
+    await expectAnchorError(cancelSlots([2]), "SlotNotInBooking");
+    await expectAnchorError(cancelSlots([0, 1]), "InvalidParameters");
+  });
+
+  it("books explicit slot lists across locations as one order", async () => {
+    const slotPrice = new BN(100_000);
+    const ctx = await setupBookableLocation(slotPrice, 3);
+    const { advertiser, provider, providerPda, oracle, slots } = ctx;
+    const second = await registerLocation(
+      provider,
+      providerPda,
+      slotPrice,
+      oracle.publicKey
+    );
+    const secondSchedulePda = await createScheduleWithSlots(
+      provider,
+      providerPda,
+      second.locationIdx,
+      second.locationPda,
+      slots.map((slot) => ({ ...slot }))
+    );
+    const secondDevice = await createOracleDevice(
+      provider,
+      oracle,
+      second.locationPda
+    );
+
+    const createOrder = async () => {
+      const { nextOrderId } = await program.account.campaign.fetch(
+        ctx.campaignPda
+      );
+      const orderPda = deriveBookingOrderPda(ctx.campaignPda, nextOrderId);
+      await program.methods
+        .createBookingOrder(ctx.campaignIdx)
+        .accounts({
+          campaign: ctx.campaignPda,
+          order: orderPda,
+          authority: advertiser.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([advertiser])
+        .rpc();
+      return { orderId: nextOrderId, orderPda };
+    };
+    const bookSlots = async (
+      order: { orderId: BN; orderPda: PublicKey },
+      location: { locationIdx: BN; locationPda: PublicKey },
+      schedulePda: PublicKey,
+      device: { deviceIdx: BN; devicePda: PublicKey },
+      slotIndexes: number[]
+    ) => {
+      const bookingPda = deriveOrderBookingPda(
+        order.orderPda,
+        location.locationPda
+      );
+      await program.methods
+        .bookOrderSlots(
+          ctx.campaignIdx,
+          order.orderId,
+          location.locationIdx,
+          slotIndexes,
+          device.deviceIdx,
+          { timeSlot: {} }
+        )
+        .accounts({
+          campaign: ctx.campaignPda,
+          order: order.orderPda,
+          provider: providerPda,
+          location: location.locationPda,
+          schedule: schedulePda,
+          booking: bookingPda,
+          oracleDevice: device.devicePda,
+          deviceAuthority: provider.publicKey,
+          authority: advertiser.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([advertiser])
+        .rpc();
+      return bookingPda;
+    };
+
+    const order = await createOrder();
+    await expectAnchorError(
+      bookSlots(order, ctx, ctx.schedulePda, ctx, [2, 0]).then(() => ""),
+      "InvalidParameters"
+    );
+    const firstBooking = await bookSlots(
+      order,
+      ctx,
+      ctx.schedulePda,
+      ctx,
+      [0, 2]
+    );
+    const secondBooking = await bookSlots(
+      order,
+      second,
+      secondSchedulePda,
+      secondDevice,
+      [1]
+    );
+
+    const orderAccount = await program.account.bookingOrder.fetch(
+      order.orderPda
+    );
+    expect(orderAccount.bookingCount).to.equal(2);
+    expect(orderAccount.totalPrice.toString()).to.equal(
+      slotPrice.muln(3).toString()
+    );
+    const booking = await program.account.campaignBooking.fetch(firstBooking);
+    expect(booking.order.toBase58()).to.equal(order.orderPda.toBase58());
+    expect(booking.slotCount).to.equal(2);
+    expect(booking.rangeStartTs.toString()).to.equal(slots[0].start.toString());
+    expect(booking.rangeEndTs.toString()).to.equal(slots[2].end.toString());
+    const schedule = await program.account.locationSchedule.fetch(
+      ctx.schedulePda
+    );
+    expect(schedule.slots[0].status).to.have.property("booked");
+    expect(schedule.slots[1].status).to.have.property("available");
+    expect(schedule.slots[2].status).to.have.property("booked");
+
+    const cancelChild = (
+      location: { locationIdx: BN; locationPda: PublicKey },
+      schedulePda: PublicKey,
+      device: { devicePda: PublicKey },
+      booking: PublicKey,
+      orderPda: PublicKey = order.orderPda
+    ) =>
+      program.methods
+        .cancelLocationBooking(ctx.campaignIdx, location.locationIdx)
+        .accounts({
+          authority: advertiser.publicKey,
+          campaign: ctx.campaignPda,
+          provider: providerPda,
+          location: location.locationPda,
+          schedule: schedulePda,
+          booking,
+          order: orderPda,
+          oracleDevice: device.devicePda,
+          deviceAuthority: provider.publicKey,
+        })
+        .signers([advertiser])
+        .rpc();
+    const closeOrder = () =>
+      program.methods
+        .closeBookingOrder(ctx.campaignIdx, order.orderId)
+        .accounts({
+          campaign: ctx.campaignPda,
+          order: order.orderPda,
+          authority: advertiser.publicKey,
+        })
+        .signers([advertiser])
+        .rpc();
+
+    await cancelChild(ctx, ctx.schedulePda, ctx, firstBooking);
+    const stillActive = await program.account.campaignBooking.fetch(
+      secondBooking
+    );
+    expect(stillActive.status).to.have.property("active");
+    const reduced = await program.account.bookingOrder.fetch(order.orderPda);
+    expect(reduced.bookingCount).to.equal(1);
+    expect(reduced.totalPrice.toString()).to.equal(slotPrice.toString());
+
+    await expectAnchorError(closeOrder(), "OrderHasBookings");
+    await cancelChild(second, secondSchedulePda, secondDevice, secondBooking);
+    await closeOrder();
+    expect(await connection.getAccountInfo(order.orderPda)).to.be.null;
+
+    const rebooked = await createOrder();
+    expect(rebooked.orderId.toNumber()).to.equal(order.orderId.toNumber() + 1);
+    const settledChild = await bookSlots(
+      rebooked,
+      ctx,
+      ctx.schedulePda,
+      ctx,
+      [0]
+    );
+    const { configPda, treasury } = await ensureConfig(provider);
+    await program.methods
+      .settleLocationBooking(
+        ctx.campaignIdx,
+        ctx.locationIdx,
+        advertiser.publicKey,
+        provider.publicKey
+      )
+      .accounts({
+        campaign: ctx.campaignPda,
+        provider: providerPda,
+        location: ctx.locationPda,
+        schedule: ctx.schedulePda,
+        booking: settledChild,
+        order: rebooked.orderPda,
+        config: configPda,
+        oracleDevice: ctx.devicePda,
+        deviceAuthority: provider.publicKey,
+        earnings: deriveEarningsPda(providerPda),
+        treasury,
+        oracleAuthority: oracle.publicKey,
+      })
+      .signers([oracle])
+      .rpc();
+
+    // The child PDA is re-created for the same order and location; the settled slot
+    // must not be counted as part of it.
+    const rebookedChild = await bookSlots(
+      rebooked,
+      ctx,
+      ctx.schedulePda,
+      ctx,
+      [1, 2]
+    );
+    expect(rebookedChild.toBase58()).to.equal(settledChild.toBase58());
+    const child = await program.account.campaignBooking.fetch(rebookedChild);
+    expect(child.slotCount).to.equal(2);
+    expect(child.rangeStartTs.toString()).to.equal(slots[1].start.toString());
+    const rebookedSchedule = await program.account.locationSchedule.fetch(
+      ctx.schedulePda
+    );
+    expect(rebookedSchedule.slots[0].status).to.have.property("settled");
+    expect(rebookedSchedule.slots[0].booking.toBase58()).to.equal(
+      PublicKey.default.toBase58()
+    );
+
+    await cancelChild(
+      ctx,
+      ctx.schedulePda,
+      ctx,
+      rebookedChild,
+      rebooked.orderPda
+    );
+    const afterCancel = await program.account.locationSchedule.fetch(
+      ctx.schedulePda
+    );
+    expect(afterCancel.slots[0].status).to.have.property("settled");
+    expect(afterCancel.slots[1].status).to.have.property("available");
+    expect(afterCancel.slots[2].status).to.have.property("available");
+  });
+
+  it("rejects overlapping slots in a schedule", async () => {
+    const { provider, providerPda, oracle } = await setupActors();
+    const price = new BN(100_000);
+    const { locationIdx, locationPda } = await registerLocation(
+      provider,
+      providerPda,
+      price,
+      oracle.publicKey
+    );
+
+    const schedulePda = deriveLocationSchedulePda(locationPda);
+    await program.methods
+      .createLocationSchedule(locationIdx, 5)
+      .accounts({
+        authority: provider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([provider])
+      .rpc();
+
+    const now = Math.floor(Date.now() / 1000);
+    const start = new BN(now + 3600);
+    const end = new BN(now + 7200);
+
+    await program.methods
+      .addLocationSlot(locationIdx, start, end, price, false)
+      .accounts({
+        authority: provider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+      })
+      .signers([provider])
+      .rpc();
+
+    await expectAnchorError(
+      program.methods
+        .addLocationSlot(
+          locationIdx,
+          new BN(now + 4000),
+          new BN(now + 8000),
+          price,
+          false
+        )
+        .accounts({
+          authority: provider.publicKey,
+          provider: providerPda,
+          location: locationPda,
+          schedule: schedulePda,
+        })
+        .signers([provider])
+        .rpc(),
+      "SlotOverlap"
+    );
+  });
+
+  it("enforces operating hours when adding slots", async () => {
+    const { provider, providerPda, oracle } = await setupActors();
+    const price = new BN(100_000);
+    const { locationIdx, locationPda } = await registerLocation(
+      provider,
+      providerPda,
+      price,
+      oracle.publicKey
+    );
+
+    const nineToFive = { openMinute: 9 * 60, closeMinute: 17 * 60 };
+    await program.methods
+      .setLocationOperatingHours(locationIdx, {
+        utcOffsetMinutes: 0,
+        days: Array(7).fill(nineToFive),
+      })
+      .accounts({
+        authority: provider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+      })
+      .signers([provider])
+      .rpc();
+
+    const schedulePda = await createScheduleWithSlots(
+      provider,
+      providerPda,
+      locationIdx,
+      locationPda,
+      []
+    );
+
+    const tomorrow = (Math.floor(Date.now() / 1000 / 86400) + 1) * 86400;
+    const addSlot = (startHour: number, endHour: number, override: boolean) =>
+      program.methods
+        .addLocationSlot(
+          locationIdx,
+          new BN(tomorrow + startHour * 3600),
+          new BN(tomorrow + endHour * 3600),
+          price,
+          override
+        )
+        .accounts({
+          authority: provider.publicKey,
+          provider: providerPda,
+          location: locationPda,
+          schedule: schedulePda,
+        })
+        .signers([provider])
+        .rpc();
+
+    await expectAnchorError(addSlot(3, 4, false), "SlotOutsideOperatingHours");
+    await expectAnchorError(
+      addSlot(16, 18, false),
+      "SlotOutsideOperatingHours"
+    );
+    await addSlot(10, 11, false);
+    await addSlot(3, 4, true);
+
+    const lateNight = { openMinute: 22 * 60, closeMinute: 2 * 60 };
+    await program.methods
+      .setLocationOperatingHours(locationIdx, {
+        utcOffsetMinutes: 0,
+        days: Array(7).fill(lateNight),
+      })
+      .accounts({
+        authority: provider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+      })
+      .signers([provider])
+      .rpc();
+
+    await addSlot(23, 25, false);
+    await addSlot(25, 26, false);
+    await expectAnchorError(
+      addSlot(26, 27, false),
+      "SlotOutsideOperatingHours"
+    );
+
+    const schedule = await program.account.locationSchedule.fetch(schedulePda);
+    expect(schedule.slotCount).to.equal(4);
+  });
+
+  it("books a range and settles with per-impression pricing", async () => {
+    const { advertiser, advertiserPda, provider, providerPda, oracle } =
+      await setupActors();
+    const { configPda, treasury } = await ensureConfig(provider);
+    const budget = new BN(2 * LAMPORTS_PER_SOL);
+    const { campaignIdx, campaignPda } = await createCampaign(
+      advertiser,
+      advertiserPda,
+      budget
+    );
+
+    const slotPrice = new BN(200_000);
+    const { locationIdx, locationPda } = await registerLocation(
+      provider,
+      providerPda,
+      slotPrice,
+      oracle.publicKey
+    );
+
+    const schedulePda = deriveLocationSchedulePda(locationPda);
+    await program.methods
+      .createLocationSchedule(locationIdx, 10)
+      .accounts({
+        authority: provider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([provider])
+      .rpc();
+
+    const now = Math.floor(Date.now() / 1000);
+    const slotOneStart = new BN(now + 3600);
+    const slotOneEnd = new BN(now + 5400);
+    const slotTwoStart = new BN(now + 7200);
+    const slotTwoEnd = new BN(now + 9000);
+
+    await program.methods
+      .addLocationSlot(locationIdx, slotOneStart, slotOneEnd, slotPrice, false)
+      .accounts({
+        authority: provider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+      })
+      .signers([provider])
+      .rpc();
+
+    await program.methods
+      .addLocationSlot(locationIdx, slotTwoStart, slotTwoEnd, slotPrice, false)
+      .accounts({
+        authority: provider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+      })
+      .signers([provider])
+      .rpc();
+
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
+
+    const rangeStart = slotOneStart;
+    const rangeEnd = slotTwoEnd;
+    const bookingPda = deriveCampaignBookingPda(
+      campaignPda,
+      locationPda,
+      rangeStart,
+      rangeEnd
+    );
+
+    const pricePerImpression = new BN(200);
+    await program.methods
+      .bookLocationRange(
+        campaignIdx,
+        locationIdx,
+        rangeStart,
+        rangeEnd,
+        deviceIdx,
+        { perImpression: { price: pricePerImpression } }
+      )
+      .accounts({
+        authority: advertiser.publicKey,
+        campaign: campaignPda,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        booking: bookingPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([advertiser])
+      .rpc();
+
+    const impressions = new BN(1000);
+    await reportOracleMetrics(
+      devicePda,
+      provider.publicKey,
+      oracle,
+      new BN(0),
+      impressions,
+      deviceIdx
+    );
+
+    const providerPendingBefore = await fetchPendingEarnings(providerPda);
+    const treasuryBalanceBefore = await connection.getBalance(treasury);
+
+    await program.methods
+      .settleLocationBooking(
+        campaignIdx,
+        locationIdx,
+        advertiser.publicKey,
+        provider.publicKey
+      )
+      .accounts({
+        campaign: campaignPda,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        booking: bookingPda,
+        config: configPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+        earnings: deriveEarningsPda(providerPda),
+        treasury,
+        oracleAuthority: oracle.publicKey,
+      })
+      .signers([oracle])
+      .rpc();
+
+    const campaignAfter = await program.account.campaign.fetch(campaignPda);
+    const scheduleAfter = await program.account.locationSchedule.fetch(
+      schedulePda
+    );
+    const providerPendingAfter = await fetchPendingEarnings(providerPda);
+    const treasuryBalanceAfter = await connection.getBalance(treasury);
+
+    const totalPrice = slotPrice.muln(2);
+    const gross = pricePerImpression.mul(impressions);
+    const cappedGross = gross.gt(totalPrice) ? totalPrice : gross;
+    const expectedFee = cappedGross.muln(250).divn(10000);
+    const expectedRefund = totalPrice.sub(cappedGross);
+    const expectedNet = cappedGross.sub(expectedFee);
+
+    let bookingFetchError: any = null;
+    try {
+      await program.account.campaignBooking.fetch(bookingPda);
+    } catch (error) {
+      bookingFetchError = error;
+    }
+
+    expect(bookingFetchError).to.be.ok;
+    expect(campaignAfter.reservedBudget.toNumber()).to.equal(0);
+    expect(campaignAfter.availableBudget.toString()).to.equal(
+      budget.sub(cappedGross).toString()
+    );
+    expect(scheduleAfter.slots[0].status).to.have.property("settled");
+    expect(scheduleAfter.slots[1].status).to.have.property("settled");
+    expect(expectedRefund.toNumber()).to.be.greaterThan(0);
+    expect(providerPendingAfter - providerPendingBefore).to.equal(
+      expectedNet.toNumber()
+    );
+    expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(
+      expectedFee.toNumber()
+    );
+  });
+
+  it("books a range and settles with CPM pricing", async () => {
+    const { advertiser, advertiserPda, provider, providerPda, oracle } =
+      await setupActors();
+    const { configPda, treasury } = await ensureConfig(provider);
+    const budget = new BN(1 * LAMPORTS_PER_SOL);
+    const { campaignIdx, campaignPda } = await createCampaign(
+      advertiser,
+      advertiserPda,
+      budget
+    );
+
+    const slotPrice = new BN(150_000);
+    const { locationIdx, locationPda } = await registerLocation(
+      provider,
+      providerPda,
+      slotPrice,
+      oracle.publicKey
+    );
+
+    const schedulePda = deriveLocationSchedulePda(locationPda);
+    await program.methods
+      .createLocationSchedule(locationIdx, 5)
+      .accounts({
+        authority: provider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([provider])
+      .rpc();
+
+    const now = Math.floor(Date.now() / 1000);
+    const slotStart = new BN(now + 3600);
+    const slotEnd = new BN(now + 5400);
+    await program.methods
+      .addLocationSlot(locationIdx, slotStart, slotEnd, slotPrice, false)
+      .accounts({
+        authority: provider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+      })
+      .signers([provider])
+      .rpc();
+
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
+
+    const rangeStart = slotStart;
+    const rangeEnd = slotEnd;
+    const bookingPda = deriveCampaignBookingPda(
+      campaignPda,
+      locationPda,
+      rangeStart,
+      rangeEnd
+    );
+
+    const cpmPrice = new BN(100_000);
+    await program.methods
+      .bookLocationRange(
+        campaignIdx,
+        locationIdx,
+        rangeStart,
+        rangeEnd,
+        deviceIdx,
+        { cpm: { price: cpmPrice } }
+      )
+      .accounts({
+        authority: advertiser.publicKey,
+        campaign: campaignPda,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        booking: bookingPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([advertiser])
+      .rpc();
+
+    const impressions = new BN(1500);
+    await reportOracleMetrics(
+      devicePda,
+      provider.publicKey,
+      oracle,
+      new BN(0),
+      impressions,
+      deviceIdx
+    );
+
+    const providerPendingBefore = await fetchPendingEarnings(providerPda);
+    const treasuryBalanceBefore = await connection.getBalance(treasury);
+
+    await program.methods
+      .settleLocationBooking(
+        campaignIdx,
+        locationIdx,
+        advertiser.publicKey,
+        provider.publicKey
+      )
+      .accounts({
+        campaign: campaignPda,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        booking: bookingPda,
+        config: configPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+        earnings: deriveEarningsPda(providerPda),
+        treasury,
+        oracleAuthority: oracle.publicKey,
+      })
+      .signers([oracle])
+      .rpc();
+
+    const providerPendingAfter = await fetchPendingEarnings(providerPda);
+    const treasuryBalanceAfter = await connection.getBalance(treasury);
+    const totalPrice = slotPrice;
+    const gross = cpmPrice.mul(impressions).divn(1000);
+    const cappedGross = gross.gt(totalPrice) ? totalPrice : gross;
+    const expectedFee = cappedGross.muln(250).divn(10000);
+    const expectedNet = cappedGross.sub(expectedFee);
+
+    let bookingFetchError: any = null;
+    try {
+      await program.account.campaignBooking.fetch(bookingPda);
+    } catch (error) {
+      bookingFetchError = error;
+    }
+
+    expect(bookingFetchError).to.be.ok;
+    expect(providerPendingAfter - providerPendingBefore).to.equal(
+      expectedNet.toNumber()
+    );
+    expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(
+      expectedFee.toNumber()
+    );
+  });
+
+  it("locks the oracle device while a booking is active", async () => {
+    const { advertiser, advertiserPda, provider, providerPda, oracle } =
+      await setupActors();
+    const budget = new BN(1 * LAMPORTS_PER_SOL);
+    const { campaignIdx, campaignPda } = await createCampaign(
+      advertiser,
+      advertiserPda,
+      budget
+    );
+
+    const slotPrice = new BN(150_000);
+    const { locationIdx, locationPda } = await registerLocation(
+      provider,
+      providerPda,
+      slotPrice,
+      oracle.publicKey
+    );
+
+    const now = Math.floor(Date.now() / 1000);
+    const rangeStart = new BN(now + 3600);
+    const rangeEnd = new BN(now + 5400);
+    const schedulePda = await createScheduleWithSlots(
+      provider,
+      providerPda,
+      locationIdx,
+      locationPda,
+      [{ start: rangeStart, end: rangeEnd, price: slotPrice }]
+    );
+
+    const { deviceIdx, devicePda, registryPda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
+    const bookingPda = deriveCampaignBookingPda(
+      campaignPda,
+      locationPda,
+      rangeStart,
+      rangeEnd
+    );
+
+    await program.methods
+      .bookLocationRange(
+        campaignIdx,
+        locationIdx,
+        rangeStart,
+        rangeEnd,
+        deviceIdx,
+        { timeSlot: {} }
+      )
+      .accounts({
+        authority: advertiser.publicKey,
+        campaign: campaignPda,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        booking: bookingPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([advertiser])
+      .rpc();
+
+    let device = await oracleProgram.account.device.fetch(devicePda);
+    expect(device.activeBookings).to.equal(1);
+
+    await expectAnchorError(
+      oracleProgram.methods
+        .closeDevice(deviceIdx)
+        .accounts({
+          authority: provider.publicKey,
+          registry: registryPda,
+          device: devicePda,
+        })
+        .signers([provider])
+        .rpc(),
+      "DeviceHasActiveBookings"
+    );
+
+    await program.methods
+      .cancelLocationBooking(campaignIdx, locationIdx)
+      .accounts({
+        authority: advertiser.publicKey,
+        campaign: campaignPda,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        booking: bookingPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+      })
+      .signers([advertiser])
+      .rpc();
+
+    device = await oracleProgram.account.device.fetch(devicePda);
+    expect(device.activeBookings).to.equal(0);
+
+    await oracleProgram.methods
+      .closeDevice(deviceIdx)
+      .accounts({
+        authority: provider.publicKey,
+        registry: registryPda,
+        device: devicePda,
+      })
+      .signers([provider])
+      .rpc();
+
+    expect(await connection.getAccountInfo(devicePda)).to.be.null;
+  });
+
+  it("lets scoped delegates operate within their permissions", async () => {
+    const { advertiser, advertiserPda, provider, providerPda, oracle } =
+      await setupActors();
+    const operator = Keypair.generate();
+    const scheduler = Keypair.generate();
+    await airdropTo(operator.publicKey);
+    await airdropTo(scheduler.publicKey);
+
+    const budget = new BN(1 * LAMPORTS_PER_SOL);
+    const { campaignIdx, campaignPda } = await createCampaign(
+      advertiser,
+      advertiserPda,
+      budget
+    );
+    const slotPrice = new BN(150_000);
+    const { locationIdx, locationPda } = await registerLocation(
+      provider,
+      providerPda,
+      slotPrice,
+      oracle.publicKey
+    );
+
+    const PERMISSION_BOOK = 1 << 1;
+    const PERMISSION_EDIT_SLOTS = 1 << 3;
+    const PERMISSION_EDIT_METADATA = 1 << 4;
+
+    const operatorDelegatePda = deriveDelegatePda(
+      advertiserPda,
+      operator.publicKey
+    );
+    // Provider-side permissions cannot be granted over an advertiser.
+    await expectAnchorError(
+      program.methods
+        .setDelegate(operator.publicKey, PERMISSION_EDIT_SLOTS, null)
+        .accounts({
+          owner: advertiser.publicKey,
+          principal: advertiserPda,
+          delegateAccount: operatorDelegatePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([advertiser])
+        .rpc(),
+      "InvalidParameters"
+    );
+    await program.methods
+      .setDelegate(
+        operator.publicKey,
+        PERMISSION_BOOK | PERMISSION_EDIT_METADATA,
+        slotPrice
+      )
+      .accounts({
+        owner: advertiser.publicKey,
+        principal: advertiserPda,
+        delegateAccount: operatorDelegatePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([advertiser])
+      .rpc();
+
+    const schedulerDelegatePda = deriveDelegatePda(
+      providerPda,
+      scheduler.publicKey
+    );
+    await program.methods
+      .setDelegate(scheduler.publicKey, PERMISSION_EDIT_SLOTS, null)
+      .accounts({
+        owner: provider.publicKey,
+        principal: providerPda,
+        delegateAccount: schedulerDelegatePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([provider])
+      .rpc();
+
+    const now = Math.floor(Date.now() / 1000);
+    const schedulePda = deriveLocationSchedulePda(locationPda);
+    await program.methods
+      .createLocationSchedule(locationIdx, 5)
+      .accounts({
+        authority: scheduler.publicKey,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        delegate: schedulerDelegatePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([scheduler])
+      .rpc();
+
+    const slots = [
+      { start: new BN(now + 3600), end: new BN(now + 5400) },
+      { start: new BN(now + 7200), end: new BN(now + 9000) },
+    ];
+    for (const slot of slots) {
+      await program.methods
+        .addLocationSlot(locationIdx, slot.start, slot.end, slotPrice, false)
+        .accounts({
+          authority: scheduler.publicKey,
+          provider: providerPda,
+          location: locationPda,
+          schedule: schedulePda,
+          delegate: schedulerDelegatePda,
+        })
+        .signers([scheduler])
+        .rpc();
+    }
 
-  it("rejects overlapping slots in a schedule", async () => {
-    const { provider, providerPda, oracle } = await setupActors();
-    const price = new BN(100_000);
+    await expectAnchorError(
+      program.methods
+        .updateLocationPrice(locationIdx, new BN(1))
+        .accounts({
+          authority: scheduler.publicKey,
+          provider: providerPda,
+          location: locationPda,
+          delegate: schedulerDelegatePda,
+        })
+        .signers([scheduler])
+        .rpc(),
+      "Unauthorized"
+    );
+
+    await program.methods
+      .updateCampaign(campaignIdx, "Delegated name", null, null)
+      .accounts({
+        authority: operator.publicKey,
+        campaign: campaignPda,
+        delegate: operatorDelegatePda,
+      })
+      .signers([operator])
+      .rpc();
+
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
+    const bookRange = (slot: { start: BN; end: BN }) =>
+      program.methods
+        .bookLocationRange(
+          campaignIdx,
+          locationIdx,
+          slot.start,
+          slot.end,
+          deviceIdx,
+          { timeSlot: {} }
+        )
+        .accounts({
+          authority: operator.publicKey,
+          campaign: campaignPda,
+          provider: providerPda,
+          location: locationPda,
+          schedule: schedulePda,
+          booking: deriveCampaignBookingPda(
+            campaignPda,
+            locationPda,
+            slot.start,
+            slot.end
+          ),
+          delegate: operatorDelegatePda,
+          oracleDevice: devicePda,
+          deviceAuthority: provider.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([operator])
+        .rpc();
+
+    await bookRange(slots[0]);
+    const delegateAccount = await program.account.delegate.fetch(
+      operatorDelegatePda
+    );
+    expect(delegateAccount.spent.toString()).to.equal(slotPrice.toString());
+
+    await expectAnchorError(bookRange(slots[1]), "DelegateSpendLimitExceeded");
+
+    // The owner cancelling the delegate's booking restores its allowance.
+    await program.methods
+      .cancelLocationBooking(campaignIdx, locationIdx)
+      .accounts({
+        authority: advertiser.publicKey,
+        campaign: campaignPda,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        booking: deriveCampaignBookingPda(
+          campaignPda,
+          locationPda,
+          slots[0].start,
+          slots[0].end
+        ),
+        spendDelegate: operatorDelegatePda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+      })
+      .signers([advertiser])
+      .rpc();
+    const creditedDelegate = await program.account.delegate.fetch(
+      operatorDelegatePda
+    );
+    expect(creditedDelegate.spent.toNumber()).to.equal(0);
+    await bookRange(slots[1]);
+
+    await expectAnchorError(
+      program.methods
+        .withdrawBudget(campaignIdx, new BN(1000))
+        .accounts({
+          authority: operator.publicKey,
+          campaign: campaignPda,
+          delegate: operatorDelegatePda,
+          owner: advertiser.publicKey,
+        })
+        .signers([operator])
+        .rpc(),
+      "Unauthorized"
+    );
+
+    await program.methods
+      .revokeDelegate(operator.publicKey)
+      .accounts({
+        owner: advertiser.publicKey,
+        principal: advertiserPda,
+        delegateAccount: operatorDelegatePda,
+      })
+      .signers([advertiser])
+      .rpc();
+
+    expect(await connection.getAccountInfo(operatorDelegatePda)).to.be.null;
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.campaignName).to.equal("Delegated name");
+  });
+
+  it("transfers advertiser, provider and location ownership", async () => {
+    const { advertiser, advertiserPda, provider, providerPda, oracle } =
+      await setupActors();
+    const newAdvertiser = Keypair.generate();
+    const newProvider = Keypair.generate();
+    await airdropTo(newAdvertiser.publicKey);
+    await airdropTo(newProvider.publicKey);
+
+    const budget = new BN(1 * LAMPORTS_PER_SOL);
+    const { campaignIdx, campaignPda } = await createCampaign(
+      advertiser,
+      advertiserPda,
+      budget
+    );
     const { locationIdx, locationPda } = await registerLocation(
       provider,
       providerPda,
-      price,
+      new BN(150_000),
+      oracle.publicKey
+    );
+
+    await program.methods
+      .transferAdvertiser(newAdvertiser.publicKey)
+      .accounts({
+        advertiser: advertiserPda,
+        authority: advertiser.publicKey,
+      })
+      .signers([advertiser])
+      .rpc();
+    await program.methods
+      .syncCampaignAuthority(campaignIdx)
+      .accounts({ advertiser: advertiserPda, campaign: campaignPda })
+      .rpc();
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.authority.toBase58()).to.equal(
+      newAdvertiser.publicKey.toBase58()
+    );
+    expect(campaign.creator.toBase58()).to.equal(
+      advertiser.publicKey.toBase58()
+    );
+
+    await expectAnchorError(
+      program.methods
+        .withdrawBudget(campaignIdx, new BN(1000))
+        .accounts({
+          authority: advertiser.publicKey,
+          campaign: campaignPda,
+          owner: advertiser.publicKey,
+        })
+        .signers([advertiser])
+        .rpc(),
+      ["ConstraintAddress", "Unauthorized"]
+    );
+    await program.methods
+      .withdrawBudget(campaignIdx, new BN(1000))
+      .accounts({
+        authority: newAdvertiser.publicKey,
+        campaign: campaignPda,
+        owner: newAdvertiser.publicKey,
+      })
+      .signers([newAdvertiser])
+      .rpc();
+
+    const nextIdx = await getNextCampaignIdx(advertiserPda);
+    const secondCampaignPda = deriveCampaignPda(advertiser.publicKey, nextIdx);
+    await program.methods
+      .createCampaign("Campaign Two", "Created after transfer", "", new BN(0))
+      .accounts({
+        advertiser: advertiserPda,
+        campaign: secondCampaignPda,
+        authority: newAdvertiser.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([newAdvertiser])
+      .rpc();
+    const secondCampaign = await program.account.campaign.fetch(
+      secondCampaignPda
+    );
+    expect(secondCampaign.authority.toBase58()).to.equal(
+      newAdvertiser.publicKey.toBase58()
+    );
+
+    await program.methods
+      .transferProvider(newProvider.publicKey)
+      .accounts({ provider: providerPda, authority: provider.publicKey })
+      .signers([provider])
+      .rpc();
+    await program.methods
+      .syncLocationAuthority(locationIdx)
+      .accounts({
+        provider: providerPda,
+        location: locationPda,
+        schedule: null,
+      })
+      .rpc();
+
+    await program.methods
+      .updateLocationPrice(locationIdx, new BN(200_000))
+      .accounts({
+        authority: newProvider.publicKey,
+        provider: providerPda,
+        location: locationPda,
+      })
+      .signers([newProvider])
+      .rpc();
+
+    const receiver = await setupActors();
+    await program.methods
+      .transferLocation(locationIdx)
+      .accounts({
+        provider: providerPda,
+        newProvider: receiver.providerPda,
+        location: locationPda,
+        authority: newProvider.publicKey,
+        newAuthority: receiver.provider.publicKey,
+      })
+      .signers([newProvider, receiver.provider])
+      .rpc();
+
+    const location = await program.account.location.fetch(locationPda);
+    expect(location.provider.toBase58()).to.equal(
+      receiver.providerPda.toBase58()
+    );
+    expect(location.authority.toBase58()).to.equal(
+      receiver.provider.publicKey.toBase58()
+    );
+    expect(location.price.toNumber()).to.equal(200_000);
+
+    const receiverAccount = await program.account.provider.fetch(
+      receiver.providerPda
+    );
+    expect(receiverAccount.locationCount.toNumber()).to.equal(1);
+  });
+
+  it("refuses to transfer a location with booked slots", async () => {
+    const ctx = await setupBookableLocation(new BN(100_000), 1);
+    await bookRange(ctx, ctx.slots[0].start, ctx.slots[0].end);
+    const receiver = await setupActors();
+
+    await expectAnchorError(
+      program.methods
+        .transferLocation(ctx.locationIdx)
+        .accounts({
+          provider: ctx.providerPda,
+          newProvider: receiver.providerPda,
+          location: ctx.locationPda,
+          authority: ctx.provider.publicKey,
+          newAuthority: receiver.provider.publicKey,
+        })
+        .signers([ctx.provider, receiver.provider])
+        .rpc(),
+      "LocationHasBookings"
+    );
+  });
+
+  it("lets a linked agency run campaigns and earn commission", async () => {
+    const { advertiser, advertiserPda, provider, providerPda, oracle } =
+      await setupActors();
+    const { configPda, treasury } = await ensureConfig(provider);
+    const agencyOperator = Keypair.generate();
+    await airdropTo(agencyOperator.publicKey);
+
+    const agencyPda = deriveAgencyPda(agencyOperator.publicKey);
+    const commissionBps = 1_000;
+    await program.methods
+      .createAgency(commissionBps)
+      .accounts({
+        agency: agencyPda,
+        authority: agencyOperator.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agencyOperator])
+      .rpc();
+
+    const campaignIdx = await getNextCampaignIdx(advertiserPda);
+    const campaignPda = deriveCampaignPda(advertiser.publicKey, campaignIdx);
+    const createForAdvertiser = () =>
+      program.methods
+        .createCampaign(
+          "Agency campaign",
+          "Bought by the agency",
+          "https://example.com/agency.png",
+          new BN(1 * LAMPORTS_PER_SOL)
+        )
+        .accounts({
+          advertiser: advertiserPda,
+          agency: agencyPda,
+          campaign: campaignPda,
+          authority: agencyOperator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agencyOperator])
+        .rpc();
+
+    await expectAnchorError(createForAdvertiser(), "Unauthorized");
+
+    const delegatePda = deriveDelegatePda(
+      advertiserPda,
+      agencyOperator.publicKey
+    );
+    const link = () =>
+      program.methods
+        .linkAgency()
+        .accounts({
+          advertiser: advertiserPda,
+          agency: agencyPda,
+          delegateAccount: delegatePda,
+          authority: advertiser.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([advertiser])
+        .rpc();
+
+    // Linking never takes over a delegate the advertiser configured by hand.
+    const PERMISSION_BOOK = 1 << 1;
+    const delegateAccounts = {
+      owner: advertiser.publicKey,
+      principal: advertiserPda,
+      delegateAccount: delegatePda,
+      systemProgram: SystemProgram.programId,
+    };
+    await program.methods
+      .setDelegate(agencyOperator.publicKey, PERMISSION_BOOK, new BN(1))
+      .accounts(delegateAccounts)
+      .signers([advertiser])
+      .rpc();
+    let linkError: any = null;
+    try {
+      await link();
+    } catch (error) {
+      linkError = error;
+    }
+    expect(linkError).to.be.ok;
+    const handConfigured = await program.account.delegate.fetch(delegatePda);
+    expect(handConfigured.permissions).to.equal(PERMISSION_BOOK);
+    await program.methods
+      .revokeDelegate(agencyOperator.publicKey)
+      .accounts(delegateAccounts)
+      .signers([advertiser])
+      .rpc();
+
+    await link();
+
+    await createForAdvertiser();
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.authority.toBase58()).to.equal(
+      advertiser.publicKey.toBase58()
+    );
+    expect(campaign.agency.toBase58()).to.equal(agencyPda.toBase58());
+    expect(campaign.agencyCommissionBps).to.equal(commissionBps);
+
+    const slotPrice = new BN(200_000);
+    const { locationIdx, locationPda } = await registerLocation(
+      provider,
+      providerPda,
+      slotPrice,
       oracle.publicKey
     );
+    const now = Math.floor(Date.now() / 1000);
+    const rangeStart = new BN(now + 3600);
+    const rangeEnd = new BN(now + 5400);
+    const schedulePda = await createScheduleWithSlots(
+      provider,
+      providerPda,
+      locationIdx,
+      locationPda,
+      [{ start: rangeStart, end: rangeEnd, price: slotPrice }]
+    );
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
+    const bookingPda = deriveCampaignBookingPda(
+      campaignPda,
+      locationPda,
+      rangeStart,
+      rangeEnd
+    );
+
+    await program.methods
+      .bookLocationRange(
+        campaignIdx,
+        locationIdx,
+        rangeStart,
+        rangeEnd,
+        deviceIdx,
+        { timeSlot: {} }
+      )
+      .accounts({
+        authority: agencyOperator.publicKey,
+        campaign: campaignPda,
+        delegate: delegatePda,
+        provider: providerPda,
+        location: locationPda,
+        schedule: schedulePda,
+        booking: bookingPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agencyOperator])
+      .rpc();
+
+    const config = await program.account.soulboardConfig.fetch(configPda);
+    const agencyBalanceBefore = await connection.getBalance(
+      agencyOperator.publicKey
+    );
+    const providerPendingBefore = await fetchPendingEarnings(providerPda);
+    const budgetBefore = (await program.account.campaign.fetch(campaignPda))
+      .availableBudget;
 
-    const schedulePda = deriveLocationSchedulePda(locationPda);
     await program.methods
-      .createLocationSchedule(locationIdx, 5)
+      .settleLocationBooking(
+        campaignIdx,
+        locationIdx,
+        advertiser.publicKey,
+        provider.publicKey
+      )
       .accounts({
-        authority: provider.publicKey,
+        campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
         schedule: schedulePda,
+        booking: bookingPda,
+        config: configPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+        earnings: deriveEarningsPda(providerPda),
+        treasury,
+        agency: agencyPda,
+        agencyAuthority: agencyOperator.publicKey,
+        oracleAuthority: oracle.publicKey,
+      })
+      .signers([oracle])
+      .rpc();
+
+    // The commission is charged to the advertiser's budget on top of the price;
+    // the provider's share is untouched.
+    const expectedFee = slotPrice.muln(config.feeBps).divn(10000);
+    const expectedCommission = slotPrice.muln(commissionBps).divn(10000);
+    const expectedNet = slotPrice.sub(expectedFee);
+    const agencyBalanceAfter = await connection.getBalance(
+      agencyOperator.publicKey
+    );
+    const providerPendingAfter = await fetchPendingEarnings(providerPda);
+    const budgetAfter = (await program.account.campaign.fetch(campaignPda))
+      .availableBudget;
+    expect(agencyBalanceAfter - agencyBalanceBefore).to.equal(
+      expectedCommission.toNumber()
+    );
+    expect(providerPendingAfter - providerPendingBefore).to.equal(
+      expectedNet.toNumber()
+    );
+    expect(budgetBefore.sub(budgetAfter).toString()).to.equal(
+      expectedCommission.toString()
+    );
+
+    const transferAgency = () =>
+      program.methods
+        .transferAgency(Keypair.generate().publicKey)
+        .accounts({ agency: agencyPda, authority: agencyOperator.publicKey })
+        .signers([agencyOperator])
+        .rpc();
+    await expectAnchorError(transferAgency(), "AgencyHasAdvertisers");
+
+    await program.methods
+      .unlinkAgency()
+      .accounts({
+        advertiser: advertiserPda,
+        agency: agencyPda,
+        delegateAccount: delegatePda,
+        authority: advertiser.publicKey,
+      })
+      .signers([advertiser])
+      .rpc();
+
+    const advertiserAccount = await program.account.advertiser.fetch(
+      advertiserPda
+    );
+    expect(advertiserAccount.agency).to.be.null;
+    expect(await connection.getAccountInfo(delegatePda)).to.be.null;
+    await transferAgency();
+  });
+
+  it("pays agency commission on legacy campaign-location settlements", async () => {
+    const { advertiser, advertiserPda, provider, providerPda, oracle } =
+      await setupActors();
+    const { configPda, treasury } = await ensureConfig(provider);
+    const agencyOperator = Keypair.generate();
+    await airdropTo(agencyOperator.publicKey);
+
+    const agencyPda = deriveAgencyPda(agencyOperator.publicKey);
+    const commissionBps = 500;
+    await program.methods
+      .createAgency(commissionBps)
+      .accounts({
+        agency: agencyPda,
+        authority: agencyOperator.publicKey,
         systemProgram: SystemProgram.programId,
       })
-      .signers([provider])
+      .signers([agencyOperator])
+      .rpc();
+    await program.methods
+      .linkAgency()
+      .accounts({
+        advertiser: advertiserPda,
+        agency: agencyPda,
+        delegateAccount: deriveDelegatePda(
+          advertiserPda,
+          agencyOperator.publicKey
+        ),
+        authority: advertiser.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([advertiser])
       .rpc();
 
-    const now = Math.floor(Date.now() / 1000);
-    const start = new BN(now + 3600);
-    const end = new BN(now + 7200);
+    const campaignIdx = await getNextCampaignIdx(advertiserPda);
+    const campaignPda = deriveCampaignPda(advertiser.publicKey, campaignIdx);
+    await program.methods
+      .createCampaign(
+        "Agency legacy campaign",
+        "Settled through a campaign location",
+        "https://example.com/agency-legacy.png",
+        new BN(1 * LAMPORTS_PER_SOL)
+      )
+      .accounts({
+        advertiser: advertiserPda,
+        agency: agencyPda,
+        campaign: campaignPda,
+        authority: agencyOperator.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agencyOperator])
+      .rpc();
 
+    const price = new BN(400_000);
+    const { locationIdx, locationPda } = await registerLocation(
+      provider,
+      providerPda,
+      price,
+      oracle.publicKey
+    );
+    const { deviceIdx, devicePda } = await createOracleDevice(
+      provider,
+      oracle,
+      locationPda
+    );
+    const campaignLocationPda = deriveCampaignLocationPda(
+      campaignPda,
+      locationPda
+    );
     await program.methods
-      .addLocationSlot(locationIdx, start, end, price)
+      .addCampaignLocation(campaignIdx, locationIdx, deviceIdx)
       .accounts({
-        authority: provider.publicKey,
+        authority: advertiser.publicKey,
+        campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
-        schedule: schedulePda,
+        campaignLocation: campaignLocationPda,
+        oracleDevice: devicePda,
+        deviceAuthority: provider.publicKey,
+        systemProgram: SystemProgram.programId,
       })
-      .signers([provider])
+      .signers([advertiser])
       .rpc();
 
-    await expectAnchorError(
+    const settlementAmount = new BN(300_000);
+    const settle = (withAgency: boolean) =>
       program.methods
-        .addLocationSlot(
+        .settleCampaignLocation(
+          campaignIdx,
           locationIdx,
-          new BN(now + 4000),
-          new BN(now + 8000),
-          price
+          settlementAmount,
+          deviceIdx
         )
         .accounts({
-          authority: provider.publicKey,
+          config: configPda,
+          oracleDevice: devicePda,
+          deviceAuthority: provider.publicKey,
+          treasury,
+          oracleAuthority: oracle.publicKey,
+          earnings: deriveEarningsPda(providerPda),
+          campaign: campaignPda,
           provider: providerPda,
           location: locationPda,
-          schedule: schedulePda,
+          campaignLocation: campaignLocationPda,
+          agency: withAgency ? agencyPda : null,
+          agencyAuthority: withAgency ? agencyOperator.publicKey : null,
         })
-        .signers([provider])
-        .rpc(),
-      "SlotOverlap"
+        .signers([oracle])
+        .rpc();
+
+    await expectAnchorError(settle(false), "InvalidAgency");
+
+    const config = await program.account.soulboardConfig.fetch(configPda);
+    const agencyBalanceBefore = await connection.getBalance(
+      agencyOperator.publicKey
+    );
+    const providerPendingBefore = await fetchPendingEarnings(providerPda);
+    await settle(true);
+
+    const expectedFee = settlementAmount.muln(config.feeBps).divn(10000);
+    const expectedCommission = settlementAmount
+      .muln(commissionBps)
+      .divn(10000);
+    const expectedNet = settlementAmount.sub(expectedFee);
+    const agencyBalanceAfter = await connection.getBalance(
+      agencyOperator.publicKey
+    );
+    const providerPendingAfter = await fetchPendingEarnings(providerPda);
+    expect(agencyBalanceAfter - agencyBalanceBefore).to.equal(
+      expectedCommission.toNumber()
+    );
+    expect(providerPendingAfter - providerPendingBefore).to.equal(
+      expectedNet.toNumber()
     );
   });
 
-  it("books a range and settles with per-impression pricing", async () => {
+  it("routes referral payouts out of the platform fee", async () => {
     const { advertiser, advertiserPda, provider, providerPda, oracle } =
       await setupActors();
     const { configPda, treasury } = await ensureConfig(provider);
-    const budget = new BN(2 * LAMPORTS_PER_SOL);
+    const referrer = Keypair.generate();
+    await airdropTo(referrer.publicKey, 1);
+
+    await program.methods
+      .updateConfig(500, { fromPlatformFee: {} })
+      .accounts({ config: configPda, authority: configAuthority.publicKey })
+      .signers([configAuthority])
+      .rpc();
+
+    const budget = new BN(1 * LAMPORTS_PER_SOL);
     const { campaignIdx, campaignPda } = await createCampaign(
       advertiser,
       advertiserPda,
       budget
     );
-
-    const slotPrice = new BN(200_000);
+    const slotPrice = new BN(400_000);
     const { locationIdx, locationPda } = await registerLocation(
       provider,
       providerPda,
@@ -1188,55 +3936,64 @@ describe("soulboard", () => {
       oracle.publicKey
     );
 
-    const schedulePda = deriveLocationSchedulePda(locationPda);
-    await program.methods
-      .createLocationSchedule(locationIdx, 10)
-      .accounts({
-        authority: provider.publicKey,
-        provider: providerPda,
-        location: locationPda,
-        schedule: schedulePda,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([provider])
-      .rpc();
-
-    const now = Math.floor(Date.now() / 1000);
-    const slotOneStart = new BN(now + 3600);
-    const slotOneEnd = new BN(now + 5400);
-    const slotTwoStart = new BN(now + 7200);
-    const slotTwoEnd = new BN(now + 9000);
-
-    await program.methods
-      .addLocationSlot(locationIdx, slotOneStart, slotOneEnd, slotPrice)
-      .accounts({
-        authority: provider.publicKey,
-        provider: providerPda,
-        location: locationPda,
-        schedule: schedulePda,
-      })
-      .signers([provider])
-      .rpc();
+    await expectAnchorError(
+      program.methods
+        .setCampaignReferral(campaignIdx, {
+          referrer: referrer.publicKey,
+          bps: 600,
+        })
+        .accounts({
+          campaign: campaignPda,
+          referrerDelegate: deriveDelegatePda(advertiserPda, referrer.publicKey),
+          config: configPda,
+          authority: advertiser.publicKey,
+        })
+        .signers([advertiser])
+        .rpc(),
+      "InvalidParameters"
+    );
 
-    await program.methods
-      .addLocationSlot(locationIdx, slotTwoStart, slotTwoEnd, slotPrice)
-      .accounts({
-        authority: provider.publicKey,
-        provider: providerPda,
-        location: locationPda,
-        schedule: schedulePda,
-      })
-      .signers([provider])
-      .rpc();
+    const referralBps = 100;
+    const setLocationReferral = (referrerKey: PublicKey | null) =>
+      program.methods
+        .setLocationReferral(
+          locationIdx,
+          referrerKey ? { referrer: referrerKey, bps: referralBps } : null
+        )
+        .accounts({
+          provider: providerPda,
+          location: locationPda,
+          referrerDelegate: referrerKey
+            ? deriveDelegatePda(providerPda, referrerKey)
+            : null,
+          config: configPda,
+          authority: provider.publicKey,
+        })
+        .signers([provider])
+        .rpc();
+    // Providers cannot refer themselves, and the advertiser cannot be paid for
+    // referring the location it books.
+    await expectAnchorError(
+      setLocationReferral(provider.publicKey),
+      "InvalidReferrer"
+    );
+    await setLocationReferral(advertiser.publicKey);
 
+    const now = Math.floor(Date.now() / 1000);
+    const rangeStart = new BN(now + 3600);
+    const rangeEnd = new BN(now + 5400);
+    const schedulePda = await createScheduleWithSlots(
+      provider,
+      providerPda,
+      locationIdx,
+      locationPda,
+      [{ start: rangeStart, end: rangeEnd, price: slotPrice }]
+    );
     const { deviceIdx, devicePda } = await createOracleDevice(
       provider,
-      oracle.publicKey,
+      oracle,
       locationPda
     );
-
-    const rangeStart = slotOneStart;
-    const rangeEnd = slotTwoEnd;
     const bookingPda = deriveCampaignBookingPda(
       campaignPda,
       locationPda,
@@ -1244,253 +4001,543 @@ describe("soulboard", () => {
       rangeEnd
     );
 
-    const pricePerImpression = new BN(200);
+    const book = () =>
+      program.methods
+        .bookLocationRange(
+          campaignIdx,
+          locationIdx,
+          rangeStart,
+          rangeEnd,
+          deviceIdx,
+          { timeSlot: {} }
+        )
+        .accounts({
+          authority: advertiser.publicKey,
+          campaign: campaignPda,
+          provider: providerPda,
+          location: locationPda,
+          schedule: schedulePda,
+          booking: bookingPda,
+          oracleDevice: devicePda,
+          deviceAuthority: provider.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([advertiser])
+        .rpc();
+    await expectAnchorError(book(), "InvalidReferrer");
+
+    await setLocationReferral(referrer.publicKey);
+    await book();
+    // The booking keeps the referral it was made under.
+    await setLocationReferral(null);
+
+    const config = await program.account.soulboardConfig.fetch(configPda);
+    const referrerBalanceBefore = await connection.getBalance(
+      referrer.publicKey
+    );
+    const treasuryBalanceBefore = await connection.getBalance(treasury);
+    const providerPendingBefore = await fetchPendingEarnings(providerPda);
+
     await program.methods
-      .bookLocationRange(
+      .settleLocationBooking(
         campaignIdx,
         locationIdx,
-        rangeStart,
-        rangeEnd,
-        deviceIdx,
-        { perImpression: { price: pricePerImpression } }
+        advertiser.publicKey,
+        provider.publicKey
       )
       .accounts({
-        authority: advertiser.publicKey,
         campaign: campaignPda,
         provider: providerPda,
         location: locationPda,
         schedule: schedulePda,
         booking: bookingPda,
+        config: configPda,
         oracleDevice: devicePda,
         deviceAuthority: provider.publicKey,
-        systemProgram: SystemProgram.programId,
+        earnings: deriveEarningsPda(providerPda),
+        treasury,
+        locationReferrer: referrer.publicKey,
+        oracleAuthority: oracle.publicKey,
       })
-      .signers([advertiser])
+      .signers([oracle])
       .rpc();
 
-    const impressions = new BN(1000);
-    await reportOracleMetrics(
-      devicePda,
-      provider.publicKey,
-      oracle,
-      new BN(0),
-      impressions,
-      deviceIdx
+    const platformFee = slotPrice.muln(config.feeBps).divn(10000);
+    const referral = slotPrice.muln(referralBps).divn(10000);
+    expect(
+      (await connection.getBalance(referrer.publicKey)) - referrerBalanceBefore
+    ).to.equal(referral.toNumber());
+    expect((await connection.getBalance(treasury)) - treasuryBalanceBefore).to.equal(
+      platformFee.sub(referral).toNumber()
     );
+    expect(
+      (await fetchPendingEarnings(providerPda)) - providerPendingBefore
+    ).to.equal(slotPrice.sub(platformFee).toNumber());
+  });
+
+  it("applies the most specific active fee override at settlement", async () => {
+    const slotPrice = new BN(500_000);
+    const ctx = await setupBookableLocation(slotPrice);
+    const { configPda, treasury } = await ensureConfig(ctx.provider);
+    const now = Math.floor(Date.now() / 1000);
+
+    const providerOverridePda = deriveFeeOverridePda(ctx.providerPda);
+    await program.methods
+      .setFeeOverride(ctx.providerPda, { provider: {} }, 100, null)
+      .accounts({
+        config: configPda,
+        feeOverride: providerOverridePda,
+        authority: configAuthority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([configAuthority])
+      .rpc();
+
+    const locationOverrideBps = 50;
+    const locationOverridePda = deriveFeeOverridePda(ctx.locationPda);
+    await program.methods
+      .setFeeOverride(
+        ctx.locationPda,
+        { location: {} },
+        locationOverrideBps,
+        new BN(now + 86_400)
+      )
+      .accounts({
+        config: configPda,
+        feeOverride: locationOverridePda,
+        authority: configAuthority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([configAuthority])
+      .rpc();
 
-    const providerBalanceBefore = await connection.getBalance(
-      provider.publicKey
+    await expectAnchorError(
+      program.methods
+        .setFeeOverride(ctx.locationPda, { location: {} }, 10, null)
+        .accounts({
+          config: configPda,
+          feeOverride: locationOverridePda,
+          authority: ctx.provider.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.provider])
+        .rpc(),
+      "ConstraintHasOne"
     );
+
+    const [slot] = ctx.slots;
+    const bookingPda = await bookRange(ctx, slot.start, slot.end);
     const treasuryBalanceBefore = await connection.getBalance(treasury);
 
     await program.methods
       .settleLocationBooking(
-        campaignIdx,
-        locationIdx,
-        rangeStart,
-        rangeEnd,
-        advertiser.publicKey,
-        provider.publicKey
+        ctx.campaignIdx,
+        ctx.locationIdx,
+        ctx.advertiser.publicKey,
+        ctx.provider.publicKey
       )
       .accounts({
-        campaign: campaignPda,
-        provider: providerPda,
-        location: locationPda,
-        schedule: schedulePda,
+        campaign: ctx.campaignPda,
+        provider: ctx.providerPda,
+        location: ctx.locationPda,
+        schedule: ctx.schedulePda,
         booking: bookingPda,
         config: configPda,
-        oracleDevice: devicePda,
-        deviceAuthority: provider.publicKey,
-        locationAuthority: provider.publicKey,
+        providerFeeOverride: providerOverridePda,
+        locationFeeOverride: locationOverridePda,
+        oracleDevice: ctx.devicePda,
+        deviceAuthority: ctx.provider.publicKey,
+        earnings: deriveEarningsPda(ctx.providerPda),
         treasury,
-        oracleAuthority: oracle.publicKey,
+        oracleAuthority: ctx.oracle.publicKey,
       })
-      .signers([oracle])
+      .signers([ctx.oracle])
       .rpc();
 
-    const campaignAfter = await program.account.campaign.fetch(campaignPda);
-    const scheduleAfter = await program.account.locationSchedule.fetch(
-      schedulePda
+    expect((await connection.getBalance(treasury)) - treasuryBalanceBefore).to.equal(
+      slotPrice.muln(locationOverrideBps).divn(10000).toNumber()
     );
-    const providerBalanceAfter = await connection.getBalance(provider.publicKey);
-    const treasuryBalanceAfter = await connection.getBalance(treasury);
 
-    const totalPrice = slotPrice.muln(2);
-    const gross = pricePerImpression.mul(impressions);
-    const cappedGross = gross.gt(totalPrice) ? totalPrice : gross;
-    const expectedFee = cappedGross.muln(250).divn(10000);
-    const expectedRefund = totalPrice.sub(cappedGross);
-    const expectedNet = cappedGross.sub(expectedFee);
+    await program.methods
+      .removeFeeOverride(ctx.locationPda)
+      .accounts({
+        config: configPda,
+        feeOverride: locationOverridePda,
+        authority: configAuthority.publicKey,
+      })
+      .signers([configAuthority])
+      .rpc();
+    expect(await connection.getAccountInfo(locationOverridePda)).to.be.null;
+  });
 
-    let bookingFetchError: any = null;
-    try {
-      await program.account.campaignBooking.fetch(bookingPda);
-    } catch (error) {
-      bookingFetchError = error;
-    }
+  it("accounts for fees in the treasury PDA and splits withdrawals", async () => {
+    const slotPrice = new BN(800_000);
+    const ctx = await setupBookableLocation(slotPrice);
+    const { configPda, treasury } = await ensureConfig(ctx.provider);
+    const treasuryBefore = await program.account.treasury.fetch(treasury);
 
-    expect(bookingFetchError).to.be.ok;
-    expect(campaignAfter.reservedBudget.toNumber()).to.equal(0);
-    expect(campaignAfter.availableBudget.toString()).to.equal(
-      budget.sub(cappedGross).toString()
+    const [slot] = ctx.slots;
+    const bookingPda = await bookRange(ctx, slot.start, slot.end);
+    await program.methods
+      .settleLocationBooking(
+        ctx.campaignIdx,
+        ctx.locationIdx,
+        ctx.advertiser.publicKey,
+        ctx.provider.publicKey
+      )
+      .accounts({
+        campaign: ctx.campaignPda,
+        provider: ctx.providerPda,
+        location: ctx.locationPda,
+        schedule: ctx.schedulePda,
+        booking: bookingPda,
+        config: configPda,
+        oracleDevice: ctx.devicePda,
+        deviceAuthority: ctx.provider.publicKey,
+        earnings: deriveEarningsPda(ctx.providerPda),
+        treasury,
+        oracleAuthority: ctx.oracle.publicKey,
+      })
+      .signers([ctx.oracle])
+      .rpc();
+
+    const booking = await program.account.campaignBooking.fetchNullable(
+      bookingPda
     );
-    expect(scheduleAfter.slots[0].status).to.have.property("settled");
-    expect(scheduleAfter.slots[1].status).to.have.property("settled");
-    expect(expectedRefund.toNumber()).to.be.greaterThan(0);
-    expect(providerBalanceAfter - providerBalanceBefore).to.equal(
-      expectedNet.toNumber()
+    expect(booking).to.be.null;
+    const treasuryAfter = await program.account.treasury.fetch(treasury);
+    const collected = treasuryAfter.totalCollected.sub(
+      treasuryBefore.totalCollected
     );
-    expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(
-      expectedFee.toNumber()
+    expect(collected.toNumber()).to.be.greaterThan(0);
+    const period = Math.floor(Date.now() / 1000 / (30 * 86_400));
+    const periodEntry = treasuryAfter.periods.find(
+      (entry: any) => entry.period.toNumber() === period
     );
-  });
-
-  it("books a range and settles with CPM pricing", async () => {
-    const { advertiser, advertiserPda, provider, providerPda, oracle } =
-      await setupActors();
-    const { configPda, treasury } = await ensureConfig(provider);
-    const budget = new BN(1 * LAMPORTS_PER_SOL);
-    const { campaignIdx, campaignPda } = await createCampaign(
-      advertiser,
-      advertiserPda,
-      budget
+    expect(periodEntry.collected.gte(collected)).to.equal(true);
+
+    const beneficiary = Keypair.generate();
+    const recipient = Keypair.generate();
+    await airdropTo(beneficiary.publicKey, 1);
+    await airdropTo(recipient.publicKey, 1);
+    const beneficiaryBefore = await connection.getBalance(
+      beneficiary.publicKey
     );
+    const recipientBefore = await connection.getBalance(recipient.publicKey);
 
-    const slotPrice = new BN(150_000);
-    const { locationIdx, locationPda } = await registerLocation(
-      provider,
-      providerPda,
-      slotPrice,
-      oracle.publicKey
+    await expectAnchorError(
+      program.methods
+        .withdrawTreasury(collected, [])
+        .accounts({
+          config: configPda,
+          treasury,
+          recipient: recipient.publicKey,
+          authority: ctx.provider.publicKey,
+        })
+        .signers([ctx.provider])
+        .rpc(),
+      "ConstraintHasOne"
     );
 
-    const schedulePda = deriveLocationSchedulePda(locationPda);
     await program.methods
-      .createLocationSchedule(locationIdx, 5)
+      .withdrawTreasury(collected, [
+        { beneficiary: beneficiary.publicKey, bps: 4_000 },
+      ])
       .accounts({
-        authority: provider.publicKey,
-        provider: providerPda,
-        location: locationPda,
-        schedule: schedulePda,
-        systemProgram: SystemProgram.programId,
+        config: configPda,
+        treasury,
+        recipient: recipient.publicKey,
+        authority: configAuthority.publicKey,
       })
-      .signers([provider])
+      .remainingAccounts([
+        { pubkey: beneficiary.publicKey, isWritable: true, isSigner: false },
+      ])
+      .signers([configAuthority])
       .rpc();
 
-    const now = Math.floor(Date.now() / 1000);
-    const slotStart = new BN(now + 3600);
-    const slotEnd = new BN(now + 5400);
+    const split = collected.muln(4_000).divn(10_000);
+    expect(
+      (await connection.getBalance(beneficiary.publicKey)) - beneficiaryBefore
+    ).to.equal(split.toNumber());
+    expect(
+      (await connection.getBalance(recipient.publicKey)) - recipientBefore
+    ).to.equal(collected.sub(split).toNumber());
+    const treasuryFinal = await program.account.treasury.fetch(treasury);
+    expect(
+      treasuryFinal.totalWithdrawn.sub(treasuryAfter.totalWithdrawn).toString()
+    ).to.equal(collected.toString());
+  });
+
+  it("credits settlements to the provider earnings vault", async () => {
+    const slotPrice = new BN(400_000);
+    const ctx = await setupBookableLocation(slotPrice);
+    const { configPda, treasury } = await ensureConfig(ctx.provider);
+    const config = await program.account.soulboardConfig.fetch(configPda);
+    const earningsPda = deriveEarningsPda(ctx.providerPda);
+
+    const [slot] = ctx.slots;
+    const bookingPda = await bookRange(ctx, slot.start, slot.end);
     await program.methods
-      .addLocationSlot(locationIdx, slotStart, slotEnd, slotPrice)
+      .settleLocationBooking(
+        ctx.campaignIdx,
+        ctx.locationIdx,
+        ctx.advertiser.publicKey,
+        ctx.provider.publicKey
+      )
       .accounts({
-        authority: provider.publicKey,
-        provider: providerPda,
-        location: locationPda,
-        schedule: schedulePda,
+        campaign: ctx.campaignPda,
+        provider: ctx.providerPda,
+        location: ctx.locationPda,
+        schedule: ctx.schedulePda,
+        booking: bookingPda,
+        config: configPda,
+        oracleDevice: ctx.devicePda,
+        deviceAuthority: ctx.provider.publicKey,
+        earnings: earningsPda,
+        treasury,
+        oracleAuthority: ctx.oracle.publicKey,
       })
-      .signers([provider])
+      .signers([ctx.oracle])
       .rpc();
 
-    const { deviceIdx, devicePda } = await createOracleDevice(
-      provider,
-      oracle.publicKey,
-      locationPda
-    );
+    const net = slotPrice.sub(slotPrice.muln(config.feeBps).divn(10000));
+    const credited = await program.account.providerEarnings.fetch(earningsPda);
+    expect(credited.provider.toBase58()).to.equal(ctx.providerPda.toBase58());
+    expect(credited.pending.toString()).to.equal(net.toString());
+    expect(credited.lifetime.toString()).to.equal(net.toString());
+    expect(credited.withdrawn.toNumber()).to.equal(0);
 
-    const rangeStart = slotStart;
-    const rangeEnd = slotEnd;
-    const bookingPda = deriveCampaignBookingPda(
-      campaignPda,
-      locationPda,
-      rangeStart,
-      rangeEnd
+    await expectAnchorError(
+      program.methods
+        .withdrawEarnings(net.addn(1))
+        .accounts({
+          provider: ctx.providerPda,
+          earnings: earningsPda,
+          authority: ctx.provider.publicKey,
+        })
+        .signers([ctx.provider])
+        .rpc(),
+      "InsufficientEarnings"
     );
 
-    const cpmPrice = new BN(100_000);
+    const payout = Keypair.generate();
+    await airdropTo(payout.publicKey, 1);
+    const payoutBefore = await connection.getBalance(payout.publicKey);
+    const firstWithdrawal = net.divn(2);
     await program.methods
-      .bookLocationRange(
-        campaignIdx,
-        locationIdx,
-        rangeStart,
-        rangeEnd,
-        deviceIdx,
-        { cpm: { price: cpmPrice } }
-      )
+      .withdrawEarnings(firstWithdrawal)
       .accounts({
-        authority: advertiser.publicKey,
-        campaign: campaignPda,
-        provider: providerPda,
-        location: locationPda,
-        schedule: schedulePda,
-        booking: bookingPda,
-        oracleDevice: devicePda,
-        deviceAuthority: provider.publicKey,
-        systemProgram: SystemProgram.programId,
+        provider: ctx.providerPda,
+        earnings: earningsPda,
+        payout: payout.publicKey,
+        authority: ctx.provider.publicKey,
       })
-      .signers([advertiser])
+      .signers([ctx.provider])
       .rpc();
+    expect(
+      (await connection.getBalance(payout.publicKey)) - payoutBefore
+    ).to.equal(firstWithdrawal.toNumber());
 
-    const impressions = new BN(1500);
-    await reportOracleMetrics(
-      devicePda,
-      provider.publicKey,
-      oracle,
-      new BN(0),
-      impressions,
-      deviceIdx
+    const remaining = net.sub(firstWithdrawal);
+    const authorityBefore = await connection.getBalance(ctx.provider.publicKey);
+    await program.methods
+      .withdrawEarnings(remaining)
+      .accounts({
+        provider: ctx.providerPda,
+        earnings: earningsPda,
+        authority: ctx.provider.publicKey,
+      })
+      .signers([ctx.provider])
+      .rpc();
+    expect(
+      (await connection.getBalance(ctx.provider.publicKey)) - authorityBefore
+    ).to.be.greaterThan(0);
+
+    const drained = await program.account.providerEarnings.fetch(earningsPda);
+    expect(drained.pending.toNumber()).to.equal(0);
+    expect(drained.withdrawn.toString()).to.equal(net.toString());
+    expect(drained.lifetime.toString()).to.equal(net.toString());
+  });
+
+  it("lets anyone settle after the grace period without the oracle", async () => {
+    const slotPrice = new BN(250_000);
+    const ctx = await setupBookableLocation(slotPrice, 0);
+    const { provider, providerPda } = ctx;
+    const { configPda, treasury } = await ensureConfig(provider);
+    const config = await program.account.soulboardConfig.fetch(configPda);
+    const setFallback = (graceSecs: number, fallback: any) =>
+      program.methods
+        .setSettlementFallback(graceSecs, fallback)
+        .accounts({ config: configPda, authority: configAuthority.publicKey })
+        .signers([configAuthority])
+        .rpc();
+    await setFallback(1, { refund: {} });
+
+    const now = Math.floor(Date.now() / 1000);
+    const slots = [
+      { start: new BN(now + 10), end: new BN(now + 12) },
+      { start: new BN(now + 12), end: new BN(now + 14) },
+    ];
+    for (const slot of slots) {
+      await program.methods
+        .addLocationSlot(ctx.locationIdx, slot.start, slot.end, slotPrice, false)
+        .accounts({
+          authority: provider.publicKey,
+          provider: providerPda,
+          location: ctx.locationPda,
+          schedule: ctx.schedulePda,
+        })
+        .signers([provider])
+        .rpc();
+    }
+    const timeSlotBooking = await bookRange(ctx, slots[0].start, slots[0].end);
+    const impressionBooking = await bookRange(
+      ctx,
+      slots[1].start,
+      slots[1].end,
+      { perImpression: { price: new BN(1_000) } }
     );
+    const crank = (booking: PublicKey) =>
+      program.methods
+        .settleLocationBooking(
+          ctx.campaignIdx,
+          ctx.locationIdx,
+          ctx.advertiser.publicKey,
+          provider.publicKey
+        )
+        .accounts({
+          campaign: ctx.campaignPda,
+          provider: providerPda,
+          location: ctx.locationPda,
+          schedule: ctx.schedulePda,
+          booking,
+          config: configPda,
+          oracleDevice: ctx.devicePda,
+          deviceAuthority: provider.publicKey,
+          earnings: deriveEarningsPda(providerPda),
+          treasury,
+          oracleAuthority: null,
+        })
+        .rpc();
 
-    const providerBalanceBefore = await connection.getBalance(
-      provider.publicKey
+    await expectAnchorError(
+      crank(timeSlotBooking),
+      "SettlementGracePending"
     );
-    const treasuryBalanceBefore = await connection.getBalance(treasury);
+    await new Promise((resolve) =>
+      setTimeout(resolve, (now + 16) * 1000 - Date.now())
+    );
+
+    const pendingBefore = await fetchPendingEarnings(providerPda);
+    await crank(timeSlotBooking);
+    const net = slotPrice.sub(slotPrice.muln(config.feeBps).divn(10000));
+    expect((await fetchPendingEarnings(providerPda)) - pendingBefore).to.equal(
+      net.toNumber()
+    );
+
+    // A deactivated device must not strand the remaining booking.
+    await oracleProgram.methods
+      .setDeviceStatus(ctx.deviceIdx, { inactive: {} })
+      .accounts({ device: ctx.devicePda, authority: provider.publicKey })
+      .signers([provider])
+      .rpc();
+
+    const before = await program.account.campaign.fetch(ctx.campaignPda);
+    await crank(impressionBooking);
+    const after = await program.account.campaign.fetch(ctx.campaignPda);
+    expect(after.availableBudget.sub(before.availableBudget).toString()).to.equal(
+      slotPrice.toString()
+    );
+    expect(after.reservedBudget.toNumber()).to.equal(0);
+    expect(await connection.getAccountInfo(impressionBooking)).to.equal(null);
+
+    await setFallback(3 * 86_400, { useMetrics: {} });
+  });
+
+  it("migrates accounts created before the upgrade", async () => {
+    // Loaded from tests/fixtures in their pre-upgrade layouts.
+    const legacyAuthority = new PublicKey(
+      "LegacyAuthority1111111111111111111111111111"
+    );
+    const advertiserPda = deriveAdvertiserPda(legacyAuthority);
+    const providerPda = deriveProviderPda(legacyAuthority);
+    const campaignPda = deriveCampaignPda(legacyAuthority, new BN(0));
+    const locationPda = deriveLocationPda(legacyAuthority, new BN(0));
+    const payer = provider.wallet.publicKey;
+    const systemProgram = SystemProgram.programId;
 
     await program.methods
-      .settleLocationBooking(
-        campaignIdx,
-        locationIdx,
-        rangeStart,
-        rangeEnd,
-        advertiser.publicKey,
-        provider.publicKey
-      )
+      .migrateAdvertiser()
+      .accounts({ advertiser: advertiserPda, payer, systemProgram })
+      .rpc();
+    await program.methods
+      .migrateProvider()
+      .accounts({ provider: providerPda, payer, systemProgram })
+      .rpc();
+    await program.methods
+      .createProviderEarnings()
       .accounts({
-        campaign: campaignPda,
         provider: providerPda,
-        location: locationPda,
-        schedule: schedulePda,
-        booking: bookingPda,
-        config: configPda,
-        oracleDevice: devicePda,
-        deviceAuthority: provider.publicKey,
-        locationAuthority: provider.publicKey,
-        treasury,
-        oracleAuthority: oracle.publicKey,
+        earnings: deriveEarningsPda(providerPda),
+        payer,
+        systemProgram,
       })
-      .signers([oracle])
+      .rpc();
+    await program.methods
+      .migrateCampaign()
+      .accounts({ campaign: campaignPda, payer, systemProgram })
+      .rpc();
+    await program.methods
+      .migrateLocation()
+      .accounts({ location: locationPda, payer, systemProgram })
       .rpc();
 
-    const providerBalanceAfter = await connection.getBalance(provider.publicKey);
-    const treasuryBalanceAfter = await connection.getBalance(treasury);
-    const totalPrice = slotPrice;
-    const gross = cpmPrice.mul(impressions).divn(1000);
-    const cappedGross = gross.gt(totalPrice) ? totalPrice : gross;
-    const expectedFee = cappedGross.muln(250).divn(10000);
-    const expectedNet = cappedGross.sub(expectedFee);
+    const advertiser = await program.account.advertiser.fetch(advertiserPda);
+    expect(advertiser.creator.toBase58()).to.equal(legacyAuthority.toBase58());
+    expect(advertiser.lastCampaignId.toNumber()).to.equal(1);
+    expect(advertiser.agency).to.equal(null);
 
-    let bookingFetchError: any = null;
-    try {
-      await program.account.campaignBooking.fetch(bookingPda);
-    } catch (error) {
-      bookingFetchError = error;
-    }
+    const providerAccount = await program.account.provider.fetch(providerPda);
+    expect(providerAccount.creator.toBase58()).to.equal(
+      legacyAuthority.toBase58()
+    );
+    expect(providerAccount.locationCount.toNumber()).to.equal(1);
+    const earnings = await program.account.providerEarnings.fetch(
+      deriveEarningsPda(providerPda)
+    );
+    expect(earnings.provider.toBase58()).to.equal(providerPda.toBase58());
+    expect(earnings.pending.toNumber()).to.equal(0);
 
-    expect(bookingFetchError).to.be.ok;
-    expect(providerBalanceAfter - providerBalanceBefore).to.equal(
-      expectedNet.toNumber()
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.creator.toBase58()).to.equal(legacyAuthority.toBase58());
+    expect(campaign.advertiser.toBase58()).to.equal(advertiserPda.toBase58());
+    expect(campaign.campaignName).to.equal("Legacy campaign");
+    expect(campaign.availableBudget.toNumber()).to.equal(LAMPORTS_PER_SOL);
+    expect(campaign.status).to.deep.equal({ active: {} });
+
+    const location = await program.account.location.fetch(locationPda);
+    expect(location.creator.toBase58()).to.equal(legacyAuthority.toBase58());
+    expect(location.provider.toBase58()).to.equal(providerPda.toBase58());
+    expect(location.price.toNumber()).to.equal(100_000_000);
+    expect(location.profile.venueCategory).to.deep.equal({ other: {} });
+    expect(location.hasPolicy).to.equal(false);
+
+    await expectAnchorError(
+      program.methods
+        .migrateCampaign()
+        .accounts({ campaign: campaignPda, payer, systemProgram })
+        .rpc(),
+      "AccountAlreadyMigrated"
     );
-    expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(
-      expectedFee.toNumber()
+
+    // Fees now accrue in the treasury PDA instead of the legacy wallet.
+    const { configPda, treasury } = await ensureConfig(legacyConfigAuthority);
+    const config = await program.account.soulboardConfig.fetch(configPda);
+    expect(config.authority.toBase58()).to.equal(
+      legacyConfigAuthority.publicKey.toBase58()
     );
+    expect(config.treasury.toBase58()).to.equal(treasury.toBase58());
+    expect(config.feeBps).to.equal(250);
+    await program.account.treasury.fetch(treasury);
   });
 });